@@ -1,62 +1,368 @@
+use std::env;
+use std::path::PathBuf;
+
 use console::style;
 
+use rust_decimal::Decimal;
+
+use conversion::physical_constants;
 use conversion_parser::Factory;
 
 
-use crate::ColorTypes;
+use crate::{ColorTypes, colorize_tokens, space_tokens};
 
 pub enum Command {
 	Help,
 	Constants,
-	Functions,
-	Units
+	PhysicalConstants,
+	Functions(Option<String>),
+	Units,
+	Angle(Option<String>),
+	Rational(Option<String>),
+	Fraction(Option<String>),
+	Precision(Option<String>),
+	Rounding(Option<String>),
+	Notation(Option<String>),
+	Clock(Option<String>),
+	Months(Option<String>),
+	Rates(Option<String>),
+	Save(Option<String>),
+	Load(String),
+	UnitFile(String)
 }
 
 impl Command {
-	pub fn display(&self, factory: &Factory) -> String {
+	pub fn display(&self, factory: &mut Factory) -> String {
 		match self {
 			Command::Help => display_help(),
 			Command::Constants => display_const(factory),
-			Command::Functions => display_func(factory),
+			Command::PhysicalConstants => display_physics(),
+			Command::Functions(name) => display_func(factory, name.as_deref()),
 			Command::Units => display_units(factory),
+			Command::Angle(unit) => display_angle(factory, unit.as_deref()),
+			Command::Rational(enabled) => display_rational(factory, enabled.as_deref()),
+			Command::Fraction(max) => display_fraction(factory, max.as_deref()),
+			Command::Precision(digits) => display_precision(factory, digits.as_deref()),
+			Command::Rounding(mode) => display_rounding(factory, mode.as_deref()),
+			Command::Notation(name) => display_notation(factory, name.as_deref()),
+			Command::Clock(name) => display_clock(factory, name.as_deref()),
+			Command::Months(name) => display_months(factory, name.as_deref()),
+			Command::Rates(path) => display_rates(factory, path.as_deref()),
+			Command::Save(path) => display_save(factory, path.as_deref()),
+			Command::Load(path) => display_load(factory, path),
+			Command::UnitFile(path) => display_unit_file(factory, path),
 		}
 	}
 }
 
 
 
-pub fn get_command(value: &str) -> Option<Command> {
+pub fn get_command(value: &str, arg: Option<&str>) -> Option<Command> {
+	Some(match (value, arg) {
+		("help", None) => Command::Help,
+		("constants", None) => Command::Constants,
+		("constants", Some("physics")) => Command::PhysicalConstants,
+		("functions", arg) => Command::Functions(arg.map(|v| v.to_string())),
+		("units", None) => Command::Units,
+		("angle", arg) => Command::Angle(arg.map(|v| v.to_string())),
+		("rational", arg) => Command::Rational(arg.map(|v| v.to_string())),
+		("frac", arg) => Command::Fraction(arg.map(|v| v.to_string())),
+		("precision", arg) => Command::Precision(arg.map(|v| v.to_string())),
+		("rounding", arg) => Command::Rounding(arg.map(|v| v.to_string())),
+		("notation", arg) => Command::Notation(arg.map(|v| v.to_string())),
+		("clock", arg) => Command::Clock(arg.map(|v| v.to_string())),
+		("months", arg) => Command::Months(arg.map(|v| v.to_string())),
+		("rates", arg) => Command::Rates(arg.map(|v| v.to_string())),
+		("save", None) => Command::Save(None),
+
+		_ => return None
+	})
+}
+
+/// Commands given a file path. The path is the rest of the line. "load ~/work.calc"
+pub fn get_file_command(value: &str, path: &str) -> Option<Command> {
+	let path = expand_home(path);
+
 	Some(match value {
-		"help" => Command::Help,
-		"constants" => Command::Constants,
-		"functions" => Command::Functions,
-		"units" => Command::Units,
+		"rates" => Command::Rates(Some(path)),
+		"save" => Command::Save(Some(path)),
+		"load" => Command::Load(path),
+		"units" => Command::UnitFile(path),
 
 		_ => return None
 	})
 }
 
+/// Paths starting with ~ are in the home directory. "~/work.calc"
+fn expand_home(path: &str) -> String {
+	let rest = match path.strip_prefix('~') {
+		Some(rest) if rest.is_empty() || rest.starts_with(['/', '\\']) => rest.trim_start_matches(['/', '\\']),
+		_ => return path.to_string()
+	};
+
+	match env::var_os("HOME").or_else(|| env::var_os("USERPROFILE")) {
+		Some(home) => PathBuf::from(home).join(rest).display().to_string(),
+		None => path.to_string()
+	}
+}
+
 fn display_help() -> String {
 	vec![
-		"help"
+		"help",
+		"constants [physics]",
+		"functions [name]",
+		"angle [unit]",
+		"rational [on|off]",
+		"frac [max denominator]",
+		"precision [digits|off]",
+		"rounding [even|up|zero]",
+		"notation [fixed|sigfig|eng]",
+		"clock [12|24|auto]",
+		"months [average|30d|calendar|off]",
+		"rates [csv or json file]",
+		"save [file]",
+		"load <file>",
+		"units [definition file]",
+		"define <name>[, plural] [(short, aliases)] = <amount unit>",
+		"prefix <name>- [(short-)] = <amount>"
 	]
 	.join("\n")
 }
 
+fn display_angle(factory: &mut Factory, unit: Option<&str>) -> String {
+	if let Some(unit) = unit {
+		if let Err(e) = factory.set_angle_unit(unit) {
+			return ColorTypes::Error.str(&e.to_string());
+		}
+	}
+
+	format!("{} {}", style(">").red(), ColorTypes::Default.str(factory.settings().angle_unit.long()))
+}
+
+fn display_rational(factory: &mut Factory, enabled: Option<&str>) -> String {
+	match enabled {
+		Some("on") => factory.set_rational(true),
+		Some("off") => factory.set_rational(false),
+		Some(_) => return ColorTypes::Error.str("Expected on or off"),
+		None => ()
+	}
+
+	let enabled = if factory.settings().rational { "on" } else { "off" };
+
+	format!("{} {}", style(">").red(), ColorTypes::Default.str(enabled))
+}
+
+fn display_fraction(factory: &mut Factory, max: Option<&str>) -> String {
+	if let Some(max) = max {
+		let result = match max.parse() {
+			Ok(max) => factory.set_max_denominator(max),
+			Err(_) => return ColorTypes::Error.str(&format!(r#"Expected a whole number, found "{}""#, max))
+		};
+
+		if let Err(e) = result {
+			return ColorTypes::Error.str(&e.to_string());
+		}
+	}
+
+	format!("{} {}", style(">").red(), ColorTypes::Default.str(&factory.settings().max_denominator.to_string()))
+}
+
+fn display_precision(factory: &mut Factory, digits: Option<&str>) -> String {
+	if let Some(digits) = digits {
+		let result = match digits {
+			"off" => factory.set_precision(None),
+			digits => match digits.parse() {
+				Ok(digits) => factory.set_precision(Some(digits)),
+				Err(_) => return ColorTypes::Error.str(&format!(r#"Expected a whole number or off, found "{}""#, digits))
+			}
+		};
+
+		if let Err(e) = result {
+			return ColorTypes::Error.str(&e.to_string());
+		}
+	}
+
+	let precision = factory.settings().precision.map(|d| d.to_string()).unwrap_or_else(|| "off".to_string());
+
+	format!("{} {}", style(">").red(), ColorTypes::Default.str(&precision))
+}
+
+fn display_rounding(factory: &mut Factory, mode: Option<&str>) -> String {
+	if let Some(mode) = mode {
+		if let Err(e) = factory.set_rounding(mode) {
+			return ColorTypes::Error.str(&e.to_string());
+		}
+	}
+
+	format!("{} {}", style(">").red(), ColorTypes::Default.str(&factory.settings().rounding.to_string()))
+}
+
+fn display_notation(factory: &mut Factory, name: Option<&str>) -> String {
+	if let Some(name) = name {
+		if let Err(e) = factory.set_notation(name) {
+			return ColorTypes::Error.str(&e.to_string());
+		}
+	}
+
+	format!("{} {}", style(">").red(), ColorTypes::Default.str(&factory.settings().notation.to_string()))
+}
+
+fn display_clock(factory: &mut Factory, name: Option<&str>) -> String {
+	if let Some(name) = name {
+		if let Err(e) = factory.set_clock_format(name) {
+			return ColorTypes::Error.str(&e.to_string());
+		}
+	}
+
+	let format = factory.settings().clock_format.map(|f| f.to_string()).unwrap_or_else(|| "auto".to_string());
+
+	format!("{} {}", style(">").red(), ColorTypes::Default.str(&format))
+}
+
+fn display_months(factory: &mut Factory, name: Option<&str>) -> String {
+	if let Some(name) = name {
+		if let Err(e) = factory.set_duration_months(name) {
+			return ColorTypes::Error.str(&e.to_string());
+		}
+	}
+
+	let months = factory.settings().duration_months.map(|m| m.to_string()).unwrap_or_else(|| "off".to_string());
+
+	format!("{} {}", style(">").red(), ColorTypes::Default.str(&months))
+}
+
+fn display_rates(factory: &mut Factory, path: Option<&str>) -> String {
+	if let Some(path) = path {
+		let contents = match std::fs::read_to_string(path) {
+			Ok(contents) => contents,
+			Err(e) => return ColorTypes::Error.str(&format!("Unable to read {}: {}", path, e))
+		};
+
+		if let Err(e) = factory.load_exchange_rates(&contents) {
+			return ColorTypes::Error.str(&e.to_string());
+		}
+	}
+
+	let rates = factory.exchange_rates();
+
+	let summary = match (rates.base(), rates.as_of()) {
+		(Some(base), Some(as_of)) => format!("{} rates against {} as of {}", rates.len(), base.code, as_of),
+		(Some(base), None) => format!("{} rates against {}", rates.len(), base.code),
+		_ => "No rates loaded".to_string()
+	};
+
+	format!("{} {}", style(">").red(), ColorTypes::Default.str(&summary))
+}
+
+fn display_save(factory: &Factory, path: Option<&str>) -> String {
+	let session = factory.save_session();
+
+	let path = match path {
+		Some(path) => path,
+		None => return session.trim_end().lines().map(|line| format!("{} {}", style(">").red(), ColorTypes::Default.str(line))).collect::<Vec<_>>().join("\n")
+	};
+
+	if let Err(e) = std::fs::write(path, &session) {
+		return ColorTypes::Error.str(&format!("Unable to write {}: {}", path, e));
+	}
+
+	// Every command written. Settings, units, rates and constants.
+	let count = session.lines().filter(|line| !line.is_empty() && !line.starts_with('#')).count();
+
+	format!("{} {}", style(">").red(), ColorTypes::Default.str(&format!("Saved {} lines to {}", count, path)))
+}
+
+fn display_load(factory: &mut Factory, path: &str) -> String {
+	let contents = match std::fs::read_to_string(path) {
+		Ok(contents) => contents,
+		Err(e) => return ColorTypes::Error.str(&format!("Unable to read {}: {}", path, e))
+	};
+
+	if let Err(e) = factory.load_session(&contents) {
+		return ColorTypes::Error.str(&format!("{}: {}", path, e));
+	}
+
+	format!("{} {}", style(">").red(), ColorTypes::Default.str(&format!("Loaded {}", path)))
+}
+
+/// Adds the units and prefixes in a definition file. "parsec (pc) = 3.0857e16 m"
+pub fn display_unit_file(factory: &mut Factory, path: &str) -> String {
+	let contents = match std::fs::read_to_string(path) {
+		Ok(contents) => contents,
+		Err(e) => return ColorTypes::Error.str(&format!("Unable to read {}: {}", path, e))
+	};
+
+	match factory.load_unit_definitions(path, &contents) {
+		Ok(count) => format!("{} {}", style(">").red(), ColorTypes::Default.str(&format!("Loaded {} definitions from {}", count, path))),
+		Err(e) => ColorTypes::Error.str(&e.to_string())
+	}
+}
+
 fn display_const(factory: &Factory) -> String {
 	factory.get_constants()
 	.iter()
-	.map(|(v, _)| ColorTypes::Default.str(v))
-	.map(|s| format!("{} {}", style(">").red(), s))
+	.map(|(name, value)| format!("{} {} {}", style(">").red(), ColorTypes::Default.str(&format!("{:<8}", name)), colorize_tokens(space_tokens(value.clone().into_tokens()))))
+	.collect::<Vec<String>>()
+	.join("\n")
+}
+
+fn display_physics() -> String {
+	physical_constants()
+	.into_iter()
+	.map(|constant| {
+		let (mantissa, exponent, unit) = constant.si_value();
+
+		// Scientific notation for very small and large values.
+		let mut value = if !(-3..9).contains(&exponent) {
+			format!("{}e{}", mantissa, exponent)
+		} else if exponent < 0 {
+			(mantissa / Decimal::from(10_i64.pow(exponent.unsigned_abs()))).normalize().to_string()
+		} else {
+			(mantissa * Decimal::from(10_i64.pow(exponent as u32))).normalize().to_string()
+		};
+
+		if let Some(unit) = unit {
+			value += &format!(" {}", unit);
+		}
+
+		format!(
+			"{} {} {:<36} {:<12} {:<36} {}",
+			style(">").red(),
+			ColorTypes::Default.str(&format!("{:<8}", constant.name)),
+			value,
+			format!("({})", constant.unit),
+			constant.description,
+			style(constant.source).dim()
+		)
+	})
 	.collect::<Vec<String>>()
 	.join("\n")
 }
 
-fn display_func(factory: &Factory) -> String {
+fn display_func(factory: &Factory, name: Option<&str>) -> String {
+	if let Some(name) = name {
+		let info = match factory.find_func(name) {
+			Some(func) => func.info(),
+			None => return ColorTypes::Error.str(&format!(r#"Unknown function "{}""#, name))
+		};
+
+		let mut lines = vec![
+			format!("{} {}", style(">").red(), ColorTypes::Default.str(&info.signature_as(name))),
+			format!("  {}", info.description)
+		];
+
+		lines.extend(info.examples.iter().map(|e| format!("  {}", ColorTypes::Number.str(e))));
+
+		return lines.join("\n");
+	}
+
 	factory.get_functions()
 	.iter()
-	.map(|(v, _)| ColorTypes::Default.str(v))
-	.map(|s| format!("{} {}", style(">").red(), s))
+	.map(|(name, func)| {
+		let info = func.info();
+
+		format!("{} {:<40} {}", style(">").red(), ColorTypes::Default.str(&info.signature_as(name)), info.description)
+	})
 	.collect::<Vec<String>>()
 	.join("\n")
 }
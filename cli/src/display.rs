@@ -1,4 +1,4 @@
-use conversion_parser::ExprToken;
+use conversion_parser::{ExprToken, Operator};
 use console::Style;
 
 pub enum ColorTypes {
@@ -47,7 +47,9 @@ impl From<&ExprToken> for ColorTypes {
 			ExprToken::Number(_) => ColorTypes::Number,
 			ExprToken::Operator(_) => ColorTypes::Operator,
 			ExprToken::StartGrouping |
-			ExprToken::EndGrouping => ColorTypes::Grouping,
+			ExprToken::EndGrouping |
+			ExprToken::StartList |
+			ExprToken::EndList => ColorTypes::Grouping,
 			_ => ColorTypes::Default
 		}
 	}
@@ -65,16 +67,28 @@ pub fn space_tokens(tokens: Vec<ExprToken>) -> Vec<ExprToken> {
 			break;
 		}
 
+		// [1, 2, 3], x = 1; y = 2
+		if matches!(iter.peek(), Some(ExprToken::Comma) | Some(ExprToken::Semicolon) | Some(ExprToken::EndList)) {
+			continue;
+		}
+
+		// Fractions. 2 3/8
+		let is_divide = |t: &ExprToken| t == &ExprToken::Operator(Operator::Divide);
+
+		if (token.is_number() && is_divide(iter.peek().unwrap())) || (is_divide(&token) && iter.peek().unwrap().is_number()) {
+			continue;
+		}
+
 		match token {
 			ExprToken::Number(_) => if iter.peek().unwrap().is_literal() {
 				continue;
 			}
 
-			ExprToken::Literal(_) => if iter.peek().unwrap().is_literal() {
-				continue;
-			}
+			// Imaginary numbers and exponents keep their unit separate. 2i m, 12.5e3 m
+			ExprToken::Literal(ref value) if value != "i" && !is_exponent(value) && iter.peek().unwrap().is_literal() => continue,
 
-			ExprToken::Whitespace => continue,
+			ExprToken::Whitespace |
+			ExprToken::StartList => continue,
 
 			_ => {}
 		}
@@ -85,6 +99,14 @@ pub fn space_tokens(tokens: Vec<ExprToken>) -> Vec<ExprToken> {
 	spaced
 }
 
+/// e3, e-6
+fn is_exponent(value: &str) -> bool {
+	value.strip_prefix('e')
+		.map(|exp| exp.trim_start_matches('-'))
+		.map(|exp| !exp.is_empty() && exp.chars().all(|c| c.is_ascii_digit()))
+		.unwrap_or_default()
+}
+
 pub fn colorize_tokens(tokens: Vec<ExprToken>) -> String {
 	tokens.into_iter()
 	.map(|t| {
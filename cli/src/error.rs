@@ -18,7 +18,8 @@ impl From<ParserError> for Error {
 
 impl fmt::Display for Error {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		f.debug_struct("Error")
-		.finish()
+		match self {
+			Error::ParserError(e) => e.fmt(f)
+		}
 	}
 }
\ No newline at end of file
@@ -1,6 +1,9 @@
+use std::env;
+use std::path::PathBuf;
+
 use console::Term;
 
-use conversion_parser::{Factory, ExprToken, Operator};
+use conversion_parser::{Factory, ExprToken, ParseValue};
 
 pub mod error;
 pub mod display;
@@ -8,7 +11,7 @@ pub mod commands;
 
 pub use error::{Result, Error};
 pub use display::{ColorTypes, colorize_tokens, space_tokens};
-pub use commands::{Command, get_command};
+pub use commands::{Command, get_command, get_file_command};
 
 
 fn main() {
@@ -16,39 +19,82 @@ fn main() {
 
 	let mut factory = Factory::new();
 
+	if let Some(path) = unit_definitions_path().filter(|path| path.is_file()) {
+		let _ = term.write_line(&commands::display_unit_file(&mut factory, &path.display().to_string()));
+	}
+
 	while let Ok(line) = term.read_line() {
-		parse_line(&line, &mut factory, &term)
-		.expect("parse_line");
+		if let Err(e) = parse_line(&line, &mut factory, &term) {
+			let _ = term.write_line(&ColorTypes::Error.str(&e.to_string()));
+		}
 	}
 }
 
 
 fn parse_line(line: &str, factory: &mut Factory, term: &Term) -> Result<()> {
-	let value = factory.parse(line)?;
+	// File paths aren't expressions. "save work.calc", "rates ~/rates.csv"
+	if let Some((name, path)) = line.trim().split_once(char::is_whitespace) {
+		if let Some(cmd) = get_file_command(name, path.trim()) {
+			let _ = term.write_line(&cmd.display(factory));
 
-	let tokens = value.into_tokens();
+			return Ok(());
+		}
+	}
 
-	// Check to see if it's possibly a command.
-	if tokens.len() == 1 {
-		if let Some(ExprToken::Literal(value)) = tokens.get(0) {
-			if let Some(cmd) = get_command(value) {
-				let _ = term.write_line(&cmd.display(factory));
-				return Ok(());
-			}
+	// Setting a constant. "x = PI * 180", "v = 30 km/h"
+	// Otherwise it's an equation. "x = 2x - 3"
+	if let Some((name, eval)) = split_assignment(line) {
+		let mut parser = factory.create_parser(eval);
+		let value = parser.parse().ok().and_then(|v| v.into_value());
+
+		// "x = 2x - 3" uses the name. It's not an assignment.
+		let uses_name = parser.get_parsed_tokens().contains(&ExprToken::Literal(name.to_string()));
+
+		if let Some(value) = value.filter(|_| !uses_name) {
+			factory.add_constant(name.to_string(), value.clone())?;
+
+			let _ = term.write_line(&colorize_tokens(space_tokens(value.into_tokens())));
+
+			return Ok(());
 		}
 	}
 
-	// Check if trying to set constant.
-	else if tokens.len() == 3 {
-		let mut iter = tokens.iter().peekable();
+	let value = factory.parse(line)?;
+
+	// Adding a unit or prefix. "define furlong = 201.168 m", "prefix kibi- (Ki-) = 1024"
+	if let ParseValue::Definition(definition) = value {
+		let shown = definition.to_string();
 
-		let name = iter.next().unwrap();
-		let op = iter.next().unwrap();
-		let value = iter.next().unwrap();
+		factory.add_definition(definition)?;
 
-		// TODO: Expand to be able to use something like "x = PI * 180"
-		if name.is_literal() && op.is_operator() && op.as_operator() == &Operator::Equal && value.is_number() {
-			factory.add_constant(name.clone().into_literal(), value.clone().into_number());
+		let _ = term.write_line(&ColorTypes::Default.str(&shown));
+
+		return Ok(());
+	}
+
+	let tokens = value.into_tokens();
+
+	// Check to see if it's possibly a command.
+	if tokens.len() <= 2 {
+		if let Some(ExprToken::Literal(value)) = tokens.get(0) {
+			// Commands can take a single argument. "angle deg", "frac 16"
+			let number;
+
+			let arg = match tokens.get(1) {
+				Some(ExprToken::Literal(arg)) => Some(arg.as_str()),
+				Some(ExprToken::Number(arg)) => {
+					number = arg.to_string();
+					Some(number.as_str())
+				}
+				_ => None
+			};
+
+			if tokens.len() == 1 || arg.is_some() {
+				if let Some(cmd) = get_command(value, arg) {
+					let _ = term.write_line(&cmd.display(factory));
+					return Ok(());
+				}
+			}
 		}
 	}
 
@@ -57,4 +103,30 @@ fn parse_line(line: &str, factory: &mut Factory, term: &Term) -> Result<()> {
 	let _ = term.write_line(&colorize_tokens(tokens));
 
 	Ok(())
-}
\ No newline at end of file
+}
+
+/// Units loaded on start. ~/.config/conversion/units.txt
+fn unit_definitions_path() -> Option<PathBuf> {
+	let config = env::var_os("XDG_CONFIG_HOME")
+		.filter(|path| !path.is_empty())
+		.map(PathBuf::from)
+		.or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+		.or_else(|| env::var_os("APPDATA").map(PathBuf::from))?;
+
+	Some(config.join("conversion").join("units.txt"))
+}
+
+/// "name = expression" where name is a single word.
+fn split_assignment(line: &str) -> Option<(&str, &str)> {
+	let (name, eval) = line.split_once('=')?;
+	let name = name.trim();
+
+	let is_name = name.chars().next().map(|c| !c.is_ascii_digit()).unwrap_or_default()
+		&& name.chars().all(|c| c.is_alphanumeric() || c == '_');
+
+	if is_name && !eval.starts_with('=') {
+		Some((name, eval))
+	} else {
+		None
+	}
+}
@@ -5,7 +5,10 @@ pub type Result<I> = std::result::Result<I, Error>;
 #[derive(Debug, Clone)]
 pub enum Error {
 	ExpectedArgument,
-	MissingUnit
+	MissingUnit,
+	InvalidArgument(String),
+	DivideByZero,
+	UnableToConvertValues(String, String)
 }
 
 
@@ -13,7 +16,10 @@ impl fmt::Display for Error {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
 			Error::ExpectedArgument => write!(f, "Expected Argument"),
-			Error::MissingUnit => write!(f, "Missing Unit")
+			Error::MissingUnit => write!(f, "Missing Unit"),
+			Error::InvalidArgument(v) => write!(f, "Invalid Argument: {}", v),
+			Error::DivideByZero => write!(f, "Division by zero"),
+			Error::UnableToConvertValues(a, b) => write!(f, r#"Values of type "{}" and "{}" are not able to be compaired or converted."#, a, b)
 		}
 	}
 }
\ No newline at end of file
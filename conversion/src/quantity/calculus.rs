@@ -0,0 +1,494 @@
+// Numerical methods for expressions without a closed form.
+// https://en.wikipedia.org/wiki/Newton%27s_method
+// https://en.wikipedia.org/wiki/Bisection_method
+// https://en.wikipedia.org/wiki/Richardson_extrapolation
+// https://en.wikipedia.org/wiki/Adaptive_Simpson%27s_method
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use rust_decimal::Decimal;
+
+use crate::{Error, Result, Units, is_convertable};
+use super::{Quantity, FunctionEval, FunctionResult, FunctionInfo, Param, Dimension, Lambda, Argument};
+
+
+const MAX_ITERATIONS: usize = 100;
+/// Intervals of an integral are split in half at most this many times.
+const MAX_DEPTH: usize = 60;
+/// Stops expressions which don't converge (singularities, fast oscillation) from running for minutes.
+const MAX_EVALUATIONS: usize = 100_000;
+
+/// Results are rounded to this many significant digits to remove numerical noise.
+const ROOT_PRECISION: u32 = 20;
+const DERIVATIVE_PRECISION: u32 = 16;
+const INTEGRAL_PRECISION: u32 = 15;
+
+
+/// root(x^2 - 2, x, 1) = 1.414...
+///
+/// Newton's method from the guess. Falls back to bisection once a sign change is found around it.
+#[derive(Debug, Clone)]
+pub struct Root;
+
+impl FunctionEval for Root {
+	fn eval(&self, _: Vec<Quantity>) -> FunctionResult {
+		Err(expects_expression("root"))
+	}
+
+	fn eval_args(&self, args: Vec<Argument<'_>>) -> FunctionResult {
+		let mut iter = args.into_iter();
+
+		let lambda = next(&mut iter)?.into_expression()?;
+		next(&mut iter)?;
+		let guess = next(&mut iter)?.into_quantity()?;
+
+		let input = match guess.unit() {
+			Ok(unit) => Some(unit.clone()),
+			Err(_) => implied_unit(lambda, guess.amount())
+		};
+
+		let mut f = Sampled::new(lambda, input.clone());
+
+		let amount = find_root(&mut f, guess.amount())?;
+
+		Ok(Quantity::new_unit(round(amount, ROOT_PRECISION), input))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "root",
+			params: vec![Param::new("f", Dimension::Expression), Param::new("x", Dimension::Unknown), Param::any("guess")],
+			min_args: 3,
+			max_args: Some(3),
+			description: "Value of x near the guess where f is zero.",
+			examples: &["root(x^2 - 2, x, 1)", "root(cos(x) - x, x, 1)"]
+		}
+	}
+}
+
+
+/// deriv(x^3, x, 2) = 12
+///
+/// Central differences with Richardson extrapolation.
+#[derive(Debug, Clone)]
+pub struct Derivative;
+
+impl FunctionEval for Derivative {
+	fn eval(&self, _: Vec<Quantity>) -> FunctionResult {
+		Err(expects_expression("deriv"))
+	}
+
+	fn eval_args(&self, args: Vec<Argument<'_>>) -> FunctionResult {
+		let mut iter = args.into_iter();
+
+		let lambda = next(&mut iter)?.into_expression()?;
+		next(&mut iter)?;
+		let at = next(&mut iter)?.into_quantity()?;
+
+		let input = at.unit().ok().cloned();
+		let mut f = Sampled::new(lambda, input.clone());
+
+		let x = at.amount();
+		let h = x.abs().max(Decimal::ONE) * Decimal::new(1, 5);
+
+		// The differences around a singularity still give a (meaningless) slope. deriv(1/x, x, 0)
+		f.eval(x)?;
+
+		let slope = |f: &mut Sampled<'_>, h: Decimal| -> Result<Decimal> {
+			let (above, below) = x.checked_add(h).zip(x.checked_sub(h)).ok_or_else(|| too_large("derivative"))?;
+
+			let rise = f.eval(above)?.checked_sub(f.eval(below)?).ok_or_else(|| too_large("derivative"))?;
+
+			Ok(rise / (Decimal::TWO * h))
+		};
+
+		let wide = slope(&mut f, h)?;
+		let narrow = slope(&mut f, h / Decimal::TWO)?;
+
+		// Removes the h² error term.
+		let amount = narrow + (narrow - wide) / Decimal::from(3);
+
+		Ok(Quantity::new_unit(round(amount, DERIVATIVE_PRECISION), f.into_output()) / Quantity::new_unit(Decimal::ONE, input))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "deriv",
+			params: vec![Param::new("f", Dimension::Expression), Param::new("x", Dimension::Unknown), Param::any("at")],
+			min_args: 3,
+			max_args: Some(3),
+			description: "Derivative of f with respect to x at a value.",
+			examples: &["deriv(x^3, x, 2)", "deriv(5 m/s² * x^2, x, 2 s)"]
+		}
+	}
+}
+
+
+/// integrate(x^2, x, 0, 3) = 9
+///
+/// Adaptive Simpson's rule with Richardson extrapolation. The result is in the units of f multiplied by the units of x.
+#[derive(Debug, Clone)]
+pub struct Integrate;
+
+impl FunctionEval for Integrate {
+	fn eval(&self, _: Vec<Quantity>) -> FunctionResult {
+		Err(expects_expression("integrate"))
+	}
+
+	fn eval_args(&self, args: Vec<Argument<'_>>) -> FunctionResult {
+		let mut iter = args.into_iter();
+
+		let lambda = next(&mut iter)?.into_expression()?;
+		next(&mut iter)?;
+		let start = next(&mut iter)?.into_quantity()?;
+		let end = next(&mut iter)?.into_quantity()?;
+
+		let input = start.unit().ok().or_else(|| end.unit().ok()).cloned();
+
+		let a = in_unit(start, &input)?;
+		let b = in_unit(end, &input)?;
+
+		let mut f = Sampled::new(lambda, input.clone());
+
+		let amount = if a == b {
+			Decimal::ZERO
+		} else {
+			adaptive_simpson(&mut f, a, b)?
+		};
+
+		Ok(Quantity::new_unit(round(amount, INTEGRAL_PRECISION), f.into_output()) * Quantity::new_unit(Decimal::ONE, input))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "integrate",
+			params: vec![Param::new("f", Dimension::Expression), Param::new("x", Dimension::Unknown), Param::any("a"), Param::any("b")],
+			min_args: 4,
+			max_args: Some(4),
+			description: "Integral of f with respect to x from a to b.",
+			examples: &["integrate(x^2, x, 0, 3)", "integrate(9.8 m/s² * x, x, 0 s, 10 s)"]
+		}
+	}
+}
+
+
+/// Evaluates an expression with plain numbers. The unknown is given `input` units and every
+/// result is converted into the units of the first one.
+struct Sampled<'a> {
+	lambda: &'a dyn Lambda,
+	input: Option<Units>,
+	output: Option<Option<Units>>,
+	evaluations: usize
+}
+
+impl<'a> Sampled<'a> {
+	fn new(lambda: &'a dyn Lambda, input: Option<Units>) -> Self {
+		Sampled {
+			lambda,
+			input,
+			output: None,
+			evaluations: 0
+		}
+	}
+
+	fn eval(&mut self, x: Decimal) -> Result<Decimal> {
+		self.evaluations += 1;
+
+		if self.evaluations > MAX_EVALUATIONS {
+			return Err(Error::InvalidArgument(format!("the expression didn't converge after {} evaluations", MAX_EVALUATIONS)));
+		}
+
+		let value = self.lambda.call(Quantity::new_unit(x, self.input.clone())).map_err(|e| match e {
+			Error::DivideByZero => Error::InvalidArgument(format!("the expression has a singularity at {}", x.normalize())),
+			e => e
+		})?;
+
+		match &self.output {
+			Some(unit) => in_unit(value, unit),
+			None => {
+				let amount = value.amount();
+				self.output = Some(value.into_unit());
+				Ok(amount)
+			}
+		}
+	}
+
+	fn into_output(self) -> Option<Units> {
+		self.output.flatten()
+	}
+}
+
+
+/// The units x needs when the guess is a plain number. root(x - 1 m, x, 0) is 1 m
+///
+/// Only when x can take the units of f. 2 m * x - 4 m stays a number.
+fn implied_unit(lambda: &dyn Lambda, guess: Decimal) -> Option<Units> {
+	let unit = lambda.call(Quantity::new(guess)).ok()?.into_unit()?;
+
+	if unit.is_dimensionless() {
+		return None;
+	}
+
+	match lambda.call(Quantity::new_unit(guess, Some(unit.clone()))).ok()?.unit() {
+		Ok(with_unit) if is_convertable(with_unit, &unit) => Some(unit),
+		_ => None
+	}
+}
+
+fn find_root(f: &mut Sampled<'_>, guess: Decimal) -> Result<Decimal> {
+	let start = f.eval(guess)?;
+
+	if start.is_zero() {
+		return Ok(guess);
+	}
+
+	if let Some(x) = newton(f, guess) {
+		return Ok(x);
+	}
+
+	let (low, high) = bracket(f, guess, start).ok_or_else(|| Error::InvalidArgument(format!("root didn't find a solution near {}", guess)))?;
+
+	let x = bisect(f, low, high);
+
+	// The sign also changes across a pole. root(1/x, x, 1)
+	if f.eval(x)?.abs() > start.abs() {
+		return Err(Error::InvalidArgument(format!("root found a singularity near {} instead of a solution", round(x, ROOT_PRECISION))));
+	}
+
+	Ok(x)
+}
+
+/// None if it doesn't converge. Large steps are stopped before they can overflow.
+fn newton(f: &mut Sampled<'_>, guess: Decimal) -> Option<Decimal> {
+	let limit = guess.abs().max(Decimal::ONE) * Decimal::from(1_000_000_000_000_000_u64);
+
+	let mut x = guess;
+
+	for _ in 0..MAX_ITERATIONS {
+		let fx = f.eval(x).ok()?;
+
+		if fx.is_zero() {
+			return Some(x);
+		}
+
+		let h = x.abs().max(Decimal::ONE) * Decimal::new(1, 10);
+		let derivative = (f.eval(x + h).ok()?.checked_sub(f.eval(x - h).ok()?)?).checked_div(Decimal::TWO * h)?;
+
+		let step = fx.checked_div(derivative)?;
+
+		x = x.checked_sub(step)?;
+
+		if x.abs() > limit {
+			return None;
+		}
+
+		if step.abs() <= x.abs().max(Decimal::ONE) * Decimal::new(1, 20) {
+			return Some(x);
+		}
+	}
+
+	None
+}
+
+/// Widens an interval around the guess until the sign changes.
+fn bracket(f: &mut Sampled<'_>, guess: Decimal, start: Decimal) -> Option<(Decimal, Decimal)> {
+	let mut width = guess.abs().max(Decimal::ONE) * Decimal::new(1, 3);
+
+	for _ in 0..MAX_ITERATIONS {
+		for x in [guess + width, guess - width] {
+			if let Ok(value) = f.eval(x) {
+				if value.is_zero() || value.is_sign_negative() != start.is_sign_negative() {
+					return Some((guess.min(x), guess.max(x)));
+				}
+			}
+		}
+
+		width = width.checked_mul(Decimal::TWO)?;
+
+		if width > Decimal::from(1_000_000_000_000_000_000_u64) {
+			return None;
+		}
+	}
+
+	None
+}
+
+fn bisect(f: &mut Sampled<'_>, mut low: Decimal, mut high: Decimal) -> Decimal {
+	let low_negative = f.eval(low).map(|v| v.is_sign_negative()).unwrap_or_default();
+
+	for _ in 0..MAX_ITERATIONS * 2 {
+		let middle = (low + high) / Decimal::TWO;
+
+		if middle == low || middle == high {
+			break;
+		}
+
+		match f.eval(middle) {
+			Ok(value) if value.is_zero() => return middle,
+			Ok(value) if value.is_sign_negative() == low_negative => low = middle,
+			_ => high = middle
+		}
+	}
+
+	(low + high) / Decimal::TWO
+}
+
+
+/// Panels the interval starts as, so a function which oscillates isn't judged from five samples.
+const INITIAL_PANELS: usize = 16;
+
+/// Simpson's rule on an interval and on both of its halves.
+struct Panel {
+	start: Decimal,
+	end: Decimal,
+	/// f at the start, first quarter, middle, third quarter and end.
+	values: [Decimal; 5],
+	/// The halves extrapolated with the difference from the whole.
+	value: Decimal,
+	/// Estimated error of the value.
+	error: Decimal,
+	depth: usize
+}
+
+impl Panel {
+	fn new(f: &mut Sampled<'_>, (start, end): (Decimal, Decimal), [first, middle, last]: [Decimal; 3], depth: usize) -> Result<Self> {
+		let width = end.checked_sub(start).ok_or_else(|| too_large("integral"))?;
+		let quarter = width / Decimal::from(4);
+
+		let values = [first, f.eval(start + quarter)?, middle, f.eval(end - quarter)?, last];
+
+		let estimate = || -> Option<(Decimal, Decimal)> {
+			let whole = simpson(width, values[0], values[2], values[4])?;
+			let halves = simpson(width / Decimal::TWO, values[0], values[1], values[2])?
+				.checked_add(simpson(width / Decimal::TWO, values[2], values[3], values[4])?)?;
+
+			let correction = halves.checked_sub(whole)? / Decimal::from(15);
+
+			Some((halves.checked_add(correction)?, correction.abs()))
+		};
+
+		let (value, error) = estimate().ok_or_else(|| too_large("integral"))?;
+
+		Ok(Panel { start, end, values, value, error, depth })
+	}
+
+	/// Both halves reuse three of the values.
+	fn split(self, f: &mut Sampled<'_>) -> Result<(Panel, Panel)> {
+		let middle = self.start + (self.end - self.start) / Decimal::TWO;
+		let [first, left, center, right, last] = self.values;
+
+		Ok((
+			Panel::new(f, (self.start, middle), [first, left, center], self.depth + 1)?,
+			Panel::new(f, (middle, self.end), [center, right, last], self.depth + 1)?
+		))
+	}
+
+	/// Integral of |f| from the same values.
+	fn magnitude(&self) -> Option<Decimal> {
+		let [first, left, center, right, last] = self.values.map(|v| v.abs());
+		let half = (self.end - self.start) / Decimal::TWO;
+
+		simpson(half, first, left, center)?.checked_add(simpson(half, center, right, last)?).map(|v| v.abs())
+	}
+}
+
+impl PartialEq for Panel {
+	fn eq(&self, other: &Self) -> bool {
+		self.error == other.error
+	}
+}
+
+impl Eq for Panel {}
+
+impl PartialOrd for Panel {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+/// Panels with larger errors are split first.
+impl Ord for Panel {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.error.cmp(&other.error)
+	}
+}
+
+/// width / 6 · (first + 4 · middle + last). None if it's too large.
+fn simpson(width: Decimal, first: Decimal, middle: Decimal, last: Decimal) -> Option<Decimal> {
+	let sum = first.checked_add(middle.checked_mul(Decimal::from(4))?)?.checked_add(last)?;
+
+	(width / Decimal::from(6)).checked_mul(sum)
+}
+
+/// Keeps splitting the panel with the largest error until the total error is small enough.
+fn adaptive_simpson(f: &mut Sampled<'_>, a: Decimal, b: Decimal) -> Result<Decimal> {
+	let step = b.checked_sub(a).ok_or_else(|| too_large("integral"))? / Decimal::from(INITIAL_PANELS * 2);
+
+	// The ends and middles of the panels.
+	let values = (0..=INITIAL_PANELS * 2)
+		.map(|i| if i == INITIAL_PANELS * 2 { b } else { a + step * Decimal::from(i) })
+		.map(|x| Ok((x, f.eval(x)?)))
+		.collect::<Result<Vec<_>>>()?;
+
+	let mut panels = BinaryHeap::new();
+
+	for points in values.windows(3).step_by(2) {
+		panels.push(Panel::new(f, (points[0].0, points[2].0), [points[0].1, points[1].1, points[2].1], 0)?);
+	}
+
+	// Relative to the integral of |f| so integrals which cancel out to zero still finish.
+	let magnitude = panels.iter().try_fold(Decimal::ZERO, |total, panel| total.checked_add(panel.magnitude()?)).ok_or_else(|| too_large("integral"))?;
+	let tolerance = magnitude * Decimal::new(1, 14);
+
+	let mut error = panels.iter().try_fold(Decimal::ZERO, |total, panel| total.checked_add(panel.error)).ok_or_else(|| too_large("integral"))?;
+
+	while error > tolerance {
+		let Some(worst) = panels.pop() else { break };
+
+		if worst.depth == MAX_DEPTH {
+			let middle = worst.start + (worst.end - worst.start) / Decimal::TWO;
+
+			return Err(Error::InvalidArgument(format!("the integral didn't converge near {}", round(middle, 6))));
+		}
+
+		let removed = error - worst.error;
+
+		let (left, right) = worst.split(f)?;
+
+		error = removed.checked_add(left.error).and_then(|e| e.checked_add(right.error)).ok_or_else(|| too_large("integral"))?;
+
+		panels.push(left);
+		panels.push(right);
+	}
+
+	panels.iter().try_fold(Decimal::ZERO, |total, panel| total.checked_add(panel.value)).ok_or_else(|| too_large("integral"))
+}
+
+
+/// Amount of the value in `unit`. Values without a unit are assumed to already be in it.
+fn in_unit(value: Quantity, unit: &Option<Units>) -> Result<Decimal> {
+	match (value.unit(), unit) {
+		(Ok(from), Some(to)) if is_convertable(from, to) => Ok(value.total_amount() / to.total_factor()),
+		(Ok(from), Some(to)) => Err(Error::UnableToConvertValues(from.long(), to.long())),
+		(Ok(from), None) if !from.is_dimensionless() => Err(Error::UnableToConvertValues(from.long(), "number".to_string())),
+		(Ok(_), None) => Ok(value.total_amount()),
+		(Err(_), _) => Ok(value.amount())
+	}
+}
+
+fn round(value: Decimal, digits: u32) -> Decimal {
+	value.round_sf(digits).unwrap_or(value).normalize()
+}
+
+fn next<'a, F: Iterator<Item = Argument<'a>>>(args: &mut F) -> Result<Argument<'a>> {
+	args.next().ok_or(Error::ExpectedArgument)
+}
+
+fn too_large(name: &str) -> Error {
+	Error::InvalidArgument(format!("the {} is too large to represent", name))
+}
+
+fn expects_expression(name: &str) -> Error {
+	Error::InvalidArgument(format!("{} expects an expression", name))
+}
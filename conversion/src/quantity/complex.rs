@@ -0,0 +1,156 @@
+// https://en.wikipedia.org/wiki/Complex_number
+
+use std::{ops, fmt};
+
+use rust_decimal::Decimal;
+
+use super::transcendental;
+
+
+/// A complex number. re + im·i
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex {
+	pub re: Decimal,
+	pub im: Decimal
+}
+
+impl Complex {
+	pub const fn new(re: Decimal, im: Decimal) -> Self {
+		Complex { re, im }
+	}
+
+	pub fn real(re: Decimal) -> Self {
+		Complex::new(re, Decimal::ZERO)
+	}
+
+	/// From a magnitude and an angle in radians. 5∠30deg
+	///
+	/// Parts which are only left over from rounding are removed. 1∠90deg = i
+	pub fn from_polar(magnitude: Decimal, angle: Decimal) -> Option<Self> {
+		let re = magnitude * transcendental::cos(angle)?;
+		let im = magnitude * transcendental::sin(angle)?;
+
+		let noise = magnitude.abs() * Decimal::new(1, 20);
+
+		Some(Complex::new(
+			if re.abs() <= noise { Decimal::ZERO } else { re },
+			if im.abs() <= noise { Decimal::ZERO } else { im }
+		))
+	}
+
+	pub fn is_real(&self) -> bool {
+		self.im.is_zero()
+	}
+
+	pub fn is_zero(&self) -> bool {
+		self.re.is_zero() && self.im.is_zero()
+	}
+
+	pub fn conj(self) -> Self {
+		Complex::new(self.re, -self.im)
+	}
+
+	/// Magnitude.
+	pub fn abs(&self) -> Option<Decimal> {
+		transcendental::hypot(self.re, self.im)
+	}
+
+	/// Angle in radians. -π < arg <= π
+	pub fn arg(&self) -> Option<Decimal> {
+		transcendental::atan2(self.im, self.re)
+	}
+
+	/// Raised to a whole number. Negative powers are the reciprocal.
+	pub fn powi(self, exp: i64) -> Self {
+		let mut result = Complex::real(Decimal::ONE);
+		let mut base = self;
+		let mut remaining = exp.unsigned_abs();
+
+		while remaining > 0 {
+			if remaining & 1 == 1 {
+				result = result * base;
+			}
+
+			base = base * base;
+			remaining >>= 1;
+		}
+
+		if exp < 0 {
+			Complex::real(Decimal::ONE) / result
+		} else {
+			result
+		}
+	}
+
+	/// Rounds both parts to `digits` significant digits and removes trailing zeros.
+	pub fn round_sf(self, digits: u32) -> Self {
+		let round = |v: Decimal| v.round_sf(digits).unwrap_or(v).normalize();
+
+		Complex::new(round(self.re), round(self.im))
+	}
+}
+
+
+impl ops::Add for Complex {
+	type Output = Complex;
+
+	fn add(self, other: Complex) -> Complex {
+		Complex::new(self.re + other.re, self.im + other.im)
+	}
+}
+
+impl ops::Sub for Complex {
+	type Output = Complex;
+
+	fn sub(self, other: Complex) -> Complex {
+		Complex::new(self.re - other.re, self.im - other.im)
+	}
+}
+
+impl ops::Mul for Complex {
+	type Output = Complex;
+
+	fn mul(self, other: Complex) -> Complex {
+		Complex::new(
+			self.re * other.re - self.im * other.im,
+			self.re * other.im + self.im * other.re
+		)
+	}
+}
+
+impl ops::Div for Complex {
+	type Output = Complex;
+
+	fn div(self, other: Complex) -> Complex {
+		let denominator = other.re * other.re + other.im * other.im;
+
+		Complex::new(
+			(self.re * other.re + self.im * other.im) / denominator,
+			(self.im * other.re - self.re * other.im) / denominator
+		)
+	}
+}
+
+impl ops::Neg for Complex {
+	type Output = Complex;
+
+	fn neg(self) -> Complex {
+		Complex::new(-self.re, -self.im)
+	}
+}
+
+
+impl fmt::Display for Complex {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// 3 + 4i, 3 - 4i, 4i
+		if self.re.is_zero() && !self.im.is_zero() {
+			write!(f, "{}i", self.im)
+		} else if self.im.is_zero() {
+			write!(f, "{}", self.re)
+		} else if self.im.is_sign_negative() {
+			write!(f, "{} - {}i", self.re, -self.im)
+		} else {
+			write!(f, "{} + {}i", self.re, self.im)
+		}
+	}
+}
@@ -0,0 +1,280 @@
+// https://howardhinnant.github.io/date_algorithms.html
+
+use std::fmt;
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+use super::timezone::{TimeZone, parse_offset, format_offset};
+
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Dates are kept between the years -999999 and 999999.
+const MAX_YEAR: i64 = 999_999;
+
+pub const WEEKDAYS: [&str; 7] = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+
+
+/// A calendar date with an optional time of day. 2026-10-18, 2026-10-18T09:30
+///
+/// Stored as seconds since 1970-01-01T00:00. Without a time zone it's the same in every zone.
+/// With one the seconds are UTC and it's shown on that zone's clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DateTime {
+	seconds: Decimal,
+	has_time: bool,
+	zone: Option<TimeZone>,
+	/// Seconds the zone is ahead of UTC at this instant.
+	offset: i32
+}
+
+impl DateTime {
+	/// None outside the years -999999 to 999999.
+	pub fn from_seconds(seconds: Decimal, has_time: bool) -> Option<Self> {
+		let first = Decimal::from(days_from_civil(-MAX_YEAR, 1, 1) * SECONDS_PER_DAY);
+		let last = Decimal::from(days_from_civil(MAX_YEAR + 1, 1, 1) * SECONDS_PER_DAY);
+
+		if seconds < first || seconds >= last {
+			return None;
+		}
+
+		Some(DateTime { seconds, has_time, zone: None, offset: 0 })
+	}
+
+	/// The instant a zone's clock reads `local` seconds since 1970-01-01.
+	/// None if the zone's offset isn't known at that time.
+	pub fn from_local(local: Decimal, zone: TimeZone) -> Option<Self> {
+		DateTime::from_seconds(zone.to_utc(local)?, true)?.with_zone(zone)
+	}
+
+	pub fn from_ymd(year: i64, month: u32, day: u32) -> Option<Self> {
+		if !(-MAX_YEAR..=MAX_YEAR).contains(&year) || !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+			return None;
+		}
+
+		DateTime::from_seconds(Decimal::from(days_from_civil(year, month, day) * SECONDS_PER_DAY), false)
+	}
+
+	pub fn from_ymd_hms(year: i64, month: u32, day: u32, hour: u32, minute: u32, second: Decimal) -> Option<Self> {
+		if hour > 23 || minute > 59 || second.is_sign_negative() || second >= Decimal::from(60) {
+			return None;
+		}
+
+		let date = DateTime::from_ymd(year, month, day)?;
+
+		let seconds = date.seconds + Decimal::from(hour * 3600 + minute * 60) + second;
+
+		DateTime::from_seconds(seconds, true)
+	}
+
+	/// 2026-10-18, 2026-10-18T09:30, 2026-10-18T09:30:15.5
+	///
+	/// Times may end in Z or an offset from UTC. 2026-10-18T09:30+02:00
+	pub fn parse(value: &str) -> Option<Self> {
+		let (date, time) = match value.split_once('T') {
+			Some((date, time)) => (date, Some(time)),
+			None => (value, None)
+		};
+
+		let mut parts = date.splitn(3, '-');
+
+		let year = parts.next()?.parse().ok()?;
+		let month = parts.next()?.parse().ok()?;
+		let day = parts.next()?.parse().ok()?;
+
+		let time = match time {
+			Some(time) => time,
+			None => return DateTime::from_ymd(year, month, day)
+		};
+
+		let (time, offset) = match time.find(['Z', '+', '-']) {
+			Some(pos) => (&time[..pos], Some(parse_offset(&time[pos..])?)),
+			None => (time, None)
+		};
+
+		let mut parts = time.splitn(3, ':');
+
+		let hour = parts.next()?.parse().ok()?;
+		let minute = parts.next()?.parse().ok()?;
+		let second = match parts.next() {
+			Some(second) => second.parse().ok()?,
+			None => Decimal::ZERO
+		};
+
+		let date = DateTime::from_ymd_hms(year, month, day, hour, minute, second)?;
+
+		Some(match offset {
+			Some(offset) => DateTime::from_local(date.seconds, TimeZone::Fixed(offset))?,
+			None => date
+		})
+	}
+
+	pub fn seconds(&self) -> Decimal {
+		self.seconds
+	}
+
+	pub fn has_time(&self) -> bool {
+		self.has_time
+	}
+
+	pub fn zone(&self) -> Option<TimeZone> {
+		self.zone
+	}
+
+	/// The same instant on another zone's clock. None if the zone's offset isn't known at that time.
+	pub fn with_zone(self, zone: TimeZone) -> Option<Self> {
+		let offset = zone.offset(self.seconds.floor().to_i64()?)?;
+
+		Some(DateTime { has_time: true, zone: Some(zone), offset, ..self })
+	}
+
+	pub fn with_time(self) -> Self {
+		DateTime { has_time: true, ..self }
+	}
+
+	/// The start of the day on the zone's clock, without a time or zone.
+	pub fn start_of_day(&self) -> Self {
+		DateTime { seconds: Decimal::from(self.days() * SECONDS_PER_DAY), has_time: false, zone: None, offset: 0 }
+	}
+
+	/// Seconds ahead of UTC. 0 without a zone.
+	pub fn offset(&self) -> i32 {
+		self.offset
+	}
+
+	/// Seconds since 1970-01-01 on the zone's clock.
+	pub fn local_seconds(&self) -> Decimal {
+		self.seconds + Decimal::from(self.offset())
+	}
+
+	/// Days since 1970-01-01 on the zone's clock.
+	pub fn days(&self) -> i64 {
+		(self.local_seconds() / Decimal::from(SECONDS_PER_DAY)).floor().to_i64().unwrap_or_default()
+	}
+
+	/// Year, month and day.
+	pub fn date(&self) -> (i64, u32, u32) {
+		civil_from_days(self.days())
+	}
+
+	/// Hour, minute and second.
+	pub fn time(&self) -> (u32, u32, Decimal) {
+		let seconds = self.local_seconds() - Decimal::from(self.days() * SECONDS_PER_DAY);
+		let whole = seconds.trunc().to_u32().unwrap_or_default();
+
+		(whole / 3600, whole / 60 % 60, seconds - Decimal::from(whole / 60 * 60))
+	}
+
+	/// 1 is Monday, 7 is Sunday.
+	pub fn weekday(&self) -> u32 {
+		// 1970-01-01 was a Thursday.
+		((self.days() + 3).rem_euclid(7) + 1) as u32
+	}
+
+	/// Adding part of a day gives the date a time. 2026-10-18 + 6 h = 2026-10-18T06:00
+	pub fn add_seconds(&self, seconds: Decimal) -> Option<Self> {
+		let total = self.seconds.checked_add(seconds)?;
+		let has_time = self.has_time || !(seconds % Decimal::from(SECONDS_PER_DAY)).is_zero();
+
+		let date = DateTime::from_seconds(total, has_time)?;
+
+		match self.zone {
+			Some(zone) => date.with_zone(zone).map(|d| DateTime { has_time, ..d }),
+			None => Some(date)
+		}
+	}
+
+	/// Calendar months. The day is clamped to the end of shorter months. 2026-01-31 + 1 month = 2026-02-28
+	pub fn add_months(&self, months: i64) -> Option<Self> {
+		let (year, month, day) = self.date();
+
+		let index = (year.checked_mul(12)? + month as i64 - 1).checked_add(months)?;
+		let (year, month) = (index.div_euclid(12), (index.rem_euclid(12) + 1) as u32);
+
+		let date = DateTime::from_ymd(year, month, day.min(days_in_month(year, month)))?;
+		let time_of_day = self.local_seconds() - Decimal::from(self.days() * SECONDS_PER_DAY);
+
+		Some(match self.zone {
+			Some(zone) => DateTime::from_local(date.seconds + time_of_day, zone)?,
+			None => DateTime::from_seconds(date.seconds + time_of_day, self.has_time)?
+		})
+	}
+}
+
+impl fmt::Display for DateTime {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (year, month, day) = self.date();
+
+		write!(f, "{:04}-{:02}-{:02}", year, month, day)?;
+
+		if !self.has_time {
+			return Ok(());
+		}
+
+		let (hour, minute, second) = self.time();
+
+		write!(f, "T{:02}:{:02}", hour, minute)?;
+
+		if !second.is_zero() {
+			let second = second.normalize();
+
+			if second < Decimal::TEN {
+				f.write_str(":0")?;
+			} else {
+				f.write_str(":")?;
+			}
+
+			second.fmt(f)?;
+		}
+
+		match self.zone {
+			Some(TimeZone::Fixed(0)) => f.write_str("Z"),
+			Some(TimeZone::Fixed(_)) => f.write_str(&format_offset(self.offset())),
+			Some(TimeZone::Named(name)) => write!(f, "{} {}", format_offset(self.offset()), name),
+			None => Ok(())
+		}
+	}
+}
+
+
+pub fn is_leap_year(year: i64) -> bool {
+	(year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+	match month {
+		2 if is_leap_year(year) => 29,
+		2 => 28,
+		4 | 6 | 9 | 11 => 30,
+		_ => 31
+	}
+}
+
+/// Days since 1970-01-01 in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let year_of_era = year - era * 400;
+	let month = month as i64;
+
+	let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+	era * 146_097 + day_of_era - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+	let days = days + 719_468;
+	let era = days.div_euclid(146_097);
+	let day_of_era = days - era * 146_097;
+	let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let mp = (5 * day_of_year + 2) / 153;
+
+	let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+	let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+	(year, month, day)
+}
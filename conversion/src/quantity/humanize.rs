@@ -0,0 +1,279 @@
+use std::fmt;
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+use crate::{BaseUnit, DateTime, Quantity, Units, Result, Error, is_convertable};
+use crate::units::{Second, Minute, Hour, Day, Week, Month, Year};
+
+
+/// How long the months of a humanized duration are.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MonthLength {
+	/// The average calendar month of the month unit. 30.4375 days
+	Average,
+	/// 30 days.
+	Days30,
+	/// Whole months on the calendar counted from the start date. 2026-10-18 to 2027-01-01 is 2 months 14 days.
+	/// The average month without a start date.
+	Calendar
+}
+
+impl MonthLength {
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"average" => Some(MonthLength::Average),
+			"30d" => Some(MonthLength::Days30),
+			"calendar" => Some(MonthLength::Calendar),
+			_ => None
+		}
+	}
+
+	fn seconds(self) -> Decimal {
+		match self {
+			MonthLength::Average | MonthLength::Calendar => Month.factor_amount(),
+			MonthLength::Days30 => Day.factor_amount() * Decimal::from(30)
+		}
+	}
+}
+
+impl fmt::Display for MonthLength {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			MonthLength::Average => "average",
+			MonthLength::Days30 => "30d",
+			MonthLength::Calendar => "calendar"
+		})
+	}
+}
+
+
+/// How `Quantity::humanize` splits a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DurationFormat {
+	/// 1d 2h 3m 4s instead of 1 day 2 hours 3 minutes 4 seconds.
+	pub compact: bool,
+	/// Most units shown, counted from the largest. The last one is rounded.
+	pub max_parts: Option<usize>,
+	/// Months are skipped when None.
+	pub months: Option<MonthLength>,
+	/// The date the duration is counted from. Used by calendar months.
+	pub start: Option<DateTime>
+}
+
+
+impl Quantity {
+	/// The amount and name of each part of a duration. Parts which are zero are skipped.
+	///
+	/// 93784 s = [(1, "day"), (2, "hours"), (3, "minutes"), (4, "seconds")]
+	pub fn humanize_parts(&self, format: &DurationFormat) -> Result<Vec<(Decimal, String)>> {
+		let total = match self.unit() {
+			Ok(unit) if is_convertable(unit, &Units::new(Box::new(Second))) => self.total_amount(),
+			_ => return Err(Error::InvalidArgument(format!("{} isn't a duration", self)))
+		};
+
+		// Drops the last digits lost while converting. 2 months 3 h = 2 months 2 h 59 min 59.9999… s
+		let total = total.round_sf(20).unwrap_or(total);
+
+		if let (Some(MonthLength::Calendar), Some(start)) = (format.months, format.start) {
+			return calendar_parts(start, total, format).ok_or_else(|| Error::InvalidArgument(format!("{} is too far from {}", self, start)));
+		}
+
+		let mut units: Vec<(Decimal, Box<dyn BaseUnit>, &str)> = vec![(Year.factor_amount(), Box::new(Year), "y")];
+
+		match format.months {
+			Some(months) => units.push((months.seconds(), Box::new(Month), "mo")),
+			None => units.push((Week.factor_amount(), Box::new(Week), "w"))
+		}
+
+		units.push((Day.factor_amount(), Box::new(Day), "d"));
+		units.push((Hour.factor_amount(), Box::new(Hour), "h"));
+		units.push((Minute.factor_amount(), Box::new(Minute), "m"));
+		units.push((Second.factor_amount(), Box::new(Second), "s"));
+
+		let largest = units.iter().position(|(factor, ..)| *factor <= total.abs()).unwrap_or(units.len() - 1);
+		let smallest = format.max_parts.map_or(units.len() - 1, |count| (largest + count.max(1) - 1).min(units.len() - 1));
+
+		let smallest_factor = units[smallest].0;
+
+		let mut remaining = if smallest == units.len() - 1 {
+			total.abs()
+		} else {
+			(total.abs() / smallest_factor).round() * smallest_factor
+		};
+
+		let mut parts = Vec::new();
+
+		for (i, (factor, unit, short)) in units.iter().enumerate().take(smallest + 1) {
+			let amount = if i == smallest {
+				(remaining / factor).normalize()
+			} else {
+				(remaining / factor).trunc()
+			};
+
+			remaining -= amount * factor;
+
+			if !amount.is_zero() {
+				parts.push((amount, unit_name(amount, unit.as_ref(), short, format.compact)));
+			}
+		}
+
+		if parts.is_empty() {
+			let (_, unit, short) = &units[smallest];
+
+			parts.push((Decimal::ZERO, unit_name(Decimal::ZERO, unit.as_ref(), short, format.compact)));
+		}
+
+		// -1 day 2 hours
+		if total.is_sign_negative() {
+			parts[0].0 = -parts[0].0;
+		}
+
+		Ok(parts)
+	}
+
+	/// 93784 s = 1 day 2 hours 3 minutes 4 seconds, or 1d 2h 3m 4s when compact.
+	pub fn humanize(&self, format: &DurationFormat) -> Result<String> {
+		let parts = self.humanize_parts(format)?
+			.into_iter()
+			.map(|(amount, name)| if format.compact { format!("{}{}", amount, name) } else { format!("{} {}", amount, name) })
+			.collect::<Vec<_>>();
+
+		Ok(parts.join(" "))
+	}
+}
+
+
+fn unit_name(amount: Decimal, unit: &dyn BaseUnit, short: &str, compact: bool) -> String {
+	if compact {
+		short.to_string()
+	} else if amount == Decimal::ONE {
+		unit.long().to_string()
+	} else {
+		unit.multiple().to_string()
+	}
+}
+
+/// Years and months on the calendar from `start`, then days down to seconds.
+///
+/// The last part shown is rounded the same as fixed length months. Months round up from half of the month after them.
+fn calendar_parts(start: DateTime, total: Decimal, format: &DurationFormat) -> Option<Vec<(Decimal, String)>> {
+	let (mut months, rest) = calendar_months(start, total)?;
+
+	let direction = if total.is_sign_negative() { -1 } else { 1 };
+
+	// Length of the month after the whole months.
+	let boundary = start.add_months(months)?.seconds();
+	let next_month = (start.add_months(months + direction)?.seconds() - boundary).abs();
+
+	let mut rest = rest.abs();
+	months = months.abs();
+
+	let fixed: [(Box<dyn BaseUnit>, &str); 4] = [(Box::new(Day), "d"), (Box::new(Hour), "h"), (Box::new(Minute), "m"), (Box::new(Second), "s")];
+
+	// Index 0 is years, 1 months, then the fixed units.
+	let largest = if months >= 12 {
+		0
+	} else if months > 0 {
+		1
+	} else {
+		2 + fixed.iter().position(|(unit, _)| unit.factor_amount() <= rest).unwrap_or(fixed.len() - 1)
+	};
+
+	let last = fixed.len() + 1;
+	let smallest = format.max_parts.map_or(last, |count| (largest + count.max(1) - 1).min(last));
+
+	let round_up = rest * Decimal::TWO >= next_month;
+
+	match smallest {
+		0 => {
+			months = (Decimal::from(months + round_up as i64) / Decimal::from(12)).round().to_i64()? * 12;
+			rest = Decimal::ZERO;
+		}
+		1 => {
+			months += round_up as i64;
+			rest = Decimal::ZERO;
+		}
+		i if i != last => {
+			let factor = fixed[i - 2].0.factor_amount();
+
+			rest = (rest / factor).round() * factor;
+
+			// 1 month 30 days 23 hours 59 minutes rounded to minutes in a 31 day month.
+			if rest >= next_month {
+				months += 1;
+				rest -= next_month;
+			}
+		}
+		_ => ()
+	}
+
+	let mut parts = Vec::new();
+
+	for (amount, unit, short) in [(months / 12, Box::new(Year) as Box<dyn BaseUnit>, "y"), (months % 12, Box::new(Month), "mo")].iter().take(smallest + 1) {
+		if *amount != 0 {
+			let amount = Decimal::from(*amount);
+
+			parts.push((amount, unit_name(amount, unit.as_ref(), short, format.compact)));
+		}
+	}
+
+	for (i, (unit, short)) in fixed.iter().enumerate().take(smallest.saturating_sub(1)) {
+		let factor = unit.factor_amount();
+
+		let amount = if i + 2 == smallest {
+			(rest / factor).normalize()
+		} else {
+			(rest / factor).trunc()
+		};
+
+		rest -= amount * factor;
+
+		if !amount.is_zero() {
+			parts.push((amount, unit_name(amount, unit.as_ref(), short, format.compact)));
+		}
+	}
+
+	if parts.is_empty() {
+		let (unit, short): (Box<dyn BaseUnit>, &str) = match smallest {
+			0 => (Box::new(Year), "y"),
+			1 => (Box::new(Month), "mo"),
+			i => (fixed[i - 2].0.clone(), fixed[i - 2].1)
+		};
+
+		parts.push((Decimal::ZERO, unit_name(Decimal::ZERO, unit.as_ref(), short, format.compact)));
+	}
+
+	if total.is_sign_negative() {
+		parts[0].0 = -parts[0].0;
+	}
+
+	Some(parts)
+}
+
+/// Whole calendar months from `start` which fit in `seconds`, and the seconds left over.
+/// Both are negative when counting back.
+fn calendar_months(start: DateTime, seconds: Decimal) -> Option<(i64, Decimal)> {
+	let end = start.add_seconds(seconds)?.seconds();
+
+	// Starts from the average month then moves to the last month boundary before the end.
+	let mut months = (seconds / Month.factor_amount()).trunc().to_i64()?;
+
+	let past_end = |months: i64| -> Option<bool> {
+		let boundary = start.add_months(months)?.seconds();
+
+		Some(if seconds.is_sign_negative() { boundary < end } else { boundary > end })
+	};
+
+	let direction = if seconds.is_sign_negative() { -1 } else { 1 };
+
+	while past_end(months)? {
+		months -= direction;
+	}
+
+	while !past_end(months + direction)? {
+		months += direction;
+	}
+
+	Some((months, end - start.add_months(months)?.seconds()))
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+
+
+/// What a parameter is expected to be.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dimension {
+	/// Any quantity. Units are kept.
+	Any,
+	/// A number without units. Cancelled units (km/m) are allowed.
+	Dimensionless,
+	/// Any angle unit. Bare numbers use the default angle unit.
+	Angle,
+	/// A list of values.
+	List,
+	/// An expression which the function evaluates itself. x^2 in integrate(x^2, x, 0, 1)
+	Expression,
+	/// Name of the unknown in an Expression.
+	Unknown
+}
+
+impl fmt::Display for Dimension {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Dimension::Any => f.write_str("any"),
+			Dimension::Dimensionless => f.write_str("number"),
+			Dimension::Angle => f.write_str("angle"),
+			Dimension::List => f.write_str("list"),
+			Dimension::Expression => f.write_str("expression"),
+			Dimension::Unknown => f.write_str("unknown")
+		}
+	}
+}
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+	pub name: &'static str,
+	pub dimension: Dimension
+}
+
+impl Param {
+	pub const fn new(name: &'static str, dimension: Dimension) -> Self {
+		Param { name, dimension }
+	}
+
+	pub const fn any(name: &'static str) -> Self {
+		Param::new(name, Dimension::Any)
+	}
+
+	pub const fn number(name: &'static str) -> Self {
+		Param::new(name, Dimension::Dimensionless)
+	}
+}
+
+
+/// Describes a function. Used to validate calls and for function listings.
+#[derive(Debug, Clone)]
+pub struct FunctionInfo {
+	pub name: &'static str,
+	pub params: Vec<Param>,
+	pub min_args: usize,
+	/// None if the last parameter can be repeated.
+	pub max_args: Option<usize>,
+	/// One line description.
+	pub description: &'static str,
+	pub examples: &'static [&'static str]
+}
+
+impl FunctionInfo {
+	pub fn is_variadic(&self) -> bool {
+		self.max_args.is_none()
+	}
+
+	pub fn accepts(&self, count: usize) -> bool {
+		count >= self.min_args && self.max_args.map(|max| count <= max).unwrap_or(true)
+	}
+
+	/// "1 argument", "1-2 arguments", "at least 1 argument"
+	pub fn expects(&self) -> String {
+		let (amount, last) = match self.max_args {
+			Some(max) if max == self.min_args => (max.to_string(), max),
+			Some(max) => (format!("{}-{}", self.min_args, max), max),
+			None => (format!("at least {}", self.min_args), self.min_args)
+		};
+
+		format!("{} argument{}", amount, if last == 1 { "" } else { "s" })
+	}
+
+	pub fn signature(&self) -> String {
+		self.signature_as(self.name)
+	}
+
+	/// The signature using a different name. Used for aliases.
+	///
+	/// round(x, [places: number])
+	pub fn signature_as(&self, name: &str) -> String {
+		let params = self.params.iter()
+			.enumerate()
+			.map(|(i, param)| {
+				let mut value = param.name.to_string();
+
+				if param.dimension != Dimension::Any {
+					value += &format!(": {}", param.dimension);
+				}
+
+				if self.is_variadic() && i + 1 == self.params.len() {
+					value += "...";
+				} else if i >= self.min_args {
+					value = format!("[{}]", value);
+				}
+
+				value
+			})
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		format!("{}({})", name, params)
+	}
+}
@@ -1,13 +1,15 @@
 // Basic Math Functions
 
-use crate::{Error, Result};
-use super::{Quantity, FunctionEval, FunctionResult};
+use rust_decimal::{Decimal, RoundingStrategy};
+use rust_decimal::prelude::{ToPrimitive, Signed};
 
-// TODO: Ensure params are all same base type.
+use crate::{Error, Result, Units, Radian, is_convertable};
+use super::{Quantity, Complex, ComplexQuantity, IntervalQuantity, FunctionEval, FunctionResult, FunctionInfo, Param, Dimension};
+use super::transcendental::{self, sqrt};
 
 
 macro_rules! create_single {
-	($struc:ident, $func:ident) => {
+	($struc:ident, $name:literal, $func:ident, $description:literal, [$($example:literal),*]) => {
 		#[derive(Debug, Clone)]
 		pub struct $struc;
 
@@ -21,36 +23,85 @@ macro_rules! create_single {
 
 				Ok(last_item)
 			}
+
+			fn info(&self) -> FunctionInfo {
+				single_info($name, Param::any("x"), $description, &[$($example),*])
+			}
+		}
+	};
+}
+
+/// Functions which only accept dimensionless values. exp(5 m) has no meaning.
+macro_rules! create_dimensionless {
+	($struc:ident, $name:literal, $func:path, $description:literal, [$($example:literal),*]) => {
+		#[derive(Debug, Clone)]
+		pub struct $struc;
+
+		impl FunctionEval for $struc {
+			fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+				let value = dimensionless($name, next(&mut params.into_iter())?)?;
+
+				Ok(Quantity::new($func(value).ok_or_else(|| undefined($name, value))?))
+			}
+
+			fn info(&self) -> FunctionInfo {
+				single_info($name, Param::number("x"), $description, &[$($example),*])
+			}
 		}
 	};
 }
 
-// macro_rules! create_double {
-// 	($struc:ident, $func:ident) => {
-// 		#[derive(Debug, Clone)]
-// 		pub struct $struc;
+/// Trig functions. Accepts any angle unit. sin(30 deg) = 0.5
+macro_rules! create_angle {
+	($struc:ident, $name:literal, $func:path, $description:literal, [$($example:literal),*]) => {
+		#[derive(Debug, Clone)]
+		pub struct $struc;
+
+		impl FunctionEval for $struc {
+			fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+				let value = radians($name, next(&mut params.into_iter())?)?;
+
+				Ok(Quantity::new($func(value).ok_or_else(|| undefined($name, value))?))
+			}
+
+			fn takes_angle(&self) -> bool {
+				true
+			}
 
-// 		impl FunctionEval for $struc {
-// 			fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
-// 				let mut iter = params.into_iter();
+			fn info(&self) -> FunctionInfo {
+				single_info($name, Param::new("angle", Dimension::Angle), $description, &[$($example),*])
+			}
+		}
+	};
+}
 
-// 				let mut first = next(&mut iter)?;
-// 				let second = next(&mut iter)?;
+/// Inverse trig functions. Returns radians.
+macro_rules! create_inverse_angle {
+	($struc:ident, $name:literal, $func:path, $description:literal, [$($example:literal),*]) => {
+		#[derive(Debug, Clone)]
+		pub struct $struc;
 
-// 				first.set_amount(first.amount().$func(second.amount()));
+		impl FunctionEval for $struc {
+			fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+				let value = dimensionless($name, next(&mut params.into_iter())?)?;
 
-// 				Ok(first)
-// 			}
-// 		}
-// 	};
-// }
+				Ok(Quantity::new_unit($func(value).ok_or_else(|| undefined($name, value))?, Some(Units::new(Box::new(Radian)))))
+			}
 
+			fn info(&self) -> FunctionInfo {
+				single_info($name, Param::number("x"), $description, &[$($example),*])
+			}
+		}
+	};
+}
 
 #[derive(Debug, Clone)]
 pub struct Min;
 
 impl FunctionEval for Min {
 	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		ensure_compatible(&params)?;
+
 		let mut iter = params.into_iter();
 
 		let mut last_item = next(&mut iter)?;
@@ -61,6 +112,17 @@ impl FunctionEval for Min {
 
 		Ok(last_item)
 	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "min",
+			params: vec![Param::any("values")],
+			min_args: 1,
+			max_args: None,
+			description: "Smallest value. Units must be compatible.",
+			examples: &["min(1 km, 800 m)"]
+		}
+	}
 }
 
 
@@ -69,6 +131,8 @@ pub struct Max;
 
 impl FunctionEval for Max {
 	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		ensure_compatible(&params)?;
+
 		let mut iter = params.into_iter();
 
 		let mut last_item = next(&mut iter)?;
@@ -79,50 +143,1373 @@ impl FunctionEval for Max {
 
 		Ok(last_item)
 	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "max",
+			params: vec![Param::any("values")],
+			min_args: 1,
+			max_args: None,
+			description: "Largest value. Units must be compatible.",
+			examples: &["max(1 km, 800 m)"]
+		}
+	}
 }
 
 
 
-create_single!(Ceil, ceil);
-create_single!(Floor, floor);
-create_single!(Round, round);
-create_single!(Trunc, trunc);
-create_single!(Fract, fract);
-create_single!(Abs, abs);
-// create_single!(Signum, signum);
-// create_single!(Sqrt, sqrt);
-// create_single!(Exp, exp);
-// create_single!(Exp2, exp2);
-// create_single!(NaturalLogarithm, ln);
-// create_single!(Log2, log2);
-// create_single!(Log10, log10);
-// create_single!(CubicRoot, cbrt);
-// create_single!(Sine, sin);
-// create_single!(Cosine, cos);
-// create_single!(Tangent, tan);
-// create_single!(Arcsine, asin);
-// create_single!(Arctangent, atan);
-// create_single!(Arccosine, acos);
-// create_single!(HyperbolicSine, sinh);
-// create_single!(HyperbolicTangent, tanh);
-// create_single!(HyperbolicCosine, cosh);
-// create_single!(InverseHyperbolicSine, asinh);
-// create_single!(InverseHyperbolicTangent, atanh);
-// create_single!(InverseHyperbolicCosine, acosh);
+#[derive(Debug, Clone)]
+pub struct Sum;
+
+impl FunctionEval for Sum {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let (amounts, unit) = normalize(params)?;
+
+		let total = checked_sum(amounts).ok_or_else(|| too_large("sum"))?;
+
+		Ok(Quantity::new_unit(total, unit))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "sum",
+			params: vec![Param::any("values")],
+			min_args: 1,
+			max_args: None,
+			description: "Adds the values together.",
+			examples: &["sum([1 km, 500 m])"]
+		}
+	}
+}
+
 
-// create_double!(Copysign, copysign);
-// create_double!(DivEuclid, div_euclid);
-// create_double!(RemEuclid, rem_euclid);
-// create_double!(Pow, powf);
-// create_double!(Log, log);
-// create_double!(Hypot, hypot);
-// create_double!(Atan2, atan2);
+#[derive(Debug, Clone)]
+pub struct Average;
 
+impl FunctionEval for Average {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let (amounts, unit) = normalize(params)?;
 
+		Ok(Quantity::new_unit(mean(&amounts), unit))
+	}
 
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "avg",
+			params: vec![Param::any("values")],
+			min_args: 1,
+			max_args: None,
+			description: "Arithmetic mean of the values.",
+			examples: &["avg([1, 2, 3, 4])"]
+		}
+	}
+}
 
 
-fn next<F: Iterator<Item = Quantity>>(params: &mut F) -> Result<Quantity> {
-	params.next()
-	.ok_or(Error::ExpectedArgument)
-}
\ No newline at end of file
+#[derive(Debug, Clone)]
+pub struct Median;
+
+impl FunctionEval for Median {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let (mut amounts, unit) = normalize(params)?;
+
+		amounts.sort();
+
+		let middle = amounts.len() / 2;
+
+		let amount = if amounts.len() % 2 == 0 {
+			(amounts[middle - 1] + amounts[middle]) / Decimal::new(2, 0)
+		} else {
+			amounts[middle]
+		};
+
+		Ok(Quantity::new_unit(amount, unit))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "median",
+			params: vec![Param::any("values")],
+			min_args: 1,
+			max_args: None,
+			description: "Middle value once sorted.",
+			examples: &["median([3, 1, 2])"]
+		}
+	}
+}
+
+
+#[derive(Debug, Clone)]
+pub struct Count;
+
+impl FunctionEval for Count {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		Ok(Quantity::new(Decimal::from(params.len())))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "count",
+			params: vec![Param::any("values")],
+			min_args: 0,
+			max_args: None,
+			description: "Number of values.",
+			examples: &["count([1, 2, 3])"]
+		}
+	}
+}
+
+
+#[derive(Debug, Clone)]
+pub struct Product;
+
+impl FunctionEval for Product {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let mut iter = params.into_iter();
+
+		let mut last_item = next(&mut iter)?;
+
+		for other in iter {
+			last_item = last_item * other;
+		}
+
+		Ok(last_item)
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "product",
+			params: vec![Param::any("values")],
+			min_args: 1,
+			max_args: None,
+			description: "Multiplies the values together.",
+			examples: &["product([2 m, 3 m])"]
+		}
+	}
+}
+
+
+#[derive(Debug, Clone)]
+pub struct Mode;
+
+impl FunctionEval for Mode {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let (mut amounts, unit) = normalize(params)?;
+
+		amounts.sort();
+
+		// Ties return the smallest value.
+		let mut best = (amounts[0], 0);
+		let mut current = (amounts[0], 0);
+
+		for amount in amounts {
+			if amount == current.0 {
+				current.1 += 1;
+			} else {
+				current = (amount, 1);
+			}
+
+			if current.1 > best.1 {
+				best = current;
+			}
+		}
+
+		Ok(Quantity::new_unit(best.0, unit))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "mode",
+			params: vec![Param::any("values")],
+			min_args: 1,
+			max_args: None,
+			description: "Most common value. Ties return the smallest.",
+			examples: &["mode([1, 2, 2, 3])"]
+		}
+	}
+}
+
+
+/// Sample Variance
+#[derive(Debug, Clone)]
+pub struct Variance;
+
+impl FunctionEval for Variance {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let (amounts, unit) = normalize(params)?;
+
+		Ok(Quantity::new_unit(variance("variance", &amounts, true)?, unit.map(|u| u.powi(2))))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "variance",
+			params: vec![Param::any("values")],
+			min_args: 1,
+			max_args: None,
+			description: "Sample variance. Units are squared.",
+			examples: &["variance([2 m, 4 m, 6 m])"]
+		}
+	}
+}
+
+
+/// Population Variance
+#[derive(Debug, Clone)]
+pub struct PopulationVariance;
+
+impl FunctionEval for PopulationVariance {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let (amounts, unit) = normalize(params)?;
+
+		Ok(Quantity::new_unit(variance("pvariance", &amounts, false)?, unit.map(|u| u.powi(2))))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "pvariance",
+			params: vec![Param::any("values")],
+			min_args: 1,
+			max_args: None,
+			description: "Population variance. Units are squared.",
+			examples: &["pvariance([2, 4, 4, 4, 5, 5, 7, 9])"]
+		}
+	}
+}
+
+
+/// Sample Standard Deviation
+#[derive(Debug, Clone)]
+pub struct StandardDeviation;
+
+impl FunctionEval for StandardDeviation {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let (amounts, unit) = normalize(params)?;
+
+		Ok(Quantity::new_unit(std_dev("stdev", &amounts, true)?, unit))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "stdev",
+			params: vec![Param::any("values")],
+			min_args: 1,
+			max_args: None,
+			description: "Sample standard deviation.",
+			examples: &["stdev([2 m, 4 m, 6 m])"]
+		}
+	}
+}
+
+
+/// Population Standard Deviation
+#[derive(Debug, Clone)]
+pub struct PopulationStandardDeviation;
+
+impl FunctionEval for PopulationStandardDeviation {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let (amounts, unit) = normalize(params)?;
+
+		Ok(Quantity::new_unit(std_dev("pstdev", &amounts, false)?, unit))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "pstdev",
+			params: vec![Param::any("values")],
+			min_args: 1,
+			max_args: None,
+			description: "Population standard deviation.",
+			examples: &["pstdev([2, 4, 4, 4, 5, 5, 7, 9])"]
+		}
+	}
+}
+
+
+/// percentile(p, values...)
+///
+/// Linearly interpolates between the closest ranks. p is from 0 to 100.
+#[derive(Debug, Clone)]
+pub struct Percentile;
+
+impl FunctionEval for Percentile {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let mut iter = params.into_iter();
+
+		let percent = next(&mut iter)?;
+
+		if percent.unit().is_ok() || percent.amount() < Decimal::ZERO || percent.amount() > Decimal::ONE_HUNDRED {
+			return Err(Error::InvalidArgument(format!("percentile expects a number from 0 to 100, got {}", percent)));
+		}
+
+		let (mut amounts, unit) = normalize(iter.collect())?;
+
+		amounts.sort();
+
+		let rank = percent.amount() / Decimal::ONE_HUNDRED * Decimal::from(amounts.len() - 1);
+		let lower = rank.floor().to_usize().unwrap_or_default();
+		let upper = rank.ceil().to_usize().unwrap_or_default();
+
+		let amount = amounts[lower] + (amounts[upper] - amounts[lower]) * rank.fract();
+
+		Ok(Quantity::new_unit(amount, unit))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "percentile",
+			params: vec![Param::number("p"), Param::any("values")],
+			min_args: 2,
+			max_args: None,
+			description: "Value below which p percent of the values fall.",
+			examples: &["percentile(90, [1, 2, 3, 4, 5])"]
+		}
+	}
+}
+
+
+/// zscore(x, values...)
+///
+/// How many sample standard deviations x is from the mean of the values.
+#[derive(Debug, Clone)]
+pub struct ZScore;
+
+impl FunctionEval for ZScore {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let (mut amounts, _) = normalize(params)?;
+
+		let value = amounts.remove(0);
+
+		let std_dev = std_dev("zscore", &amounts, true)?;
+
+		if std_dev.is_zero() {
+			return Err(Error::InvalidArgument("zscore values have no deviation".to_string()));
+		}
+
+		let deviation = value.checked_sub(mean(&amounts)).ok_or_else(|| too_large("zscore"))?;
+
+		Ok(Quantity::new(deviation / std_dev))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "zscore",
+			params: vec![Param::any("x"), Param::any("values")],
+			min_args: 2,
+			max_args: None,
+			description: "Sample standard deviations x is from the mean.",
+			examples: &["zscore(6, [2, 4, 6])"]
+		}
+	}
+}
+
+
+/// slope([x...], [y...])
+///
+/// Slope of the least squares regression line. Units are y / x.
+#[derive(Debug, Clone)]
+pub struct RegressionSlope;
+
+impl FunctionEval for RegressionSlope {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let regression = Regression::new(params)?;
+
+		let (unit, factor) = Units::multiply(regression.y_unit.clone(), regression.x_unit.clone().map(|u| u.powi(-1)));
+
+		let slope = regression.slope()?.checked_mul(factor).ok_or_else(|| too_large("slope"))?;
+
+		Ok(Quantity::new_unit(slope, unit))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "slope",
+			params: vec![Param::new("xs", Dimension::List), Param::new("ys", Dimension::List)],
+			min_args: 2,
+			max_args: Some(2),
+			description: "Slope of the least squares line. Units are y / x.",
+			examples: &["slope([1 s, 2 s, 3 s], [2 m, 4 m, 6 m])"]
+		}
+	}
+}
+
+
+/// intercept([x...], [y...])
+///
+/// Y intercept of the least squares regression line. Units are y.
+#[derive(Debug, Clone)]
+pub struct RegressionIntercept;
+
+impl FunctionEval for RegressionIntercept {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let regression = Regression::new(params)?;
+
+		let slope = regression.slope()?;
+
+		let intercept = slope.checked_mul(mean(&regression.xs))
+			.and_then(|offset| mean(&regression.ys).checked_sub(offset))
+			.ok_or_else(|| too_large("intercept"))?;
+
+		Ok(Quantity::new_unit(intercept, regression.y_unit))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "intercept",
+			params: vec![Param::new("xs", Dimension::List), Param::new("ys", Dimension::List)],
+			min_args: 2,
+			max_args: Some(2),
+			description: "Y intercept of the least squares line.",
+			examples: &["intercept([1, 2, 3], [3, 5, 7])"]
+		}
+	}
+}
+
+
+/// correlation([x...], [y...])
+///
+/// Pearson correlation coefficient.
+#[derive(Debug, Clone)]
+pub struct Correlation;
+
+impl FunctionEval for Correlation {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let regression = Regression::new(params)?;
+
+		let (x_squares, y_squares) = (regression.sum_squares(&regression.xs)?, regression.sum_squares(&regression.ys)?);
+
+		// Each root is taken first when the product is too large.
+		let deviation = match x_squares.checked_mul(y_squares) {
+			Some(product) => sqrt(product),
+			None => sqrt(x_squares).zip(sqrt(y_squares)).and_then(|(x, y)| x.checked_mul(y))
+		}.ok_or_else(|| too_large("correlation"))?;
+
+		if deviation.is_zero() {
+			return Err(Error::InvalidArgument("correlation values have no deviation".to_string()));
+		}
+
+		Ok(Quantity::new(regression.sum_products()? / deviation))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "correlation",
+			params: vec![Param::new("xs", Dimension::List), Param::new("ys", Dimension::List)],
+			min_args: 2,
+			max_args: Some(2),
+			description: "Pearson correlation coefficient.",
+			examples: &["correlation([1, 2, 3], [2, 4, 7])"]
+		}
+	}
+}
+
+
+/// Paired x and y values. Lists are flattened into the parameters so the
+/// first half are the x values and the second half are the y values.
+struct Regression {
+	xs: Vec<Decimal>,
+	ys: Vec<Decimal>,
+	x_unit: Option<Units>,
+	y_unit: Option<Units>
+}
+
+impl Regression {
+	fn new(mut params: Vec<Quantity>) -> Result<Self> {
+		if params.len() < 4 || !params.len().is_multiple_of(2) {
+			return Err(Error::InvalidArgument(format!("expected two lists of the same length with at least 2 values, got {} values", params.len())));
+		}
+
+		let ys = params.split_off(params.len() / 2);
+
+		let (xs, x_unit) = normalize(params)?;
+		let (ys, y_unit) = normalize(ys)?;
+
+		Ok(Self { xs, ys, x_unit, y_unit })
+	}
+
+	fn sum_squares(&self, values: &[Decimal]) -> Result<Decimal> {
+		deviation_products(values, values).ok_or_else(|| too_large("regression"))
+	}
+
+	fn sum_products(&self) -> Result<Decimal> {
+		deviation_products(&self.xs, &self.ys).ok_or_else(|| too_large("regression"))
+	}
+
+	fn slope(&self) -> Result<Decimal> {
+		let sum_squares = self.sum_squares(&self.xs)?;
+
+		if sum_squares.is_zero() {
+			return Err(Error::InvalidArgument("regression x values have no deviation".to_string()));
+		}
+
+		Ok(self.sum_products()? / sum_squares)
+	}
+}
+
+
+
+create_single!(Ceil, "ceil", ceil, "Rounds up to the nearest integer.", ["ceil(1.2)"]);
+create_single!(Floor, "floor", floor, "Rounds down to the nearest integer.", ["floor(1.8)"]);
+create_single!(Fract, "fract", fract, "Only the fractional part.", ["fract(1.25)"]);
+create_dimensionless!(Exp, "exp", transcendental::exp, "e raised to the power of x.", ["exp(1)"]);
+create_dimensionless!(Exp2, "exp2", exp2, "2 raised to the power of x.", ["exp2(10)"]);
+create_dimensionless!(NaturalLogarithm, "ln", transcendental::ln, "Natural logarithm.", ["ln(10)"]);
+create_dimensionless!(Log2, "log2", transcendental::log2, "Base 2 logarithm.", ["log2(1024)"]);
+create_dimensionless!(Log10, "log10", transcendental::log10, "Base 10 logarithm.", ["log10(1000)"]);
+create_angle!(Sine, "sin", transcendental::sin, "Sine of an angle.", ["sin(30 deg)", "sin(1 rad)"]);
+create_angle!(Cosine, "cos", transcendental::cos, "Cosine of an angle.", ["cos(60 deg)"]);
+create_angle!(Tangent, "tan", transcendental::tan, "Tangent of an angle.", ["tan(45 deg)"]);
+create_inverse_angle!(Arcsine, "asin", transcendental::asin, "Inverse sine. Returns radians.", ["asin(0.5) -> deg"]);
+create_inverse_angle!(Arccosine, "acos", transcendental::acos, "Inverse cosine. Returns radians.", ["acos(0.5) -> deg"]);
+create_inverse_angle!(Arctangent, "atan", transcendental::atan, "Inverse tangent. Returns radians.", ["atan(1) -> deg"]);
+create_dimensionless!(HyperbolicSine, "sinh", transcendental::sinh, "Hyperbolic sine.", ["sinh(1)"]);
+create_dimensionless!(HyperbolicCosine, "cosh", transcendental::cosh, "Hyperbolic cosine.", ["cosh(1)"]);
+create_dimensionless!(HyperbolicTangent, "tanh", transcendental::tanh, "Hyperbolic tangent.", ["tanh(1)"]);
+create_dimensionless!(InverseHyperbolicSine, "asinh", transcendental::asinh, "Inverse hyperbolic sine.", ["asinh(1)"]);
+create_dimensionless!(InverseHyperbolicCosine, "acosh", transcendental::acosh, "Inverse hyperbolic cosine.", ["acosh(2)"]);
+create_dimensionless!(InverseHyperbolicTangent, "atanh", transcendental::atanh, "Inverse hyperbolic tangent.", ["atanh(0.5)"]);
+
+
+/// abs(-5 m) = 5 m, abs(3 + 4i) = 5
+#[derive(Debug, Clone)]
+pub struct Abs;
+
+impl FunctionEval for Abs {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let mut value = next(&mut params.into_iter())?;
+
+		value.set_amount(value.amount().abs());
+
+		Ok(value)
+	}
+
+	fn eval_complex(&self, params: Vec<ComplexQuantity>) -> Result<ComplexQuantity> {
+		let (value, unit) = next(&mut params.into_iter())?;
+
+		Ok((Complex::real(value.abs().ok_or_else(|| undefined("abs", value.re))?), unit))
+	}
+
+	fn eval_interval(&self, params: Vec<IntervalQuantity>) -> Result<IntervalQuantity> {
+		let (mut lo, mut hi) = next(&mut params.into_iter())?;
+		let (low, high) = (lo.amount(), hi.amount());
+
+		// abs([-3 .. 2]) = [0 .. 3]
+		if low.is_sign_negative() && high.is_sign_positive() {
+			lo.set_amount(Decimal::ZERO);
+			hi.set_amount(high.max(-low));
+		} else if high <= Decimal::ZERO {
+			lo.set_amount(-high);
+			hi.set_amount(-low);
+		}
+
+		Ok((lo, hi))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		single_info("abs", Param::any("x"), "Absolute value. The magnitude of complex numbers.", &["abs(-5 m)", "abs(3 + 4i)"])
+	}
+}
+
+
+/// re(3 + 4i) = 3
+#[derive(Debug, Clone)]
+pub struct RealPart;
+
+impl FunctionEval for RealPart {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		next(&mut params.into_iter())
+	}
+
+	fn eval_complex(&self, params: Vec<ComplexQuantity>) -> Result<ComplexQuantity> {
+		let (value, unit) = next(&mut params.into_iter())?;
+
+		Ok((Complex::real(value.re), unit))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		single_info("re", Param::any("z"), "Real part of a complex number.", &["re(3 + 4i)"])
+	}
+}
+
+
+/// im(3 + 4i) = 4
+#[derive(Debug, Clone)]
+pub struct ImaginaryPart;
+
+impl FunctionEval for ImaginaryPart {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let mut value = next(&mut params.into_iter())?;
+
+		value.set_amount(Decimal::ZERO);
+
+		Ok(value)
+	}
+
+	fn eval_complex(&self, params: Vec<ComplexQuantity>) -> Result<ComplexQuantity> {
+		let (value, unit) = next(&mut params.into_iter())?;
+
+		Ok((Complex::real(value.im), unit))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		single_info("im", Param::any("z"), "Imaginary part of a complex number.", &["im(3 + 4i)"])
+	}
+}
+
+
+/// arg(1 + i) = π/4 rad
+#[derive(Debug, Clone)]
+pub struct ComplexArgument;
+
+impl FunctionEval for ComplexArgument {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let value = next(&mut params.into_iter())?;
+
+		let (angle, _) = self.eval_complex(vec![(Complex::real(value.amount()), None)])?;
+
+		Ok(Quantity::new_unit(angle.re, Some(Units::new(Box::new(Radian)))))
+	}
+
+	fn eval_complex(&self, params: Vec<ComplexQuantity>) -> Result<ComplexQuantity> {
+		let (value, _) = next(&mut params.into_iter())?;
+
+		let angle = value.arg().ok_or_else(|| undefined("arg", value.re))?;
+
+		Ok((Complex::real(angle), Some(Units::new(Box::new(Radian)))))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		single_info("arg", Param::any("z"), "Angle of a complex number. Returns radians.", &["arg(1 + i) -> deg"])
+	}
+}
+
+
+/// conj(3 + 4i) = 3 - 4i
+#[derive(Debug, Clone)]
+pub struct Conjugate;
+
+impl FunctionEval for Conjugate {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		next(&mut params.into_iter())
+	}
+
+	fn eval_complex(&self, params: Vec<ComplexQuantity>) -> Result<ComplexQuantity> {
+		let (value, unit) = next(&mut params.into_iter())?;
+
+		Ok((value.conj(), unit))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		single_info("conj", Param::any("z"), "Complex conjugate.", &["conj(3 + 4i)"])
+	}
+}
+
+
+/// lo([10 kg .. 12 kg]) = 10 kg
+#[derive(Debug, Clone)]
+pub struct LowerBound;
+
+impl FunctionEval for LowerBound {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		next(&mut params.into_iter())
+	}
+
+	fn eval_interval(&self, params: Vec<IntervalQuantity>) -> Result<IntervalQuantity> {
+		let (lo, _) = next(&mut params.into_iter())?;
+
+		Ok((lo.clone(), lo))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		single_info("lo", Param::any("x"), "Lower bound of an interval. Whether a comparison is certainly true.", &["lo([10 kg .. 12 kg])", "lo([1 .. 3] > 2)"])
+	}
+}
+
+
+/// hi([10 kg .. 12 kg]) = 12 kg
+#[derive(Debug, Clone)]
+pub struct UpperBound;
+
+impl FunctionEval for UpperBound {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		next(&mut params.into_iter())
+	}
+
+	fn eval_interval(&self, params: Vec<IntervalQuantity>) -> Result<IntervalQuantity> {
+		let (_, hi) = next(&mut params.into_iter())?;
+
+		Ok((hi.clone(), hi))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		single_info("hi", Param::any("x"), "Upper bound of an interval. Whether a comparison is possibly true.", &["hi([10 kg .. 12 kg])", "hi([1 .. 3] > 2)"])
+	}
+}
+
+
+/// mid([10 kg .. 12 kg]) = 11 kg
+#[derive(Debug, Clone)]
+pub struct Midpoint;
+
+impl FunctionEval for Midpoint {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		next(&mut params.into_iter())
+	}
+
+	fn eval_interval(&self, params: Vec<IntervalQuantity>) -> Result<IntervalQuantity> {
+		let (mut lo, hi) = next(&mut params.into_iter())?;
+
+		lo.set_amount(lo.amount() + (hi.amount() - lo.amount()) / Decimal::TWO);
+
+		Ok((lo.clone(), lo))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		single_info("mid", Param::any("x"), "Midpoint of an interval.", &["mid([10 kg .. 12 kg])"])
+	}
+}
+
+
+/// width([10 kg .. 12 kg]) = 2 kg
+#[derive(Debug, Clone)]
+pub struct Width;
+
+impl FunctionEval for Width {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let mut value = next(&mut params.into_iter())?;
+
+		value.set_amount(Decimal::ZERO);
+
+		Ok(value)
+	}
+
+	fn eval_interval(&self, params: Vec<IntervalQuantity>) -> Result<IntervalQuantity> {
+		let (mut lo, hi) = next(&mut params.into_iter())?;
+
+		lo.set_amount(hi.amount() - lo.amount());
+
+		Ok((lo.clone(), lo))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		single_info("width", Param::any("x"), "Distance between the bounds of an interval.", &["width([10 kg .. 12 kg])"])
+	}
+}
+
+
+/// round(x, places)
+///
+/// Negative places round to the left of the decimal point. round(1234, -2) = 1200
+#[derive(Debug, Clone)]
+pub struct Round;
+
+impl FunctionEval for Round {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		round_places("round", params, RoundingStrategy::MidpointNearestEven)
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "round",
+			params: vec![Param::any("x"), Param::number("places")],
+			min_args: 1,
+			max_args: Some(2),
+			description: "Rounds to the nearest integer or number of decimal places.",
+			examples: &["round(1.5)", "round(3.14159, 2)"]
+		}
+	}
+}
+
+
+/// trunc(x, places)
+#[derive(Debug, Clone)]
+pub struct Trunc;
+
+impl FunctionEval for Trunc {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		round_places("trunc", params, RoundingStrategy::ToZero)
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "trunc",
+			params: vec![Param::any("x"), Param::number("places")],
+			min_args: 1,
+			max_args: Some(2),
+			description: "Removes the fractional part or the digits after a number of decimal places.",
+			examples: &["trunc(-1.8)", "trunc(3.14159, 2)"]
+		}
+	}
+}
+
+
+/// round_to(x, step)
+///
+/// The step can have units of the same dimension. round_to(1.234 m, 5 cm) = 1.25 m
+#[derive(Debug, Clone)]
+pub struct RoundTo;
+
+impl FunctionEval for RoundTo {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let mut iter = params.into_iter();
+
+		let value = next(&mut iter)?;
+		let step = next(&mut iter)?;
+
+		if let (Ok(from), Ok(to)) = (value.unit(), step.unit()) {
+			if !is_convertable(from, to) {
+				return Err(Error::InvalidArgument(format!("round_to can't round {} to a step in {}", from.long(), to.long())));
+			}
+		}
+
+		let step_amount = step.total_amount();
+
+		if step_amount.is_zero() {
+			return Err(Error::InvalidArgument("round_to expects a step which isn't zero".to_string()));
+		}
+
+		let steps = value.total_amount()
+			.checked_div(step_amount)
+			.ok_or_else(|| undefined("round_to", value.amount()))?
+			.round();
+
+		let amount = steps.checked_mul(step_amount).ok_or_else(|| undefined("round_to", value.amount()))?;
+
+		let unit = value.into_unit().or_else(|| step.into_unit());
+
+		let mut value = Quantity::new_from_base_unit(amount, unit);
+		value.set_amount(value.amount().normalize());
+
+		Ok(value)
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "round_to",
+			params: vec![Param::any("x"), Param::any("step")],
+			min_args: 2,
+			max_args: Some(2),
+			description: "Rounds to the nearest multiple of step.",
+			examples: &["round_to(1.23, 0.05)", "round_to(1.234 m, 5 cm)"]
+		}
+	}
+}
+
+
+/// sigfig(x, digits)
+#[derive(Debug, Clone)]
+pub struct SignificantFigures;
+
+impl FunctionEval for SignificantFigures {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let mut iter = params.into_iter();
+
+		let mut value = next(&mut iter)?;
+		let digits = dimensionless("sigfig", next(&mut iter)?)?;
+
+		let digits = digits.to_u32()
+			.filter(|d| digits.fract().is_zero() && (1..=28).contains(d))
+			.ok_or_else(|| Error::InvalidArgument(format!("sigfig expects between 1 and 28 digits, got {}", digits)))?;
+
+		let amount = value.amount().round_sf(digits).ok_or_else(|| undefined("sigfig", value.amount()))?;
+
+		value.set_amount(amount);
+
+		Ok(value)
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "sigfig",
+			params: vec![Param::any("x"), Param::number("digits")],
+			min_args: 2,
+			max_args: Some(2),
+			description: "Rounds to a number of significant figures.",
+			examples: &["sigfig(123456, 2)", "sigfig(0.0012345, 3)"]
+		}
+	}
+}
+
+
+/// Negative places are to the left of the decimal point.
+fn round_places(name: &str, params: Vec<Quantity>, strategy: RoundingStrategy) -> FunctionResult {
+	let mut iter = params.into_iter();
+
+	let mut value = next(&mut iter)?;
+
+	let places = match iter.next() {
+		Some(places) => dimensionless(name, places)?,
+		None => Decimal::ZERO
+	};
+
+	let places = places.to_i32()
+		.filter(|p| places.fract().is_zero() && p.abs() <= 28)
+		.ok_or_else(|| Error::InvalidArgument(format!("{} expects whole decimal places, got {}", name, places)))?;
+
+	let amount = if places >= 0 {
+		value.amount().round_dp_with_strategy(places as u32, strategy)
+	} else {
+		let factor = transcendental::powi(Decimal::TEN, -places as i64).ok_or_else(|| undefined(name, value.amount()))?;
+
+		(value.amount() / factor).round_dp_with_strategy(0, strategy) * factor
+	};
+
+	value.set_amount(amount);
+
+	Ok(value)
+}
+
+
+#[derive(Debug, Clone)]
+pub struct Signum;
+
+impl FunctionEval for Signum {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let value = next(&mut params.into_iter())?;
+
+		Ok(Quantity::new(value.amount().signum()))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "signum",
+			params: vec![Param::any("x")],
+			min_args: 1,
+			max_args: Some(1),
+			description: "Sign of the value. -1, 0 or 1.",
+			examples: &["signum(-5 m)"]
+		}
+	}
+}
+
+
+/// sqrt(9 m²) = 3 m
+#[derive(Debug, Clone)]
+pub struct Sqrt;
+
+impl FunctionEval for Sqrt {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		root("sqrt", next(&mut params.into_iter())?, 2, transcendental::sqrt)
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "sqrt",
+			params: vec![Param::any("x")],
+			min_args: 1,
+			max_args: Some(1),
+			description: "Square root. Units are rooted.",
+			examples: &["sqrt(2)", "sqrt(9 m²)"]
+		}
+	}
+}
+
+
+/// cbrt(27 m³) = 3 m
+#[derive(Debug, Clone)]
+pub struct CubicRoot;
+
+impl FunctionEval for CubicRoot {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		root("cbrt", next(&mut params.into_iter())?, 3, transcendental::cbrt)
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "cbrt",
+			params: vec![Param::any("x")],
+			min_args: 1,
+			max_args: Some(1),
+			description: "Cube root. Units are rooted.",
+			examples: &["cbrt(27 m³)"]
+		}
+	}
+}
+
+
+/// powf(3 m, 2) = 9 m²
+///
+/// Fractional exponents only keep units when the result is a whole unit. powf(9 m², 0.5) = 3 m
+#[derive(Debug, Clone)]
+pub struct Pow;
+
+impl FunctionEval for Pow {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let mut iter = params.into_iter();
+
+		let value = next(&mut iter)?;
+		let exp = dimensionless("powf", next(&mut iter)?)?;
+
+		let unit = match value.unit() {
+			Ok(unit) => {
+				let found = (1..10).find_map(|root| {
+					let power = exp * Decimal::from(root);
+
+					if power.fract().is_zero() {
+						unit.powi(power.to_i32()?).root(root)
+					} else {
+						None
+					}
+				});
+
+				Some(found.ok_or_else(|| Error::InvalidArgument(format!("powf of {} to {} doesn't have a unit", unit.long(), exp)))?)
+			}
+
+			Err(_) => None
+		};
+
+		let amount = transcendental::powf(value.amount(), exp).ok_or_else(|| undefined("powf", value.amount()))?;
+
+		Ok(Quantity::new_unit(amount, unit))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "powf",
+			params: vec![Param::any("x"), Param::number("exp")],
+			min_args: 2,
+			max_args: Some(2),
+			description: "x raised to the power of exp.",
+			examples: &["powf(2, 0.5)", "powf(3 m, 2)"]
+		}
+	}
+}
+
+
+/// log(x, base)
+#[derive(Debug, Clone)]
+pub struct Log;
+
+impl FunctionEval for Log {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let mut iter = params.into_iter();
+
+		let value = dimensionless("log", next(&mut iter)?)?;
+		let base = dimensionless("log", next(&mut iter)?)?;
+
+		Ok(Quantity::new(transcendental::log(value, base).ok_or_else(|| undefined("log", value))?))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "log",
+			params: vec![Param::number("x"), Param::number("base")],
+			min_args: 2,
+			max_args: Some(2),
+			description: "Logarithm of x in any base.",
+			examples: &["log(8, 2)"]
+		}
+	}
+}
+
+
+/// hypot(3 m, 4 m) = 5 m
+#[derive(Debug, Clone)]
+pub struct Hypot;
+
+impl FunctionEval for Hypot {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let mut iter = params.into_iter();
+
+		let (amounts, unit) = normalize(vec![next(&mut iter)?, next(&mut iter)?])?;
+
+		let amount = transcendental::hypot(amounts[0], amounts[1]).ok_or_else(|| undefined("hypot", amounts[0]))?;
+
+		Ok(Quantity::new_unit(amount, unit))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "hypot",
+			params: vec![Param::any("x"), Param::any("y")],
+			min_args: 2,
+			max_args: Some(2),
+			description: "Length of the hypotenuse. Units must be compatible.",
+			examples: &["hypot(3 m, 4 m)"]
+		}
+	}
+}
+
+
+/// atan2(y, x). Returns radians.
+#[derive(Debug, Clone)]
+pub struct Atan2;
+
+impl FunctionEval for Atan2 {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let mut iter = params.into_iter();
+
+		let (amounts, _) = normalize(vec![next(&mut iter)?, next(&mut iter)?])?;
+
+		let amount = transcendental::atan2(amounts[0], amounts[1]).ok_or_else(|| undefined("atan2", amounts[0]))?;
+
+		Ok(Quantity::new_unit(amount, Some(Units::new(Box::new(Radian)))))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "atan2",
+			params: vec![Param::any("y"), Param::any("x")],
+			min_args: 2,
+			max_args: Some(2),
+			description: "Angle of the point (x, y). Returns radians.",
+			examples: &["atan2(1, -1) -> deg"]
+		}
+	}
+}
+
+
+/// copysign(5 m, -1) = -5 m
+#[derive(Debug, Clone)]
+pub struct Copysign;
+
+impl FunctionEval for Copysign {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let mut iter = params.into_iter();
+
+		let mut value = next(&mut iter)?;
+		let sign = next(&mut iter)?;
+
+		let mut amount = value.amount().abs();
+		amount.set_sign_negative(sign.amount().is_sign_negative());
+
+		value.set_amount(amount);
+
+		Ok(value)
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "copysign",
+			params: vec![Param::any("x"), Param::any("sign")],
+			min_args: 2,
+			max_args: Some(2),
+			description: "x with the sign of another value.",
+			examples: &["copysign(5 m, -1)"]
+		}
+	}
+}
+
+
+/// divEuclid(7 m, 2 m) = 3
+#[derive(Debug, Clone)]
+pub struct DivEuclid;
+
+impl FunctionEval for DivEuclid {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let mut iter = params.into_iter();
+
+		let value = next(&mut iter)?;
+		let divisor = non_zero("divEuclid", next(&mut iter)?)?;
+
+		let is_negative = divisor.amount().is_sign_negative();
+
+		let mut quotient = value / divisor;
+
+		if is_negative {
+			quotient.set_amount(quotient.amount().ceil());
+		} else {
+			quotient.set_amount(quotient.amount().floor());
+		}
+
+		Ok(quotient)
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "divEuclid",
+			params: vec![Param::any("x"), Param::any("divisor")],
+			min_args: 2,
+			max_args: Some(2),
+			description: "Euclidean division.",
+			examples: &["divEuclid(7 m, 2 m)"]
+		}
+	}
+}
+
+
+/// remEuclid(-7 m, 2 m) = 1 m
+#[derive(Debug, Clone)]
+pub struct RemEuclid;
+
+impl FunctionEval for RemEuclid {
+	fn eval(&self, params: Vec<Quantity>) -> FunctionResult {
+		let mut iter = params.into_iter();
+
+		let value = next(&mut iter)?;
+		let divisor = non_zero("remEuclid", next(&mut iter)?)?;
+
+		let (amounts, unit) = normalize(vec![value, divisor])?;
+
+		let mut amount = amounts[0] % amounts[1];
+
+		if amount.is_sign_negative() && !amount.is_zero() {
+			amount += amounts[1].abs();
+		}
+
+		Ok(Quantity::new_unit(amount, unit))
+	}
+
+	fn info(&self) -> FunctionInfo {
+		FunctionInfo {
+			name: "remEuclid",
+			params: vec![Param::any("x"), Param::any("divisor")],
+			min_args: 2,
+			max_args: Some(2),
+			description: "Euclidean remainder. Never negative.",
+			examples: &["remEuclid(-7 m, 2 m)"]
+		}
+	}
+}
+
+
+
+
+
+fn next<T, F: Iterator<Item = T>>(params: &mut F) -> Result<T> {
+	params.next()
+	.ok_or(Error::ExpectedArgument)
+}
+
+/// Errors if any of the parameters units can't be converted into each other.
+pub fn ensure_compatible(params: &[Quantity]) -> Result<()> {
+	let mut units = params.iter().filter_map(|q| q.unit().ok());
+
+	if let Some(first) = units.next() {
+		for unit in units {
+			if !is_convertable(first, unit) {
+				return Err(Error::UnableToConvertValues(first.long(), unit.long()));
+			}
+		}
+	}
+
+	Ok(())
+}
+
+/// Converts every parameter into the largest unit given.
+///
+/// Returns the converted amounts along with the unit they're now in.
+/// Parameters without a unit are assumed to already be in that unit.
+pub fn normalize(params: Vec<Quantity>) -> Result<(Vec<Decimal>, Option<Units>)> {
+	if params.is_empty() {
+		return Err(Error::ExpectedArgument);
+	}
+
+	ensure_compatible(&params)?;
+
+	let unit = params.iter()
+		.filter_map(|q| q.unit().ok())
+		.max()
+		.cloned();
+
+	let amounts = params.into_iter()
+		.map(|q| match (q.unit(), unit.as_ref()) {
+			(Ok(_), Some(to)) => q.total_amount() / to.total_factor(),
+			_ => q.amount()
+		})
+		.collect();
+
+	Ok((amounts, unit))
+}
+
+/// None if the total is too large.
+fn checked_sum<I: IntoIterator<Item = Decimal>>(amounts: I) -> Option<Decimal> {
+	amounts.into_iter().try_fold(Decimal::ZERO, |total, amount| total.checked_add(amount))
+}
+
+/// Divides each amount first when the total is too large.
+fn mean(amounts: &[Decimal]) -> Decimal {
+	let count = Decimal::from(amounts.len());
+
+	match checked_sum(amounts.iter().copied()) {
+		Some(total) => total / count,
+		None => amounts.iter().map(|a| a / count).sum()
+	}
+}
+
+/// Sum of (x - x̄)(y - ȳ). Passing the same values twice sums the squared deviations. None if it's too large.
+fn deviation_products(xs: &[Decimal], ys: &[Decimal]) -> Option<Decimal> {
+	let (x_mean, y_mean) = (mean(xs), mean(ys));
+
+	xs.iter()
+		.zip(ys)
+		.try_fold(Decimal::ZERO, |total, (x, y)| total.checked_add(x.checked_sub(x_mean)?.checked_mul(y.checked_sub(y_mean)?)?))
+}
+
+/// Sample variance divides by n - 1, population variance by n.
+fn variance(name: &str, amounts: &[Decimal], sample: bool) -> Result<Decimal> {
+	let count = if sample { amounts.len().saturating_sub(1) } else { amounts.len() };
+
+	if count == 0 {
+		return Err(Error::ExpectedArgument);
+	}
+
+	let squares = deviation_products(amounts, amounts).ok_or_else(|| too_large(name))?;
+
+	Ok(squares / Decimal::from(count))
+}
+
+fn std_dev(name: &str, amounts: &[Decimal], sample: bool) -> Result<Decimal> {
+	sqrt(variance(name, amounts, sample)?).ok_or(Error::ExpectedArgument)
+}
+
+/// Returns the amount if it doesn't have a dimension. Cancelled units (km/m) are converted.
+fn dimensionless(name: &str, value: Quantity) -> Result<Decimal> {
+	match value.unit() {
+		Ok(unit) if !unit.is_dimensionless() => Err(Error::InvalidArgument(format!("{} expects a dimensionless value, got {}", name, unit.long()))),
+		_ => Ok(value.total_amount())
+	}
+}
+
+/// Returns the angle in radians. Bare numbers are already radians.
+fn radians(name: &str, value: Quantity) -> Result<Decimal> {
+	match value.unit() {
+		Ok(unit) if !unit.is_dimensionless() && !is_convertable(unit, &Units::new(Box::new(Radian))) => {
+			Err(Error::InvalidArgument(format!("{} expects an angle, got {}", name, unit.long())))
+		}
+
+		_ => Ok(value.total_amount())
+	}
+}
+
+fn single_info(name: &'static str, param: Param, description: &'static str, examples: &'static [&'static str]) -> FunctionInfo {
+	FunctionInfo {
+		name,
+		params: vec![param],
+		min_args: 1,
+		max_args: Some(1),
+		description,
+		examples
+	}
+}
+
+fn non_zero(name: &str, value: Quantity) -> Result<Quantity> {
+	if value.amount().is_zero() {
+		Err(Error::InvalidArgument(format!("{} can't divide by zero", name)))
+	} else {
+		Ok(value)
+	}
+}
+
+fn too_large(name: &str) -> Error {
+	Error::InvalidArgument(format!("{} values are too large to represent", name))
+}
+
+fn undefined(name: &str, value: Decimal) -> Error {
+	Error::InvalidArgument(format!("{}({}) is undefined or too large to represent", name, value))
+}
+
+fn root<F: Fn(Decimal) -> Option<Decimal>>(name: &str, value: Quantity, root: i32, func: F) -> FunctionResult {
+	let unit = match value.unit() {
+		Ok(unit) => Some(unit.root(root).ok_or_else(|| Error::InvalidArgument(format!("{} of {} doesn't have a unit", name, unit.long())))?),
+		Err(_) => None
+	};
+
+	let amount = func(value.amount()).ok_or_else(|| undefined(name, value.amount()))?;
+
+	Ok(Quantity::new_unit(amount, unit))
+}
+
+fn exp2(value: Decimal) -> Option<Decimal> {
+	transcendental::powf(Decimal::TWO, value)
+}
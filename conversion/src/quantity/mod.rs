@@ -1,4 +1,5 @@
 use std::{ops, fmt, cmp};
+use std::convert::TryFrom;
 use std::cmp::{Ordering, PartialOrd};
 
 use rust_decimal::{Decimal, prelude::FromPrimitive};
@@ -7,10 +8,32 @@ use crate::{BaseUnit, Result, Error};
 
 
 pub mod math;
+pub mod info;
+pub mod calculus;
+pub mod complex;
+pub mod rational;
+pub mod datetime;
+pub mod humanize;
+pub mod timezone;
 pub mod physics;
+pub mod transcendental;
+
+pub use info::*;
+pub use complex::Complex;
+pub use rational::Rational;
+pub use datetime::DateTime;
+pub use humanize::{DurationFormat, MonthLength};
+pub use timezone::TimeZone;
+pub use physics::*;
 
 pub type FunctionResult = Result<Quantity>;
 
+/// A complex number with units. Real values have no imaginary part.
+pub type ComplexQuantity = (Complex, Option<Units>);
+
+/// The lower and upper bound of an interval, both in the same units.
+pub type IntervalQuantity = (Quantity, Quantity);
+
 
 // Adds the ability to clone Box<dyn FunctionEval>
 pub trait CloneFunctionEval {
@@ -31,49 +54,171 @@ impl Clone for Box<dyn FunctionEval> {
 
 pub trait FunctionEval: fmt::Debug + CloneFunctionEval {
 	fn eval(&self, params: Vec<Quantity>) -> FunctionResult;
+
+	/// Called with the arguments before they're evaluated. Functions with `Dimension::Expression`
+	/// parameters implement this instead of `eval`.
+	fn eval_args(&self, args: Vec<Argument<'_>>) -> FunctionResult {
+		self.eval(args.into_iter().map(Argument::into_quantity).collect::<Result<_>>()?)
+	}
+
+	fn info(&self) -> FunctionInfo;
+
+	/// Called instead of `eval` when a parameter is complex.
+	fn eval_complex(&self, _params: Vec<ComplexQuantity>) -> Result<ComplexQuantity> {
+		Err(Error::InvalidArgument(format!("{} doesn't accept complex numbers", self.info().name)))
+	}
+
+	/// Called instead of `eval` when a parameter is an interval.
+	fn eval_interval(&self, _params: Vec<IntervalQuantity>) -> Result<IntervalQuantity> {
+		Err(Error::InvalidArgument(format!("{} doesn't accept intervals", self.info().name)))
+	}
+
+	/// Parameters are angles. Bare numbers are given the default angle unit before evaluating.
+	fn takes_angle(&self) -> bool {
+		false
+	}
+}
+
+
+/// An unevaluated expression argument. x^2 in integrate(x^2, x, 0, 1)
+pub trait Lambda: fmt::Debug {
+	/// Evaluates the expression with its unknown set to `value`.
+	fn call(&self, value: Quantity) -> FunctionResult;
+}
+
+#[derive(Debug)]
+pub enum Argument<'a> {
+	Value(Quantity),
+	Expression(&'a dyn Lambda),
+	/// Name of the unknown used in an Expression.
+	Unknown(String)
+}
+
+impl<'a> Argument<'a> {
+	pub fn into_quantity(self) -> Result<Quantity> {
+		match self {
+			Argument::Value(value) => Ok(value),
+			Argument::Expression(_) => Err(Error::InvalidArgument("Expected a value, found an expression".to_string())),
+			Argument::Unknown(name) => Err(Error::InvalidArgument(format!(r#"Expected a value, found the unknown "{}""#, name)))
+		}
+	}
+
+	pub fn into_expression(self) -> Result<&'a dyn Lambda> {
+		match self {
+			Argument::Expression(lambda) => Ok(lambda),
+			_ => Err(Error::InvalidArgument("Expected an expression".to_string()))
+		}
+	}
 }
 
 
+/// An amount with optional units.
+///
+/// Amounts which were typed in are also kept as an exact fraction. + - * / and whole powers keep
+/// them exact, anything else (functions, percentages, overflowing) only has the Decimal.
 #[derive(Debug, Clone)]
-pub struct Quantity(Decimal, Option<Units>);
+pub struct Quantity(Decimal, Option<Units>, Option<Rational>);
 
 impl Quantity {
 	pub fn new(value: Decimal) -> Quantity {
-		Quantity(value, None)
+		Quantity(value, None, None)
 	}
 
 	pub fn new_from_base_unit(value: Decimal, unit: Option<Units>) -> Quantity {
 		if let Some(unit) = unit {
-			Quantity(value / unit.base().factor_amount(), Some(unit))
+			Quantity(value / unit.total_factor(), Some(unit), None)
 		} else {
-			Quantity(value, None)
+			Quantity(value, None, None)
 		}
 	}
 
 	pub fn new_unit(value: Decimal, unit: Option<Units>) -> Quantity {
-		Quantity(value, unit)
+		Quantity(value, unit, None)
+	}
+
+	/// A value which was typed in. 0.1 is exactly 1/10
+	pub fn new_exact(value: Decimal, unit: Option<Units>) -> Quantity {
+		Quantity(value, unit, Rational::from_decimal(value))
+	}
+
+	/// None if the fraction is too large for a Decimal.
+	pub fn from_rational(value: Rational, unit: Option<Units>) -> Option<Quantity> {
+		Some(Quantity(value.to_decimal()?, unit, Some(value)))
 	}
 
 	pub fn empty() -> Quantity {
-		Quantity(Decimal::default(), None)
+		Quantity(Decimal::default(), None, None)
 	}
 
-	// pub fn pow(self, exp: Quantity) -> Quantity {
-	// 	Quantity::new_unit(self.amount().powf(exp.amount()), self.1)
-	// }
+	/// Division which errors instead of panicking when the divisor is zero. 1 / 0
+	pub fn checked_div(self, other: Quantity) -> Result<Quantity> {
+		// 200 / 20% divides by 20% of 200.
+		let divisor = if other.unit().map(|u| u.base() == "%").unwrap_or_default() {
+			self.amount() * other.amount()
+		} else {
+			other.amount()
+		};
+
+		if divisor.is_zero() {
+			return Err(Error::DivideByZero);
+		}
+
+		Ok(self / other)
+	}
+
+	/// Units are raised as well. Fractional powers need whole unit powers. (4 m²)^0.5 = 2 m
+	pub fn pow(self, exp: Quantity) -> Result<Quantity> {
+		// (2/3)^2 = 4/9
+		let exact = match (self.2, exp.2, exp.unit()) {
+			(Some(base), Some(power), Err(_)) if power.is_integer() => i32::try_from(power.numer()).ok().and_then(|p| base.checked_powi(p)),
+			_ => None
+		};
+
+		let mut value = math::Pow.eval(vec![self, exp])?;
+		value.set_amount(value.amount().normalize());
+
+		Ok(value.with_exact(exact))
+	}
 
 
 	pub fn amount(&self) -> Decimal {
 		self.0
 	}
 
+	/// The amount is no longer exact.
 	pub fn set_amount(&mut self, value: Decimal) {
 		self.0 = value;
+		self.2 = None;
+	}
+
+	/// The amount as an exact fraction if it's known.
+	pub fn exact(&self) -> Option<Rational> {
+		self.2
+	}
+
+	/// Only the Decimal is kept. Used for irrational constants such as PI.
+	pub fn into_inexact(mut self) -> Self {
+		self.2 = None;
+		self
+	}
+
+	/// Replaces the amount when the exact value fits in a Decimal.
+	fn with_exact(mut self, exact: Option<Rational>) -> Self {
+		match exact.and_then(|e| Some((e, e.to_decimal()?))) {
+			Some((exact, amount)) => {
+				self.0 = amount;
+				self.2 = Some(exact);
+			}
+
+			None => self.2 = None
+		}
+
+		self
 	}
 
 	pub fn total_amount(&self) -> Decimal {
 		if let Ok(unit) = self.unit() {
-			self.amount() * unit.base().factor_amount()
+			self.amount() * unit.total_factor()
 		} else {
 			self.amount()
 		}
@@ -143,6 +288,8 @@ impl ops::Add for Quantity {
 		// - 900GB + 200GB = 1.1TB
 		// - 1h - 30m = 30m
 
+		let exact_total = exact_total(&self, &other, Rational::checked_add);
+
 		let total_amount = if other.unit().map(|u| u.base() == "%").unwrap_or_default() {
 			// 200 + 20% = 240
 			other.remove_units();
@@ -159,7 +306,9 @@ impl ops::Add for Quantity {
 			std::cmp::max
 		);
 
-		Quantity::new_from_base_unit(total_amount, unit)
+		let exact = exact_total.and_then(|total| total.checked_div(exact_factor(unit.as_ref())?));
+
+		Quantity::new_from_base_unit(total_amount, unit).with_exact(exact)
 	}
 }
 
@@ -167,6 +316,8 @@ impl ops::Sub for Quantity {
 	type Output = Quantity;
 
 	fn sub(self, mut other: Quantity) -> Self::Output {
+		let exact_total = exact_total(&self, &other, Rational::checked_sub);
+
 		let total_amount = if other.unit().map(|u| u.base() == "%").unwrap_or_default() {
 			// 200 - 20% = 160
 			other.remove_units();
@@ -183,7 +334,9 @@ impl ops::Sub for Quantity {
 			std::cmp::max
 		);
 
-		Quantity::new_from_base_unit(total_amount, unit)
+		let exact = exact_total.and_then(|total| total.checked_div(exact_factor(unit.as_ref())?));
+
+		Quantity::new_from_base_unit(total_amount, unit).with_exact(exact)
 	}
 }
 
@@ -191,28 +344,23 @@ impl ops::Mul for Quantity {
 	type Output = Quantity;
 
 	fn mul(self, mut other: Quantity) -> Self::Output {
-		// TODO: Re-question on if I should actually use total_amount.
-		// Could cause issues with types like PB, EXA, etc..
-
-		let total_amount = if other.unit().map(|u| u.base() == "%").unwrap_or_default() {
+		if other.unit().map(|u| u.base() == "%").unwrap_or_default() {
 			// 200 * 20% = 8,000
 			other.remove_units();
 
-			self.total_amount() * (self.total_amount() * (other.total_amount() / Decimal::new(100, 0)))
-		} else {
-			self.total_amount() * other.total_amount()
-		};
+			let amount = (self.amount() * (self.amount() * (other.amount() / Decimal::new(100, 0)))).normalize();
 
-		// Return Largest Unit.
-		let unit = return_unit(
-			self.into_unit(),
-			other.into_unit(),
-			std::cmp::max
-		);
+			return Quantity::new_unit(amount, self.into_unit());
+		}
+
+		let operands = exact_operands(&self, &other);
+
+		// 2 m * 3 m = 6 m²
+		let (unit, factor) = Units::multiply(self.1, other.1);
 
-		let factor = unit.as_ref().map(|i| i.base().factor_amount()).unwrap_or_else(|| Decimal::new(1, 0));
+		let exact = operands.and_then(|(l, r)| l.checked_mul(r)?.checked_mul(Rational::from_decimal(factor)?));
 
-		Quantity::new_from_base_unit(total_amount / factor, unit)
+		Quantity::new_unit(self.0 * other.0 * factor, unit).with_exact(exact)
 	}
 }
 
@@ -220,25 +368,33 @@ impl ops::Div for Quantity {
 	type Output = Quantity;
 
 	fn div(self, mut other: Quantity) -> Self::Output {
-		let total_amount = if other.unit().map(|u| u.base() == "%").unwrap_or_default() {
+		if other.unit().map(|u| u.base() == "%").unwrap_or_default() {
 			// 200 / 20% = 5
 			other.remove_units();
 
-			self.total_amount() / (self.total_amount() * (other.total_amount() / Decimal::new(100, 0)))
-		} else {
-			self.total_amount() / other.total_amount()
-		};
+			let amount = (self.amount() / (self.amount() * (other.amount() / Decimal::new(100, 0)))).normalize();
 
-		// Return Largest Unit.
-		let unit = return_unit(
-			self.into_unit(),
-			other.into_unit(),
-			std::cmp::max
-		);
+			return Quantity::new_unit(amount, self.into_unit());
+		}
+
+		let operands = exact_operands(&self, &other);
+
+		// 5 km / 10 km/h = 0.5 h
+		let (unit, factor) = Units::multiply(self.1, other.1.map(|u| u.powi(-1)));
+
+		let exact = operands.and_then(|(l, r)| l.checked_div(r)?.checked_mul(Rational::from_decimal(factor)?));
+
+		Quantity::new_unit(self.0 / other.0 * factor, unit).with_exact(exact)
+	}
+}
+
+impl ops::Neg for Quantity {
+	type Output = Quantity;
 
-		let factor = unit.as_ref().map(|i| i.base().factor_amount()).unwrap_or_else(|| Decimal::new(1, 0));
+	fn neg(self) -> Self::Output {
+		let exact = self.2.and_then(Rational::checked_neg);
 
-		Quantity::new_from_base_unit(total_amount * factor, unit)
+		Quantity(-self.0, self.1, None).with_exact(exact)
 	}
 }
 
@@ -255,6 +411,36 @@ impl PartialEq for Quantity {
 }
 
 
+/// `func(left, right)` in base units. Percentages aren't exact.
+fn exact_total<F>(left: &Quantity, right: &Quantity, func: F) -> Option<Rational> where F: FnOnce(Rational, Rational) -> Option<Rational> {
+	let is_percent = |q: &Quantity| q.unit().map(|u| u.base() == "%").unwrap_or_default();
+
+	if is_percent(left) || is_percent(right) {
+		return None;
+	}
+
+	func(
+		left.2?.checked_mul(exact_factor(left.1.as_ref())?)?,
+		right.2?.checked_mul(exact_factor(right.1.as_ref())?)?
+	)
+}
+
+fn exact_factor(unit: Option<&Units>) -> Option<Rational> {
+	match unit {
+		Some(unit) if !unit.is_exact() => None,
+		Some(unit) => Rational::from_decimal(unit.total_factor()),
+		None => Some(Rational::ONE)
+	}
+}
+
+/// Both exact amounts if their units have exact factors.
+fn exact_operands(left: &Quantity, right: &Quantity) -> Option<(Rational, Rational)> {
+	exact_factor(left.1.as_ref())?;
+	exact_factor(right.1.as_ref())?;
+
+	Some((left.2?, right.2?))
+}
+
 fn return_unit<E, F>(u1: Option<E>, u2: Option<E>, func: F) -> Option<E> where F: FnOnce(E, E) -> E {
 	match (u1, u2) {
 		(Some(u), None) |
@@ -278,84 +464,194 @@ pub enum UnitLocation {
 
 
 // Units.
-// Ex: 1 GB or 1 GB/s
+// Ex: 1 GB, 1 GB/s or 1 m²
+// Stored as each unit along with the power it's raised to.
+// km/h = [(km, 1), (h, -1)]
+
+pub type UnitTerm = (Box<dyn BaseUnit>, i32);
 
 #[derive(Debug, Clone)]
-pub struct Units(Vec<Box<dyn BaseUnit>>);
+pub struct Units(Vec<UnitTerm>);
 
 impl Units {
 	pub fn new(unit: Box<dyn BaseUnit>) -> Self {
-		Self(vec![unit])
+		Self(vec![(unit, 1)])
 	}
 
 	pub fn new_2(unit: Box<dyn BaseUnit>, unit2: Box<dyn BaseUnit>) -> Self {
-		Self(vec![unit, unit2])
+		Self(vec![(unit, 1), (unit2, -1)])
 	}
 
+	/// The first unit is the numerator. The rest are divisors.
 	pub fn new_vec(units: Vec<Box<dyn BaseUnit>>) -> Self {
-		Self(units)
+		Self(
+			units.into_iter()
+			.enumerate()
+			.map(|(i, u)| (u, if i == 0 { 1 } else { -1 }))
+			.collect()
+		)
+	}
+
+	pub fn new_powers(terms: Vec<UnitTerm>) -> Self {
+		Self(terms)
 	}
 
 
 	pub fn base(&self) -> &Box<dyn BaseUnit> {
-		self.0.first().unwrap()
+		&self.0.first().unwrap().0
+	}
+
+	pub fn terms(&self) -> &[UnitTerm] {
+		&self.0
+	}
+
+	/// The base units along with their combined powers.
+	/// km/h and m/s both are [("meter", 1), ("second", -1)]
+	pub fn dimension(&self) -> Vec<(&str, i32)> {
+		let mut dimension: Vec<(&str, i32)> = Vec::new();
+
+		for (unit, exp) in &self.0 {
+			for (name, power) in unit.dimension() {
+				if let Some(found) = dimension.iter_mut().find(|(n, _)| *n == name) {
+					found.1 += power * exp;
+				} else {
+					dimension.push((name, power * exp));
+				}
+			}
+		}
+
+		dimension.retain(|(_, e)| *e != 0);
+		dimension.sort_unstable();
+
+		dimension
 	}
 
-	pub fn base_2(&self) -> Option<&Box<dyn BaseUnit>> {
-		self.0.get(1)
+	pub fn is_dimensionless(&self) -> bool {
+		self.dimension().is_empty()
 	}
 
-	pub fn is_base_equal(&self, other: &Units) -> bool {
-		self.base().base_unit() == other.base().base_unit()
+	/// Returns the (numerator, denominator) of the factor.
+	/// Keeping them separate stops km/h from turning into 0.2777...
+	pub fn factor_parts(&self) -> (Decimal, Decimal) {
+		let mut numerator = Decimal::new(1, 0);
+		let mut denominator = Decimal::new(1, 0);
+
+		for (unit, exp) in &self.0 {
+			let (num, den) = unit.factor_fraction();
+			let (num, den) = (powi(num, exp.abs()), powi(den, exp.abs()));
+
+			if *exp > 0 {
+				numerator *= num;
+				denominator *= den;
+			} else {
+				numerator *= den;
+				denominator *= num;
+			}
+		}
+
+		(numerator, denominator)
 	}
 
-	pub fn is_base_2_equal(&self, other: &Units) -> bool {
-		self.base_2().map(|b| b.base_unit()) ==
-		other.base_2().map(|b| b.base_unit())
+	/// Every unit has an exact factor.
+	pub fn is_exact(&self) -> bool {
+		self.0.iter().all(|(unit, _)| unit.is_exact())
 	}
 
 	pub fn total_factor(&self) -> Decimal {
-		let base = self.base().factor_amount();
+		let (numerator, denominator) = self.factor_parts();
 
-		if let Some(div) = self.base_2() {
-			base / div.factor_amount()
+		numerator / denominator
+	}
+
+	pub fn powi(&self, exp: i32) -> Units {
+		Units(self.0.iter().map(|(u, e)| (u.clone(), e * exp)).collect())
+	}
+
+	/// Only works if every power is divisible by the root.
+	pub fn root(&self, root: i32) -> Option<Units> {
+		if root == 0 || self.0.iter().any(|(_, e)| e % root != 0) {
+			None
 		} else {
-			base
+			Some(Units(self.0.iter().map(|(u, e)| (u.clone(), e / root)).collect()))
 		}
 	}
 
-	pub fn long(&self) -> String {
-		let base = self.base().long();
+	/// Multiplies the units together. Returns the new units along with the
+	/// factor the amount should be multiplied by.
+	///
+	/// Units of the same type are converted into the largest one.
+	///  - km * m = km² (factor 0.001)
+	///  - km / (km/h) = h (factor 1)
+	pub fn multiply(left: Option<Units>, right: Option<Units>) -> (Option<Units>, Decimal) {
+		let terms = left.into_iter().chain(right).flat_map(|u| u.0);
+
+		let mut factor = Decimal::new(1, 0);
+		let mut combined: Vec<UnitTerm> = Vec::new();
+
+		for (unit, exp) in terms {
+			if let Some(existing) = combined.iter_mut().find(|(u, _)| u.base_unit() == unit.base_unit()) {
+				if existing.0.long() != unit.long() {
+					if unit.factor_amount() > existing.0.factor_amount() {
+						factor *= powi(factor_ratio(&*existing.0, &*unit), existing.1);
+						existing.0 = unit;
+					} else {
+						factor *= powi(factor_ratio(&*unit, &*existing.0), exp);
+					}
+				}
+
+				existing.1 += exp;
+			} else {
+				combined.push((unit, exp));
+			}
+		}
+
+		combined.retain(|(_, e)| *e != 0);
 
-		if let Some(div) = self.base_2() {
-			format!("{}/{}", base, div.long())
+		// Numerators first.
+		combined.sort_by_key(|(_, e)| *e < 0);
+
+		let combined = Units(combined);
+
+		// Units defined from others cancel them. kn / (km/h) = 1.852
+		if combined.is_dimensionless() {
+			(None, factor * combined.total_factor())
 		} else {
-			base.to_string()
+			(Some(combined), factor)
 		}
 	}
 
+	pub fn long(&self) -> String {
+		self.display(|u| u.long())
+	}
+
 	pub fn short(&self) -> String {
-		let short = self.base().short().unwrap_or_else(|| self.base().long());
+		self.display(|u| u.short().unwrap_or_else(|| u.long()))
+	}
+
+	fn display<'a, F: Fn(&'a dyn BaseUnit) -> &'a str>(&'a self, name: F) -> String {
+		let join = |terms: Vec<(&'a dyn BaseUnit, i32)>| terms.into_iter()
+			.map(|(u, e)| format!("{}{}", name(u), superscript(e)))
+			.collect::<Vec<String>>()
+			.join("·");
+
+		let numerator: Vec<_> = self.0.iter().filter(|(_, e)| *e > 0).map(|(u, e)| (u.as_ref(), *e)).collect();
+		let denominator: Vec<_> = self.0.iter().filter(|(_, e)| *e < 0).map(|(u, e)| (u.as_ref(), -*e)).collect();
 
-		if let Some(div) = self.base_2() {
-			format!("{}/{}", short, div.short().unwrap_or_else(|| div.long()))
+		if numerator.is_empty() {
+			join(denominator.into_iter().map(|(u, e)| (u, -e)).collect())
+		} else if denominator.is_empty() {
+			join(numerator)
+		} else if denominator.len() == 1 {
+			format!("{}/{}", join(numerator), join(denominator))
 		} else {
-			short.to_string()
+			format!("{}/({})", join(numerator), join(denominator))
 		}
 	}
 }
 
 impl fmt::Display for Units {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		for (i, u) in self.0.iter().enumerate() {
-			if i != 0 {
-				f.write_str("/")?;
-			}
-
-			u.fmt(f)?
-		}
-
-		Ok(())
+		f.write_str(&self.short())
 	}
 }
 
@@ -363,15 +659,7 @@ impl fmt::Display for Units {
 
 impl PartialEq for Units {
 	fn eq(&self, other: &Units) -> bool {
-		if self.base().base_unit() == other.base().base_unit() {
-			match (self.base_2(), other.base_2()) {
-				(Some(u1), Some(u2)) => u1.base_unit() == u2.base_unit(),
-				(None, None) => true,
-				_ => false
-			}
-		} else {
-			false
-		}
+		self.dimension() == other.dimension()
 	}
 }
 
@@ -387,4 +675,72 @@ impl Ord for Units {
 	}
 }
 
-impl Eq for Units {}
\ No newline at end of file
+impl Eq for Units {}
+
+
+const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// 2 = "²", -1 = "⁻¹". Nothing for 1.
+pub fn superscript(exp: i32) -> String {
+	if exp == 1 {
+		return String::new();
+	}
+
+	let mut value = String::new();
+
+	if exp < 0 {
+		value.push('⁻');
+	}
+
+	for c in exp.abs().to_string().chars() {
+		value.push(SUPERSCRIPTS[c.to_digit(10).unwrap() as usize]);
+	}
+
+	value
+}
+
+/// Splits "m²" into ("m", 2).
+pub fn split_superscript(value: &str) -> (&str, i32) {
+	let name = value.trim_end_matches(|c| SUPERSCRIPTS.contains(&c) || c == '⁻');
+	let power = &value[name.len()..];
+
+	if power.is_empty() || name.is_empty() {
+		return (value, 1);
+	}
+
+	let mut exp = 0;
+
+	for c in power.chars() {
+		if let Some(digit) = SUPERSCRIPTS.iter().position(|s| *s == c) {
+			exp = exp * 10 + digit as i32;
+		}
+	}
+
+	if power.starts_with('⁻') {
+		(name, -exp)
+	} else {
+		(name, exp)
+	}
+}
+
+/// How many of `to` one `from` is.
+fn factor_ratio(from: &dyn BaseUnit, to: &dyn BaseUnit) -> Decimal {
+	let (from_num, from_den) = from.factor_fraction();
+	let (to_num, to_den) = to.factor_fraction();
+
+	from_num * to_den / (from_den * to_num)
+}
+
+pub fn powi(value: Decimal, exp: i32) -> Decimal {
+	let mut result = Decimal::new(1, 0);
+
+	for _ in 0..exp.abs() {
+		result *= value;
+	}
+
+	if exp < 0 {
+		Decimal::new(1, 0) / result
+	} else {
+		result
+	}
+}
@@ -1,3 +1,138 @@
+// Speed Of Light, Gravitation, etc..
+// https://physics.nist.gov/cuu/Constants/
+// https://en.wikipedia.org/wiki/2019_redefinition_of_the_SI_base_units
 
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 
-// Speed Of Light, Gravitation, etc..
\ No newline at end of file
+use crate::{BaseUnit, Quantity, Units, UnitTerm};
+use crate::units::{Meter, Second, Gram, KiloGram, PicoGram, Ampere, Mole, Kelvin};
+
+
+pub const SI_EXACT: &str = "SI 2019 (exact)";
+pub const CODATA_2022: &str = "CODATA 2022";
+pub const CGPM_1901: &str = "CGPM 1901 (exact)";
+
+
+#[derive(Debug, Clone)]
+pub struct PhysicalConstant {
+	/// Name used in expressions.
+	pub name: &'static str,
+	pub description: &'static str,
+	/// Units the value is usually written in.
+	pub unit: &'static str,
+	pub source: &'static str,
+	pub value: Quantity
+}
+
+impl PhysicalConstant {
+	/// The value with its mass in kilograms, as a mantissa and a power of ten.
+	/// Decimal can't hold most of them in kilograms. 9.1093837139e-16 pg = (9.1093837139, -31, kg)
+	pub fn si_value(&self) -> (Decimal, i32, Option<Units>) {
+		let mut exponent = 0;
+
+		let units = self.value.unit().ok().map(|units| Units::new_powers(units.terms().iter().map(|(term, power)| {
+			if term.base_unit().long() == Gram.long() {
+				exponent += power * scientific(term.factor_amount() / KiloGram.factor_amount()).1;
+
+				unit(KiloGram, *power)
+			} else {
+				(term.clone(), *power)
+			}
+		}).collect()));
+
+		let (mantissa, shift) = scientific(self.value.amount());
+
+		(mantissa, exponent + shift, units)
+	}
+}
+
+
+/// Values are stored in SI base units (kg, m, s, A, mol, K).
+///
+/// Decimal only holds 28 decimal places so very small values use a smaller mass unit (g, pg).
+/// Every other unit stays coherent. Planck is pg·m²/s instead of kg·m²/s
+pub fn physical_constants() -> Vec<PhysicalConstant> {
+	vec![
+		constant("c", "Speed of light in vacuum", "m/s", SI_EXACT, dec!(299792458), vec![
+			unit(Meter, 1), unit(Second, -1)
+		]),
+
+		// 6.62607015e-34 kg·m²/s. "h" is hours.
+		constant("planck", "Planck constant", "J·s", SI_EXACT, dec!(6.62607015e-19), vec![
+			unit(PicoGram, 1), unit(Meter, 2), unit(Second, -1)
+		]),
+
+		// 1.380649e-23 kg·m²/s²·K
+		constant("k", "Boltzmann constant", "J/K", SI_EXACT, dec!(1.380649e-20), vec![
+			unit(Gram, 1), unit(Meter, 2), unit(Second, -2), unit(Kelvin, -1)
+		]),
+
+		constant("N_A", "Avogadro constant", "mol⁻¹", SI_EXACT, dec!(6.02214076e23), vec![
+			unit(Mole, -1)
+		]),
+
+		constant("e", "Elementary charge", "C", SI_EXACT, dec!(1.602176634e-19), vec![
+			unit(Ampere, 1), unit(Second, 1)
+		]),
+
+		constant("G", "Newtonian constant of gravitation", "m³/kg·s²", CODATA_2022, dec!(6.67430e-11), vec![
+			unit(Meter, 3), unit(KiloGram, -1), unit(Second, -2)
+		]),
+
+		constant("g0", "Standard acceleration of gravity", "m/s²", CGPM_1901, dec!(9.80665), vec![
+			unit(Meter, 1), unit(Second, -2)
+		]),
+
+		// 9.1093837139e-31 kg
+		constant("m_e", "Electron mass", "kg", CODATA_2022, dec!(9.1093837139e-16), vec![
+			unit(PicoGram, 1)
+		]),
+
+		// 1.67262192595e-27 kg
+		constant("m_p", "Proton mass", "kg", CODATA_2022, dec!(1.67262192595e-12), vec![
+			unit(PicoGram, 1)
+		]),
+
+		// N_A * k
+		constant("R", "Molar gas constant", "J/mol·K", SI_EXACT, dec!(8.31446261815324), vec![
+			unit(KiloGram, 1), unit(Meter, 2), unit(Second, -2), unit(Mole, -1), unit(Kelvin, -1)
+		]),
+
+		constant("eps0", "Vacuum electric permittivity", "F/m", CODATA_2022, dec!(8.8541878188e-12), vec![
+			unit(Ampere, 2), unit(Second, 4), unit(KiloGram, -1), unit(Meter, -3)
+		])
+	]
+}
+
+
+fn constant(name: &'static str, description: &'static str, unit: &'static str, source: &'static str, amount: Decimal, terms: Vec<UnitTerm>) -> PhysicalConstant {
+	PhysicalConstant {
+		name,
+		description,
+		unit,
+		source,
+		value: Quantity::new_unit(amount, Some(Units::new_powers(terms)))
+	}
+}
+
+fn unit<U: BaseUnit + 'static>(value: U, power: i32) -> UnitTerm {
+	(Box::new(value), power)
+}
+
+/// Splits into a mantissa from 1 to 10 and a power of ten. 0.00123 = (1.23, -3)
+fn scientific(value: Decimal) -> (Decimal, i32) {
+	let (mut mantissa, mut exponent) = (value, 0);
+
+	while mantissa.abs() >= Decimal::TEN {
+		mantissa /= Decimal::TEN;
+		exponent += 1;
+	}
+
+	while !mantissa.is_zero() && mantissa.abs() < Decimal::ONE {
+		mantissa *= Decimal::TEN;
+		exponent -= 1;
+	}
+
+	(mantissa.normalize(), exponent)
+}
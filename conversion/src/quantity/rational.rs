@@ -0,0 +1,210 @@
+// https://en.wikipedia.org/wiki/Rational_number
+
+use std::fmt;
+use std::convert::TryFrom;
+
+use rust_decimal::Decimal;
+
+
+/// An exact fraction. numer / denom
+///
+/// Always reduced with a positive denominator. Operations return None instead of overflowing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rational {
+	numer: i128,
+	denom: i128
+}
+
+impl Rational {
+	pub const ZERO: Rational = Rational { numer: 0, denom: 1 };
+	pub const ONE: Rational = Rational { numer: 1, denom: 1 };
+
+	/// None if the denominator is zero.
+	pub fn new(numer: i128, denom: i128) -> Option<Self> {
+		if denom == 0 {
+			return None;
+		}
+
+		let divisor = gcd(numer, denom);
+
+		let (numer, denom) = (numer / divisor, denom / divisor);
+
+		if denom < 0 {
+			Some(Rational { numer: numer.checked_neg()?, denom: denom.checked_neg()? })
+		} else {
+			Some(Rational { numer, denom })
+		}
+	}
+
+	/// 1.25 -> 5/4
+	pub fn from_decimal(value: Decimal) -> Option<Self> {
+		Rational::new(value.mantissa(), 10i128.checked_pow(value.scale())?)
+	}
+
+	/// None if either part is too large for a Decimal.
+	pub fn to_decimal(self) -> Option<Decimal> {
+		let numer = Decimal::try_from_i128_with_scale(self.numer, 0).ok()?;
+		let denom = Decimal::try_from_i128_with_scale(self.denom, 0).ok()?;
+
+		numer.checked_div(denom).map(|v| v.normalize())
+	}
+
+	pub fn numer(&self) -> i128 {
+		self.numer
+	}
+
+	pub fn denom(&self) -> i128 {
+		self.denom
+	}
+
+	pub fn is_zero(&self) -> bool {
+		self.numer == 0
+	}
+
+	pub fn is_integer(&self) -> bool {
+		self.denom == 1
+	}
+
+
+	pub fn checked_add(self, other: Rational) -> Option<Rational> {
+		let divisor = gcd(self.denom, other.denom);
+
+		let left = self.numer.checked_mul(other.denom / divisor)?;
+		let right = other.numer.checked_mul(self.denom / divisor)?;
+
+		Rational::new(left.checked_add(right)?, (self.denom / divisor).checked_mul(other.denom)?)
+	}
+
+	pub fn checked_sub(self, other: Rational) -> Option<Rational> {
+		self.checked_add(other.checked_neg()?)
+	}
+
+	pub fn checked_mul(self, other: Rational) -> Option<Rational> {
+		// Reduce first so the products are smaller.
+		let a = gcd(self.numer, other.denom);
+		let b = gcd(other.numer, self.denom);
+
+		Rational::new(
+			(self.numer / a).checked_mul(other.numer / b)?,
+			(self.denom / b).checked_mul(other.denom / a)?
+		)
+	}
+
+	/// None when dividing by zero.
+	pub fn checked_div(self, other: Rational) -> Option<Rational> {
+		self.checked_mul(Rational::new(other.denom, other.numer)?)
+	}
+
+	pub fn checked_neg(self) -> Option<Rational> {
+		Some(Rational { numer: self.numer.checked_neg()?, denom: self.denom })
+	}
+
+	/// Negative powers flip the fraction. None when raising zero to a negative power.
+	pub fn checked_powi(self, exp: i32) -> Option<Rational> {
+		let mut base = if exp < 0 { Rational::ONE.checked_div(self)? } else { self };
+		let mut exp = exp.unsigned_abs();
+		let mut value = Rational::ONE;
+
+		while exp > 0 {
+			if exp & 1 == 1 {
+				value = value.checked_mul(base)?;
+			}
+
+			exp >>= 1;
+
+			if exp > 0 {
+				base = base.checked_mul(base)?;
+			}
+		}
+
+		Some(value)
+	}
+
+	/// The closest fraction with a denominator no larger than `max`. 0.3333 with 16 -> 1/3
+	///
+	/// https://en.wikipedia.org/wiki/Continued_fraction#Best_rational_approximations
+	pub fn limit_denominator(self, max: i128) -> Option<Rational> {
+		if max < 1 {
+			return None;
+		}
+
+		if self.denom <= max {
+			return Some(self);
+		}
+
+		let (mut p0, mut q0, mut p1, mut q1) = (0i128, 1i128, 1i128, 0i128);
+		let (mut n, mut d) = (self.numer.checked_abs()?, self.denom);
+
+		loop {
+			let a = n / d;
+			let q2 = q0.checked_add(a.checked_mul(q1)?)?;
+
+			if q2 > max {
+				break;
+			}
+
+			let p2 = p0.checked_add(a.checked_mul(p1)?)?;
+
+			p0 = p1;
+			q0 = q1;
+			p1 = p2;
+			q1 = q2;
+
+			let remainder = n - a * d;
+			n = d;
+			d = remainder;
+		}
+
+		// The last convergent and the closest semiconvergent below `max`.
+		let k = (max - q0) / q1;
+		let (ps, qs) = (p0.checked_add(k.checked_mul(p1)?)?, q0.checked_add(k.checked_mul(q1)?)?);
+
+		// |x - p/q| compared without dividing. |n·q - p·d| / q
+		let (n, d) = (self.numer.checked_abs()?, self.denom);
+		let error = |p: i128, q: i128| n.checked_mul(q)?.checked_sub(p.checked_mul(d)?).map(i128::abs);
+
+		let closest = if error(p1, q1)?.checked_mul(qs)? <= error(ps, qs)?.checked_mul(q1)? {
+			Rational::new(p1, q1)?
+		} else {
+			Rational::new(ps, qs)?
+		};
+
+		if self.numer < 0 {
+			closest.checked_neg()
+		} else {
+			Some(closest)
+		}
+	}
+}
+
+impl Default for Rational {
+	fn default() -> Self {
+		Rational::ZERO
+	}
+}
+
+
+impl fmt::Display for Rational {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.denom == 1 {
+			write!(f, "{}", self.numer)
+		} else {
+			write!(f, "{}/{}", self.numer, self.denom)
+		}
+	}
+}
+
+
+/// Greatest common divisor. Never zero so it's always safe to divide by.
+fn gcd(a: i128, b: i128) -> i128 {
+	let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+
+	while b != 0 {
+		let remainder = a % b;
+		a = b;
+		b = remainder;
+	}
+
+	// |i128::MIN| doesn't fit back into an i128.
+	i128::try_from(a).unwrap_or(1).max(1)
+}
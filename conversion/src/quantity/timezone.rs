@@ -0,0 +1,122 @@
+// https://www.iana.org/time-zones
+
+use std::fmt;
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+pub use tzdb_data::VERSION as TZDB_VERSION;
+
+
+/// A region from the embedded IANA database or a fixed offset from UTC. Europe/Berlin, UTC+5:30
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimeZone {
+	/// The database name. America/New_York
+	Named(&'static str),
+	/// Seconds ahead of UTC.
+	Fixed(i32)
+}
+
+impl TimeZone {
+	/// Case is ignored and the region may be left off. tokyo = Asia/Tokyo, UTC-8, GMT+5:30
+	pub fn from_name(name: &str) -> Option<Self> {
+		let lower = name.to_ascii_lowercase();
+
+		if let Some(offset) = lower.strip_prefix("utc").or_else(|| lower.strip_prefix("gmt")) {
+			if offset.is_empty() {
+				return Some(TimeZone::Fixed(0));
+			} else if let Some(offset) = parse_offset(offset) {
+				return Some(TimeZone::Fixed(offset));
+			}
+		}
+
+		let name = name.replace(' ', "_");
+
+		tzdb_data::TZ_NAMES.iter()
+			.find(|zone| zone.eq_ignore_ascii_case(&name))
+			.or_else(|| tzdb_data::TZ_NAMES.iter().find(|zone| zone.rsplit('/').next().is_some_and(|city| city.eq_ignore_ascii_case(&name))))
+			.map(|zone| TimeZone::Named(zone))
+	}
+
+	/// Seconds ahead of UTC at an instant. None if the database has no rule for it.
+	pub fn offset(&self, utc: i64) -> Option<i32> {
+		match self {
+			TimeZone::Fixed(offset) => Some(*offset),
+			TimeZone::Named(name) => tzdb_data::find_tz(name.as_bytes())
+				.and_then(|tz| tz.find_local_time_type(utc).ok())
+				.map(|local| local.ut_offset())
+		}
+	}
+
+	/// The instant a wall clock reads `local` seconds since 1970-01-01.
+	///
+	/// Times repeated when the clocks go back are the first of the two.
+	/// Times skipped when the clocks go forward are moved later by the gap. 02:30 = 03:30
+	pub fn to_utc(&self, local: Decimal) -> Option<Decimal> {
+		let whole = local.floor().to_i64()?;
+
+		let before = self.offset(whole.checked_sub(86_400)?)?;
+		let after = self.offset(whole.checked_add(86_400)?)?;
+
+		// The larger offset is the earlier instant.
+		for offset in [before.max(after), before.min(after)].iter() {
+			if self.offset(whole - *offset as i64)? == *offset {
+				return Some(local - Decimal::from(*offset));
+			}
+		}
+
+		Some(local - Decimal::from(before))
+	}
+}
+
+impl fmt::Display for TimeZone {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			TimeZone::Named(name) => f.write_str(name),
+			TimeZone::Fixed(0) => f.write_str("UTC"),
+			TimeZone::Fixed(offset) => write!(f, "UTC{}", format_offset(*offset))
+		}
+	}
+}
+
+
+/// Seconds ahead of UTC. Z, +02:00, -0530, +5:30, -8
+pub fn parse_offset(value: &str) -> Option<i32> {
+	if value == "Z" {
+		return Some(0);
+	}
+
+	let sign = match value.chars().next()? {
+		'+' => 1,
+		'-' => -1,
+		_ => return None
+	};
+
+	let value = &value[1..];
+
+	let (hours, minutes) = match value.split_once(':') {
+		Some(parts) => parts,
+		None if value.len() > 2 => value.split_at(value.len() - 2),
+		None => (value, "00")
+	};
+
+	if hours.is_empty() || hours.len() > 2 || minutes.len() != 2 || !hours.chars().chain(minutes.chars()).all(|c| c.is_ascii_digit()) {
+		return None;
+	}
+
+	let (hours, minutes): (i32, i32) = (hours.parse().ok()?, minutes.parse().ok()?);
+
+	if hours > 23 || minutes > 59 {
+		return None;
+	}
+
+	Some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// +05:30
+pub fn format_offset(offset: i32) -> String {
+	let sign = if offset < 0 { '-' } else { '+' };
+	let offset = offset.abs();
+
+	format!("{}{:02}:{:02}", sign, offset / 3600, offset / 60 % 60)
+}
@@ -0,0 +1,525 @@
+// Transcendental Functions
+//
+// Everything is computed with Decimal arithmetic (series expansions, Newton's method)
+// instead of going through f64. Results are rounded to PRECISION significant digits
+// which hides the error that builds up in the last couple digits of a series.
+//
+// Functions return None when the value is outside of the domain or the result overflows.
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive, One, Signed};
+
+
+/// Significant digits every result is guaranteed to.
+pub const PRECISION: u32 = 24;
+
+/// Significant digits inverse trig functions return.
+const ANGLE_PRECISION: u32 = 26;
+
+const MAX_ITERATIONS: usize = 500;
+
+/// π = 3.1415926535897932384626433833
+pub const PI: Decimal = Decimal::from_parts(1102470953, 185874565, 1703060790, false, 28);
+
+/// π/2 = 1.5707963267948966192313216916
+pub const HALF_PI: Decimal = Decimal::from_parts(2698719124, 92937282, 851530395, false, 28);
+
+/// 2π = 6.2831853071795864769252867666
+pub const TWO_PI: Decimal = Decimal::from_parts(2204941906, 371749130, 3406121580, false, 28);
+
+// 2π split into parts for reducing large angles (Cody–Waite).
+// The first three have few enough digits that multiplying them by up to 10²⁰ turns is exact.
+// The last part is below Decimal's smallest scale so it's stored multiplied by 10²⁸.
+
+/// 6.2831853
+const TWO_PI_1: Decimal = Decimal::from_parts(62831853, 0, 0, false, 7);
+
+/// 0.000000007179586
+const TWO_PI_2: Decimal = Decimal::from_parts(7179586, 0, 0, false, 15);
+
+/// 0.00000000000000047692528
+const TWO_PI_3: Decimal = Decimal::from_parts(47692528, 0, 0, false, 23);
+
+/// 0.000000000000000000000006766559005768394338798750212 × 10²⁸
+const TWO_PI_4: Decimal = Decimal::from_parts(232344068, 3072071581, 366815898, false, 23);
+
+/// 10²⁸
+const TWO_PI_4_SCALE: Decimal = Decimal::from_parts(268435456, 1042612833, 542101086, false, 0);
+
+/// ln(2) = 0.6931471805599453094172321215
+pub const LN_2: Decimal = Decimal::from_parts(2860148159, 2180329217, 375755839, false, 28);
+
+/// ln(10) = 2.3025850929940456840179914547
+pub const LN_10: Decimal = Decimal::from_parts(2678495027, 336900640, 1248233880, false, 28);
+
+
+pub fn sqrt(value: Decimal) -> Option<Decimal> {
+	finish(sqrt_unrounded(value)?)
+}
+
+pub fn cbrt(value: Decimal) -> Option<Decimal> {
+	if value.is_zero() {
+		return Some(value);
+	}
+
+	let three = Decimal::from(3);
+	let mut guess = Decimal::from_f64(value.to_f64()?.cbrt())?;
+
+	for _ in 0..MAX_ITERATIONS {
+		let next = (Decimal::TWO * guess + value / (guess * guess)) / three;
+
+		if next == guess {
+			break;
+		}
+
+		guess = next;
+	}
+
+	finish(guess)
+}
+
+pub fn exp(value: Decimal) -> Option<Decimal> {
+	if value.is_zero() {
+		return Some(Decimal::ONE);
+	}
+
+	if value.is_sign_negative() {
+		// Too small to represent.
+		if value < Decimal::from(-66) {
+			return Some(Decimal::ZERO);
+		}
+
+		return finish(Decimal::ONE / exp_unrounded(-value)?);
+	}
+
+	finish(exp_unrounded(value)?)
+}
+
+pub fn ln(value: Decimal) -> Option<Decimal> {
+	finish(ln_unrounded(value)?)
+}
+
+pub fn log2(value: Decimal) -> Option<Decimal> {
+	finish(ln_unrounded(value)? / LN_2)
+}
+
+pub fn log10(value: Decimal) -> Option<Decimal> {
+	finish(ln_unrounded(value)? / LN_10)
+}
+
+pub fn log(value: Decimal, base: Decimal) -> Option<Decimal> {
+	let base = ln_unrounded(base)?;
+
+	if base.is_zero() {
+		return None;
+	}
+
+	finish(ln_unrounded(value)? / base)
+}
+
+/// Integer exponents are calculated exactly. Everything else is exp(y * ln(x)).
+pub fn powf(value: Decimal, exp: Decimal) -> Option<Decimal> {
+	if exp.fract().is_zero() {
+		return powi(value, exp.to_i64()?);
+	}
+
+	if value.is_zero() && exp.is_sign_positive() {
+		return Some(Decimal::ZERO);
+	}
+
+	if value.is_sign_negative() {
+		return None;
+	}
+
+	self::exp(ln_unrounded(value)?.checked_mul(exp)?)
+}
+
+pub fn powi(value: Decimal, exp: i64) -> Option<Decimal> {
+	let mut result = Decimal::ONE;
+	let mut base = value;
+	let mut remaining = exp.unsigned_abs();
+
+	while remaining > 0 {
+		if remaining & 1 == 1 {
+			result = result.checked_mul(base)?;
+		}
+
+		remaining >>= 1;
+
+		if remaining > 0 {
+			base = base.checked_mul(base)?;
+		}
+	}
+
+	if exp < 0 {
+		Decimal::ONE.checked_div(result)
+	} else {
+		Some(result)
+	}
+}
+
+pub fn hypot(x: Decimal, y: Decimal) -> Option<Decimal> {
+	// Scale down first so the squares don't overflow.
+	let scale = x.abs().max(y.abs());
+
+	if scale.is_zero() {
+		return Some(Decimal::ZERO);
+	}
+
+	let (x, y) = (x / scale, y / scale);
+
+	finish(scale.checked_mul(sqrt_unrounded(x * x + y * y)?)?)
+}
+
+
+// Trigonometric. Angles are in radians.
+
+pub fn sin(value: Decimal) -> Option<Decimal> {
+	finish(remove_trig_noise(value, sin_unrounded(value)?))
+}
+
+pub fn cos(value: Decimal) -> Option<Decimal> {
+	finish(remove_trig_noise(value, cos_unrounded(value)?))
+}
+
+pub fn tan(value: Decimal) -> Option<Decimal> {
+	let cos = remove_trig_noise(value, cos_unrounded(value)?);
+
+	if cos.is_zero() {
+		return None;
+	}
+
+	finish(sin_unrounded(value)? / cos)
+}
+
+pub fn asin(value: Decimal) -> Option<Decimal> {
+	if value.abs() > Decimal::ONE {
+		return None;
+	}
+
+	if value.abs() == Decimal::ONE {
+		return finish_angle(HALF_PI * value.signum());
+	}
+
+	finish_angle(atan_unrounded(value / sqrt_unrounded(Decimal::ONE - value * value)?))
+}
+
+pub fn acos(value: Decimal) -> Option<Decimal> {
+	if value.abs() > Decimal::ONE {
+		return None;
+	}
+
+	if value == Decimal::NEGATIVE_ONE {
+		return finish_angle(PI);
+	}
+
+	// acos(x) = 2 * atan(sqrt((1 - x) / (1 + x))). Stays accurate close to 1.
+	let sqrt = sqrt_unrounded((Decimal::ONE - value) / (Decimal::ONE + value))?;
+
+	finish_angle(Decimal::TWO * atan_unrounded(sqrt))
+}
+
+pub fn atan(value: Decimal) -> Option<Decimal> {
+	finish_angle(atan_unrounded(value))
+}
+
+pub fn atan2(y: Decimal, x: Decimal) -> Option<Decimal> {
+	let value = if x.is_sign_positive() && !x.is_zero() {
+		atan_unrounded(y / x)
+	} else if x.is_zero() {
+		if y.is_zero() {
+			Decimal::ZERO
+		} else {
+			HALF_PI * y.signum()
+		}
+	} else if y.is_sign_negative() {
+		atan_unrounded(y / x) - PI
+	} else {
+		atan_unrounded(y / x) + PI
+	};
+
+	finish_angle(value)
+}
+
+
+// Hyperbolic.
+
+pub fn sinh(value: Decimal) -> Option<Decimal> {
+	finish(sinh_unrounded(value)?)
+}
+
+pub fn cosh(value: Decimal) -> Option<Decimal> {
+	let exp = exp_unrounded(value.abs())?;
+
+	finish((exp + Decimal::ONE / exp) / Decimal::TWO)
+}
+
+pub fn tanh(value: Decimal) -> Option<Decimal> {
+	// Already 1 to every digit we can represent.
+	if value.abs() > Decimal::from(33) {
+		return Some(value.signum());
+	}
+
+	let exp = exp_unrounded(value.abs())?;
+
+	finish(sinh_unrounded(value)? / ((exp + Decimal::ONE / exp) / Decimal::TWO))
+}
+
+pub fn asinh(value: Decimal) -> Option<Decimal> {
+	let sqrt = sqrt_unrounded(value.checked_mul(value)?.checked_add(Decimal::ONE)?)?;
+
+	finish(atanh_unrounded(value / sqrt)?)
+}
+
+pub fn acosh(value: Decimal) -> Option<Decimal> {
+	if value < Decimal::ONE {
+		return None;
+	}
+
+	let sqrt = sqrt_unrounded(value.checked_mul(value)? - Decimal::ONE)?;
+
+	finish(ln_unrounded(value + sqrt)?)
+}
+
+pub fn atanh(value: Decimal) -> Option<Decimal> {
+	finish(atanh_unrounded(value)?)
+}
+
+
+
+fn sqrt_unrounded(value: Decimal) -> Option<Decimal> {
+	if value.is_sign_negative() {
+		return None;
+	}
+
+	if value.is_zero() {
+		return Some(value);
+	}
+
+	// f64 is only used for the starting guess.
+	let mut guess = Decimal::from_f64(value.to_f64()?.sqrt())?;
+
+	for _ in 0..MAX_ITERATIONS {
+		let next = (guess + value / guess) / Decimal::TWO;
+
+		if next == guess {
+			break;
+		}
+
+		guess = next;
+	}
+
+	Some(guess)
+}
+
+fn sinh_unrounded(value: Decimal) -> Option<Decimal> {
+	// Avoids cancellation between e^x and e^-x.
+	if value.abs() < Decimal::ONE {
+		let square = value * value;
+
+		return Some(series(value, |term, n| term * square / Decimal::from((2 * n) * (2 * n + 1))));
+	}
+
+	let exp = exp_unrounded(value.abs())?;
+
+	Some((exp - Decimal::ONE / exp) / Decimal::TWO * value.signum())
+}
+
+/// Positive values only.
+fn exp_unrounded(value: Decimal) -> Option<Decimal> {
+	// e^x = 2^k * e^r where |r| <= ln(2) / 2
+	let k = (value / LN_2).round();
+	let remainder = value - k * LN_2;
+
+	let exp = series(Decimal::ONE, |term, n| term * remainder / Decimal::from(n));
+
+	exp.checked_mul(powi(Decimal::TWO, k.to_i64()?)?)
+}
+
+fn ln_unrounded(value: Decimal) -> Option<Decimal> {
+	if value <= Decimal::ZERO {
+		return None;
+	}
+
+	if value.is_one() {
+		return Some(Decimal::ZERO);
+	}
+
+	// ln(x) = k * ln(2) + ln(m) where m is close to 1.
+	let mut k = 0;
+	let mut m = value;
+
+	let (upper, lower) = (Decimal::new(15, 1), Decimal::new(75, 2));
+
+	while m > upper {
+		m /= Decimal::TWO;
+		k += 1;
+	}
+
+	while m < lower {
+		m *= Decimal::TWO;
+		k -= 1;
+	}
+
+	// ln(m) = 2 * atanh((m - 1) / (m + 1))
+	let ln_m = Decimal::TWO * atanh_series((m - Decimal::ONE) / (m + Decimal::ONE));
+
+	Some(Decimal::from(k) * LN_2 + ln_m)
+}
+
+fn atanh_unrounded(value: Decimal) -> Option<Decimal> {
+	if value.abs() >= Decimal::ONE {
+		return None;
+	}
+
+	if value.abs() <= Decimal::new(5, 1) {
+		Some(atanh_series(value))
+	} else {
+		Some(ln_unrounded((Decimal::ONE + value) / (Decimal::ONE - value))? / Decimal::TWO)
+	}
+}
+
+/// x + x³/3 + x⁵/5 + ...
+fn atanh_series(value: Decimal) -> Decimal {
+	let square = value * value;
+
+	let mut power = value;
+	let mut sum = value;
+
+	for n in 1..MAX_ITERATIONS {
+		power *= square;
+
+		let term = power / Decimal::from(2 * n + 1);
+
+		if term.is_zero() {
+			break;
+		}
+
+		sum += term;
+	}
+
+	sum
+}
+
+fn sin_unrounded(value: Decimal) -> Option<Decimal> {
+	let mut value = reduce_angle(value)?;
+
+	// sin(x) = sin(π - x)
+	if value > HALF_PI {
+		value = PI - value;
+	} else if value < -HALF_PI {
+		value = -PI - value;
+	}
+
+	let square = value * value;
+
+	Some(series(value, |term, n| -term * square / Decimal::from((2 * n) * (2 * n + 1))))
+}
+
+fn cos_unrounded(value: Decimal) -> Option<Decimal> {
+	let value = reduce_angle(value)?.abs();
+
+	// cos(x) = -cos(π - x)
+	let (value, sign) = if value > HALF_PI {
+		(PI - value, Decimal::NEGATIVE_ONE)
+	} else {
+		(value, Decimal::ONE)
+	};
+
+	let square = value * value;
+
+	Some(sign * series(Decimal::ONE, |term, n| -term * square / Decimal::from((2 * n - 1) * (2 * n))))
+}
+
+fn atan_unrounded(value: Decimal) -> Decimal {
+	// atan(x) = ±π/2 - atan(1/x)
+	if value.abs() > Decimal::ONE {
+		return HALF_PI * value.signum() - atan_unrounded(Decimal::ONE / value);
+	}
+
+	// atan(x) = 2 * atan(x / (1 + sqrt(1 + x²))). Twice brings |x| under 0.2.
+	let mut value = value;
+
+	for _ in 0..2 {
+		let sqrt = sqrt_unrounded(Decimal::ONE + value * value).unwrap_or(Decimal::ONE);
+
+		value /= Decimal::ONE + sqrt;
+	}
+
+	let square = value * value;
+
+	let mut power = value;
+	let mut sum = value;
+
+	for n in 1..MAX_ITERATIONS {
+		power *= -square;
+
+		let term = power / Decimal::from(2 * n + 1);
+
+		if term.is_zero() {
+			break;
+		}
+
+		sum += term;
+	}
+
+	sum * Decimal::from(4)
+}
+
+/// Brings the angle into [-π, π]
+///
+/// Turns are removed using 2π split into parts so the error doesn't grow with the angle.
+fn reduce_angle(value: Decimal) -> Option<Decimal> {
+	if value.abs() <= PI {
+		return Some(value);
+	}
+
+	let turns = (value / TWO_PI).round();
+
+	let tail = turns.checked_mul(TWO_PI_4)? / TWO_PI_4_SCALE;
+
+	value.checked_sub(turns.checked_mul(TWO_PI_1)?)?
+		.checked_sub(turns.checked_mul(TWO_PI_2)?)?
+		.checked_sub(turns.checked_mul(TWO_PI_3)?)?
+		.checked_sub(tail)
+}
+
+/// Sums terms until they're too small to change the result.
+///
+/// The closure returns the next term from the previous one and its index (starting at 1).
+fn series<F: Fn(Decimal, usize) -> Decimal>(first: Decimal, next: F) -> Decimal {
+	let mut term = first;
+	let mut sum = first;
+
+	for n in 1..MAX_ITERATIONS {
+		term = next(term, n);
+
+		if term.is_zero() {
+			break;
+		}
+
+		sum += term;
+	}
+
+	sum
+}
+
+/// Angles keep more digits than PRECISION, the same as the π the angle units are made from.
+/// Otherwise converting them to degrees leaves the rounding behind. asin(1) -> deg = 89.9999999999999999999999
+fn finish_angle(value: Decimal) -> Option<Decimal> {
+	Some(value.round_sf(ANGLE_PRECISION)?.normalize())
+}
+
+fn finish(value: Decimal) -> Option<Decimal> {
+	Some(value.round_sf(PRECISION)?.normalize())
+}
+
+/// Once an angle has been reduced by π the error of π itself is bigger than a result this small.
+/// Stops sin(π) from returning 0.0000000000000000000000000002
+fn remove_trig_noise(angle: Decimal, value: Decimal) -> Decimal {
+	if angle.abs() >= Decimal::ONE && value.abs() < Decimal::new(1, PRECISION) {
+		Decimal::ZERO
+	} else {
+		value
+	}
+}
@@ -0,0 +1,66 @@
+// https://en.wikipedia.org/wiki/Angle#Units
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::quantity::transcendental::PI;
+use super::{BaseUnit, Radian};
+
+
+/// Units which are a fraction of π radians.
+macro_rules! create_pi_fraction_unit {
+	($unitName:ident, $divisor:expr, $longName:expr, $multiName:expr) => {
+		create_pi_fraction_unit!(full $unitName, $divisor, $longName, $multiName, None, []);
+	};
+
+	($unitName:ident, $divisor:expr, $longName:expr, $multiName:expr, $shortName:expr, [$($alts:expr),*]) => {
+		create_pi_fraction_unit!(full $unitName, $divisor, $longName, $multiName, Some($shortName), [$($alts),*]);
+	};
+
+	(full $unitName:ident, $divisor:expr, $longName:expr, $multiName:expr, $shortName:expr, [$($alts:expr),*]) => {
+		#[derive(Debug, Clone, PartialEq)]
+		pub struct $unitName;
+
+		impl BaseUnit for $unitName {
+			fn multiple(&self) -> &str {
+				$multiName
+			}
+
+			fn long(&self) -> &str {
+				$longName
+			}
+
+			fn short(&self) -> Option<&str> {
+				$shortName
+			}
+
+			fn alt(&self) -> Vec<&str> {
+				vec![$($alts),*]
+			}
+
+			fn factor_amount(&self) -> Decimal {
+				PI / Decimal::from($divisor)
+			}
+
+			fn factor_fraction(&self) -> (Decimal, Decimal) {
+				(PI, Decimal::from($divisor))
+			}
+
+			fn is_exact(&self) -> bool {
+				false
+			}
+
+			fn base_unit(&self) -> &dyn BaseUnit {
+				Radian.base_unit()
+			}
+		}
+	};
+}
+
+
+create_pi_fraction_unit!(Degree, 180, "degree", "degrees", "deg", ["°"]);
+create_pi_fraction_unit!(Gradian, 200, "gradian", "gradians", "grad", ["gon"]);
+create_pi_fraction_unit!(ArcMinute, 10800, "arcminute", "arcminutes", "arcmin", ["′"]);
+create_pi_fraction_unit!(ArcSecond, 648000, "arcsecond", "arcseconds", "arcsec", ["″"]);
+// 2π = π / 0.5
+create_pi_fraction_unit!(Turn, dec!(0.5), "turn", "turns");
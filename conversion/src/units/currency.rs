@@ -0,0 +1,153 @@
+// https://www.iso.org/iso-4217-currency-codes.html
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use super::BaseUnit;
+
+
+/// An ISO 4217 currency. Each one is its own dimension so converting between them needs exchange rates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Currency {
+	pub code: &'static str,
+	pub name: &'static str,
+	pub plural: &'static str,
+	/// Digits after the decimal point of the minor unit. 2 for cents, 0 for yen.
+	pub minor_digits: u32
+}
+
+impl Currency {
+	pub fn find(code: &str) -> Option<&'static Currency> {
+		CURRENCIES.iter().find(|c| c.code == code)
+	}
+
+	/// $ = USD, € = EUR, C$ = CAD
+	pub fn from_symbol(symbol: &str) -> Option<&'static Currency> {
+		CURRENCY_SYMBOLS.iter()
+			.find(|(s, _)| *s == symbol)
+			.and_then(|(_, code)| Currency::find(code))
+	}
+
+	/// Rounds to the minor unit. Halves round away from zero. $3.335 = $3.34
+	pub fn round(&self, amount: Decimal) -> Decimal {
+		amount.round_dp_with_strategy(self.minor_digits, RoundingStrategy::MidpointAwayFromZero)
+	}
+}
+
+impl BaseUnit for Currency {
+	fn multiple(&self) -> &str {
+		self.plural
+	}
+
+	fn long(&self) -> &str {
+		self.name
+	}
+
+	fn short(&self) -> Option<&str> {
+		Some(self.code)
+	}
+
+	fn alt(&self) -> Vec<&str> {
+		Vec::new()
+	}
+
+	fn base_unit(&self) -> &dyn BaseUnit {
+		self
+	}
+}
+
+
+/// Written before the amount. Longer symbols come first so C$ isn't read as $.
+pub static CURRENCY_SYMBOLS: [(&str, &str); 19] = [
+	("US$", "USD"),
+	("NZ$", "NZD"),
+	("HK$", "HKD"),
+	("C$", "CAD"),
+	("A$", "AUD"),
+	("S$", "SGD"),
+	("R$", "BRL"),
+	("$", "USD"),
+	("€", "EUR"),
+	("£", "GBP"),
+	("¥", "JPY"),
+	("₹", "INR"),
+	("₩", "KRW"),
+	("₽", "RUB"),
+	("₺", "TRY"),
+	("₪", "ILS"),
+	("₫", "VND"),
+	("₱", "PHP"),
+	("฿", "THB")
+];
+
+macro_rules! currency {
+	($code:expr, $name:expr, $plural:expr, $digits:expr) => {
+		Currency { code: $code, name: $name, plural: $plural, minor_digits: $digits }
+	};
+}
+
+pub static CURRENCIES: [Currency; 64] = [
+	currency!("AED", "UAE dirham", "UAE dirhams", 2),
+	currency!("ARS", "Argentine peso", "Argentine pesos", 2),
+	currency!("AUD", "Australian dollar", "Australian dollars", 2),
+	currency!("BDT", "Bangladeshi taka", "Bangladeshi taka", 2),
+	currency!("BGN", "Bulgarian lev", "Bulgarian leva", 2),
+	currency!("BHD", "Bahraini dinar", "Bahraini dinars", 3),
+	currency!("BRL", "Brazilian real", "Brazilian reais", 2),
+	currency!("CAD", "Canadian dollar", "Canadian dollars", 2),
+	currency!("CHF", "Swiss franc", "Swiss francs", 2),
+	currency!("CLP", "Chilean peso", "Chilean pesos", 0),
+	currency!("CNY", "Chinese yuan", "Chinese yuan", 2),
+	currency!("COP", "Colombian peso", "Colombian pesos", 2),
+	currency!("CZK", "Czech koruna", "Czech korunas", 2),
+	currency!("DKK", "Danish krone", "Danish kroner", 2),
+	currency!("EGP", "Egyptian pound", "Egyptian pounds", 2),
+	currency!("EUR", "euro", "euros", 2),
+	currency!("GBP", "pound sterling", "pounds sterling", 2),
+	currency!("GHS", "Ghanaian cedi", "Ghanaian cedis", 2),
+	currency!("HKD", "Hong Kong dollar", "Hong Kong dollars", 2),
+	currency!("HUF", "Hungarian forint", "Hungarian forints", 2),
+	currency!("IDR", "Indonesian rupiah", "Indonesian rupiahs", 2),
+	currency!("ILS", "Israeli new shekel", "Israeli new shekels", 2),
+	currency!("INR", "Indian rupee", "Indian rupees", 2),
+	currency!("IQD", "Iraqi dinar", "Iraqi dinars", 3),
+	currency!("ISK", "Icelandic króna", "Icelandic krónur", 0),
+	currency!("JOD", "Jordanian dinar", "Jordanian dinars", 3),
+	currency!("JPY", "Japanese yen", "Japanese yen", 0),
+	currency!("KES", "Kenyan shilling", "Kenyan shillings", 2),
+	currency!("KRW", "South Korean won", "South Korean won", 0),
+	currency!("KWD", "Kuwaiti dinar", "Kuwaiti dinars", 3),
+	currency!("KZT", "Kazakhstani tenge", "Kazakhstani tenge", 2),
+	currency!("LKR", "Sri Lankan rupee", "Sri Lankan rupees", 2),
+	currency!("LYD", "Libyan dinar", "Libyan dinars", 3),
+	currency!("MAD", "Moroccan dirham", "Moroccan dirhams", 2),
+	currency!("MXN", "Mexican peso", "Mexican pesos", 2),
+	currency!("MYR", "Malaysian ringgit", "Malaysian ringgits", 2),
+	currency!("NGN", "Nigerian naira", "Nigerian naira", 2),
+	currency!("NOK", "Norwegian krone", "Norwegian kroner", 2),
+	currency!("NZD", "New Zealand dollar", "New Zealand dollars", 2),
+	currency!("OMR", "Omani rial", "Omani rials", 3),
+	currency!("PEN", "Peruvian sol", "Peruvian soles", 2),
+	currency!("PHP", "Philippine peso", "Philippine pesos", 2),
+	currency!("PKR", "Pakistani rupee", "Pakistani rupees", 2),
+	currency!("PLN", "Polish złoty", "Polish złoty", 2),
+	currency!("PYG", "Paraguayan guaraní", "Paraguayan guaraníes", 0),
+	currency!("QAR", "Qatari riyal", "Qatari riyals", 2),
+	currency!("RON", "Romanian leu", "Romanian lei", 2),
+	currency!("RSD", "Serbian dinar", "Serbian dinars", 2),
+	currency!("RUB", "Russian ruble", "Russian rubles", 2),
+	currency!("SAR", "Saudi riyal", "Saudi riyals", 2),
+	currency!("SEK", "Swedish krona", "Swedish kronor", 2),
+	currency!("SGD", "Singapore dollar", "Singapore dollars", 2),
+	currency!("THB", "Thai baht", "Thai baht", 2),
+	currency!("TND", "Tunisian dinar", "Tunisian dinars", 3),
+	currency!("TRY", "Turkish lira", "Turkish lira", 2),
+	currency!("TWD", "New Taiwan dollar", "New Taiwan dollars", 2),
+	currency!("UAH", "Ukrainian hryvnia", "Ukrainian hryvnias", 2),
+	currency!("UGX", "Ugandan shilling", "Ugandan shillings", 0),
+	currency!("USD", "US dollar", "US dollars", 2),
+	currency!("UYU", "Uruguayan peso", "Uruguayan pesos", 2),
+	currency!("VND", "Vietnamese đồng", "Vietnamese đồng", 0),
+	currency!("XAF", "Central African CFA franc", "Central African CFA francs", 0),
+	currency!("XOF", "West African CFA franc", "West African CFA francs", 0),
+	currency!("ZAR", "South African rand", "South African rand", 2)
+];
@@ -11,19 +11,25 @@ use crate::Units;
 
 pub mod si;
 pub mod time;
+pub mod angle;
 pub mod data;
 pub mod mass;
 pub mod length;
 pub mod frequency;
 pub mod temperature;
+pub mod currency;
+pub mod runtime;
 
 pub use si::*;
 pub use time::*;
+pub use angle::*;
 pub use data::*;
 pub use mass::*;
 pub use length::*;
 pub use frequency::*;
 pub use temperature::*;
+pub use currency::*;
+pub use runtime::*;
 
 
 #[macro_export]
@@ -158,11 +164,37 @@ pub trait BaseUnit: fmt::Debug + CloneBaseUnit {
 		dec!(1.0)
 	}
 
+	/// The factor as (numerator, denominator). Keeps precision for factors like π / 648000
+	/// which lose digits once divided.
+	fn factor_fraction(&self) -> (Decimal, Decimal) {
+		(self.factor_amount(), dec!(1))
+	}
+
+	/// False when the factor is only an approximation, such as π / 180 for degrees.
+	fn is_exact(&self) -> bool {
+		true
+	}
+
 	fn base_unit(&self) -> &dyn BaseUnit;
 
 	fn base_long(&self) -> &str {
 		self.base_unit().long()
 	}
+
+	/// The base units and their powers. More than one for units defined from several. knot = [("meter", 1), ("second", -1)]
+	fn dimension(&self) -> Vec<(&str, i32)> {
+		vec![(self.base_long(), 1)]
+	}
+
+	/// It's named `name` or is defined from a unit named `name`.
+	fn is_defined_from(&self, name: &str) -> bool {
+		self.long() == name
+	}
+
+	/// A name which isn't a known unit. The x in 6x or km/x.
+	fn is_custom(&self) -> bool {
+		false
+	}
 }
 
 // PartialEq string == BaseUnit
@@ -218,20 +250,7 @@ impl fmt::Display for dyn BaseUnit {
 
 
 pub fn is_convertable(from: &Units, to: &Units) -> bool {
-	let from_base = from.base();
-	let to_base = to.base();
-
-	if from_base.base_unit() != to_base.base_unit() {
-		return false;
-	}
-
-	let from_base = from.base_2();
-	let to_base = to.base_2();
-
-	match (from_base, to_base) {
-		(Some(from), Some(to)) => from.base_unit() == to.base_unit(),
-		_ => true
-	}
+	from.dimension() == to.dimension()
 }
 
 
@@ -264,4 +283,8 @@ impl BaseUnit for CustomUnit {
 	fn base_unit(&self) -> &dyn BaseUnit {
 		self
 	}
+
+	fn is_custom(&self) -> bool {
+		true
+	}
 }
\ No newline at end of file
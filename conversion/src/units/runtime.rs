@@ -0,0 +1,156 @@
+use rust_decimal::Decimal;
+
+use crate::Units;
+use super::BaseUnit;
+
+
+/// A unit defined while running instead of with `create_non_standard_unit!`.
+///
+/// furlong = 201.168 m has a base of meter and a factor of 201.168.
+/// knot = 1.852 km/h has a base of km/h.
+/// Units without a base are a dimension of their own. widget
+#[derive(Debug, Clone)]
+pub struct RuntimeUnit {
+	pub name: String,
+	pub plural: String,
+	pub short: Option<String>,
+	pub aliases: Vec<String>,
+	/// Amount of `base` in one of this unit.
+	pub factor: Decimal,
+	/// The units it's defined from. Their factor is multiplied by ours.
+	pub base: Option<Units>,
+	/// False when the factor is rounded. define arc = PI * 2 rad
+	pub exact: bool
+}
+
+impl RuntimeUnit {
+	/// A unit of its own dimension. The plural adds an "s".
+	pub fn new(name: String) -> Self {
+		RuntimeUnit {
+			plural: format!("{}s", name),
+			name,
+			short: None,
+			aliases: Vec::new(),
+			factor: Decimal::ONE,
+			base: None,
+			exact: true
+		}
+	}
+
+	/// `factor` of other units. sprint = 2 weeks, knot = 1.852 km/h
+	pub fn from_unit(name: String, factor: Decimal, base: Units) -> Self {
+		RuntimeUnit {
+			exact: base.is_exact(),
+			factor,
+			base: Some(base),
+			..RuntimeUnit::new(name)
+		}
+	}
+
+	/// Every name it can be written as.
+	pub fn names(&self) -> Vec<&str> {
+		let mut names = vec![self.name.as_str(), self.plural.as_str()];
+
+		names.extend(self.short.as_deref());
+		names.extend(self.aliases.iter().map(|a| a.as_str()));
+
+		names
+	}
+}
+
+impl BaseUnit for RuntimeUnit {
+	fn multiple(&self) -> &str {
+		&self.plural
+	}
+
+	fn long(&self) -> &str {
+		&self.name
+	}
+
+	fn short(&self) -> Option<&str> {
+		self.short.as_deref()
+	}
+
+	fn alt(&self) -> Vec<&str> {
+		self.aliases.iter().map(|a| a.as_str()).collect()
+	}
+
+	fn factor_amount(&self) -> Decimal {
+		let (numerator, denominator) = self.factor_fraction();
+
+		numerator / denominator
+	}
+
+	fn factor_fraction(&self) -> (Decimal, Decimal) {
+		match &self.base {
+			Some(base) => {
+				let (numerator, denominator) = base.factor_parts();
+
+				(self.factor * numerator, denominator)
+			}
+			None => (self.factor, Decimal::ONE)
+		}
+	}
+
+	fn is_exact(&self) -> bool {
+		self.exact
+	}
+
+	/// Units defined from several units are their own base. knot
+	fn base_unit(&self) -> &dyn BaseUnit {
+		match self.base.as_ref().map(|base| base.terms()) {
+			Some([(base, 1)]) => base.base_unit(),
+			_ => self
+		}
+	}
+
+	fn dimension(&self) -> Vec<(&str, i32)> {
+		match &self.base {
+			Some(base) => base.dimension(),
+			None => vec![(self.name.as_str(), 1)]
+		}
+	}
+
+	fn is_defined_from(&self, name: &str) -> bool {
+		self.name == name || self.base.as_ref().is_some_and(|base| base.terms().iter().any(|(unit, _)| unit.is_defined_from(name)))
+	}
+}
+
+
+/// A prefix defined while running. kibi- = 1024 makes kibibyte, and Ki- makes KiB.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimePrefix {
+	/// Written before long names. kibi
+	pub name: String,
+	/// Written before short names. Ki
+	pub short: Option<String>,
+	pub factor: Decimal
+}
+
+impl RuntimePrefix {
+	pub fn new(name: String, factor: Decimal) -> Self {
+		RuntimePrefix { name, short: None, factor }
+	}
+
+	/// The unit `name` is if it starts with this prefix. `find` looks up the rest of the name.
+	///
+	/// kibibytes = 1024 bytes, KiB = 1024 B
+	pub fn apply<'a, F: Fn(&str) -> Option<&'a dyn BaseUnit>>(&self, name: &str, find: F) -> Option<RuntimeUnit> {
+		// Long prefixes go before long names and short prefixes before short names. Not kibiB
+		let unit = match name.strip_prefix(self.name.as_str()).and_then(|rest| Some((find(rest)?, rest))) {
+			Some((unit, rest)) if unit.long() == rest || unit.multiple() == rest => unit,
+			_ => {
+				let rest = name.strip_prefix(self.short.as_deref()?)?;
+
+				find(rest).filter(|unit| unit.short() == Some(rest))?
+			}
+		};
+
+		let mut prefixed = RuntimeUnit::from_unit(format!("{}{}", self.name, unit.long()), self.factor, Units::new(unit.clone_base_unit()));
+
+		prefixed.plural = format!("{}{}", self.name, unit.multiple());
+		prefixed.short = self.short.as_ref().zip(unit.short()).map(|(prefix, short)| format!("{}{}", prefix, short));
+
+		Some(prefixed)
+	}
+}
@@ -19,4 +19,5 @@ create_standard_unit!(Kelvin, "kelvin", "kelvins", "K");
 create_standard_unit!(Candela, "candela", "candelas", "cd");
 
 // Derived Units
-create_standard_unit!(Hertz, "hertz", "hertz", "Hz");
\ No newline at end of file
+create_standard_unit!(Hertz, "hertz", "hertz", "Hz");
+create_standard_unit!(Radian, "radian", "radians", "rad");
\ No newline at end of file
@@ -17,4 +17,103 @@ create_non_standard_unit!(Month, Second, Decimal::new(2629800, 0), "month", "mon
 create_non_standard_unit!(Year, Second, Decimal::new(3600 * 24 * 365, 0), "year", "years");
 create_non_standard_unit!(CommonYear, Second, Decimal::new(31557600, 0), "common year", "common years", "cy"); // 365.25 * 24.0 * 3600.0
 create_non_standard_unit!(Decade, Second, Decimal::new(3600 * 24 * 365 * 10, 0), "decade", "decades");
-create_non_standard_unit!(Century, Second, Decimal::new(3600 * 24 * 365 * 100, 0), "century", "centuries");
\ No newline at end of file
+create_non_standard_unit!(Century, Second, Decimal::new(3600 * 24 * 365 * 100, 0), "century", "centuries");
+
+/// Unix seconds of the leap seconds since 1980. Each was inserted just before the listed instant.
+const LEAP_SECONDS: [i64; 18] = [
+	362793600, 394329600, 425865600, 489024000, 567993600, 631152000,
+	662688000, 709948800, 741484800, 773020800, 820454400, 867715200,
+	915148800, 1136073600, 1230768000, 1341100800, 1435708800, 1483228800
+];
+
+const GPS_EPOCH: i64 = 315964800; // 1980-01-06T00:00Z
+const NTP_EPOCH: i64 = -2208988800; // 1900-01-01T00:00Z
+
+
+/// Instants timestamps are counted from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Epoch {
+	/// 1970-01-01T00:00Z. Leap seconds aren't counted.
+	Unix,
+	/// 1980-01-06T00:00Z. Leap seconds are counted, so GPS runs ahead of UTC.
+	Gps,
+	/// 1900-01-01T00:00Z. Leap seconds aren't counted.
+	Ntp
+}
+
+impl Epoch {
+	/// Seconds since this epoch from Unix seconds.
+	pub fn from_unix(&self, seconds: Decimal) -> Option<Decimal> {
+		match self {
+			Epoch::Unix => Some(seconds),
+			Epoch::Gps => {
+				let leaps = LEAP_SECONDS.iter().filter(|&&leap| Decimal::from(leap) <= seconds).count();
+
+				seconds.checked_sub(Decimal::from(GPS_EPOCH - leaps as i64))
+			}
+			Epoch::Ntp => seconds.checked_sub(Decimal::from(NTP_EPOCH))
+		}
+	}
+
+	/// Unix seconds from seconds since this epoch.
+	pub fn to_unix(&self, seconds: Decimal) -> Option<Decimal> {
+		match self {
+			Epoch::Unix => Some(seconds),
+			Epoch::Gps => {
+				let leaps = LEAP_SECONDS.iter()
+					.enumerate()
+					.filter(|&(i, &leap)| Decimal::from(leap - GPS_EPOCH + i as i64 + 1) <= seconds)
+					.count();
+
+				seconds.checked_add(Decimal::from(GPS_EPOCH - leaps as i64))
+			}
+			Epoch::Ntp => seconds.checked_add(Decimal::from(NTP_EPOCH))
+		}
+	}
+}
+
+
+/// A count of seconds or a fraction of them since an epoch. unix, unix_ms, unix_ns, gps, ntp
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timestamp {
+	pub epoch: Epoch,
+	/// Seconds per count.
+	pub scale: Decimal
+}
+
+impl Timestamp {
+	pub fn from_name(name: &str) -> Option<Self> {
+		let (epoch, scale) = match name {
+			"unix" => (Epoch::Unix, Decimal::ONE),
+			"unix_ms" => (Epoch::Unix, dec!(1e-3)),
+			"unix_us" => (Epoch::Unix, dec!(1e-6)),
+			"unix_ns" => (Epoch::Unix, dec!(1e-9)),
+			"gps" => (Epoch::Gps, Decimal::ONE),
+			"ntp" => (Epoch::Ntp, Decimal::ONE),
+			_ => return None
+		};
+
+		Some(Timestamp { epoch, scale })
+	}
+
+	pub fn name(&self) -> &'static str {
+		match self.epoch {
+			Epoch::Unix if self.scale == dec!(1e-3) => "unix_ms",
+			Epoch::Unix if self.scale == dec!(1e-6) => "unix_us",
+			Epoch::Unix if self.scale == dec!(1e-9) => "unix_ns",
+			Epoch::Unix => "unix",
+			Epoch::Gps => "gps",
+			Epoch::Ntp => "ntp"
+		}
+	}
+
+	/// 2026-10-18T00:00Z -> unix_ms = 1792281600000
+	pub fn from_unix(&self, seconds: Decimal) -> Option<Decimal> {
+		self.epoch.from_unix(seconds)?.checked_div(self.scale)
+	}
+
+	/// unix_ms(1700000000000) = 2023-11-14T22:13:20Z
+	pub fn to_unix(&self, count: Decimal) -> Option<Decimal> {
+		self.epoch.to_unix(count.checked_mul(self.scale)?)
+	}
+}
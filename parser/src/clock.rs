@@ -0,0 +1,45 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rust_decimal::Decimal;
+
+use conversion::DateTime;
+
+
+/// Where "now" comes from. A FixedClock keeps results the same between runs.
+pub trait Clock: fmt::Debug {
+	fn now(&self) -> DateTime;
+}
+
+
+/// The system time in UTC.
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+	fn now(&self) -> DateTime {
+		let elapsed = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+
+		let seconds = Decimal::from(elapsed.as_secs()) + Decimal::new(elapsed.subsec_millis() as i64, 3);
+
+		DateTime::from_seconds(seconds.normalize(), true).expect("system time within the supported years")
+	}
+}
+
+
+/// Always the same time.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime);
+
+impl FixedClock {
+	/// Milliseconds since 1970-01-01T00:00 UTC. Used where SystemTime isn't available.
+	pub fn from_unix_millis(millis: i64) -> Self {
+		FixedClock(DateTime::from_seconds(Decimal::new(millis, 3).normalize(), true).expect("time within the supported years"))
+	}
+}
+
+impl Clock for FixedClock {
+	fn now(&self) -> DateTime {
+		self.0
+	}
+}
@@ -1,13 +1,11 @@
 // Constants.
 // PI, E, etc..
+// Physical constants (c, G, etc..) are in conversion::physics
 
-// TODO: Make constants use Vec<ExprToken> instead of number.
-// Will allow for better custom constants.
+// Constants are written the same way a user would type them so they can carry units.
+// They're parsed once the units are loaded.
 
-use rust_decimal::Decimal;
-use rust_decimal_macros::dec;
-
-pub type DefaultConsts<'a> = Vec<(&'a str, Decimal)>;
+pub type DefaultConsts<'a> = Vec<(&'a str, &'a str)>;
 
 pub const PI: f64 = std::f64::consts::PI;
 pub const EULERS_NUMBER: f64 = std::f64::consts::E;
@@ -15,7 +13,7 @@ pub const EULERS_NUMBER: f64 = std::f64::consts::E;
 
 pub fn default_constants<'a>() -> DefaultConsts<'a> {
 	vec![
-		("PI", dec!(3.14159265358979323846264338327950288)),
-		("E", dec!(2.71828182845904523536028747135266250))
+		("PI", "3.1415926535897932384626433833"),
+		("E", "2.7182818284590452353602874714")
 	]
-}
\ No newline at end of file
+}
@@ -0,0 +1,226 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+
+use conversion::{DateTime, Quantity, Units};
+use conversion::units::Currency;
+use conversion::Error as ConversionError;
+
+use crate::{Value, Result, Error};
+
+
+/// Exchange rates from a CSV or JSON file. Rates are units of a currency per one unit of the base.
+///
+/// CSV has a row for the base, the date and each rate:
+/// ```text
+/// base,USD
+/// as_of,2026-10-18
+/// EUR,0.92
+/// ```
+///
+/// JSON has the same fields. "date" can be used in place of "as_of":
+/// ```text
+/// { "base": "USD", "as_of": "2026-10-18", "rates": { "EUR": 0.92 } }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ExchangeRates {
+	base: Option<&'static Currency>,
+	as_of: Option<DateTime>,
+	rates: Vec<(&'static Currency, Decimal)>
+}
+
+impl ExchangeRates {
+	pub fn parse(text: &str) -> Result<Self> {
+		if text.trim_start().starts_with('{') {
+			ExchangeRates::parse_json(text)
+		} else {
+			ExchangeRates::parse_csv(text)
+		}
+	}
+
+	fn parse_csv(text: &str) -> Result<Self> {
+		let mut found = ExchangeRates::default();
+
+		for (index, line) in text.lines().enumerate() {
+			let line = line.trim();
+
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+
+			let on_line = |message: String| invalid(format!("Line {}: {}", index + 1, message));
+
+			let (key, value) = line.split_once(',')
+				.map(|(key, value)| (key.trim(), value.trim()))
+				.ok_or_else(|| on_line(format!(r#"Expected "currency,rate" but found "{}""#, line)))?;
+
+			match key {
+				"base" => found.base = Some(find_currency(value).map_err(on_line)?),
+				"as_of" | "date" => found.as_of = Some(DateTime::parse(value).ok_or_else(|| on_line(format!("{} is not a valid date", value)))?),
+				// Header row
+				"currency" | "code" => (),
+				code => {
+					let currency = find_currency(code).map_err(on_line)?;
+					let rate = parse_rate(value).map_err(on_line)?;
+
+					found.rates.push((currency, rate));
+				}
+			}
+		}
+
+		found.validate()
+	}
+
+	fn parse_json(text: &str) -> Result<Self> {
+		let json: serde_json::Value = serde_json::from_str(text).map_err(|e| invalid(format!("Invalid JSON: {}", e)))?;
+
+		let mut found = ExchangeRates::default();
+
+		if let Some(base) = json.get("base").and_then(|v| v.as_str()) {
+			found.base = Some(find_currency(base).map_err(invalid)?);
+		}
+
+		if let Some(date) = json.get("as_of").or_else(|| json.get("date")).and_then(|v| v.as_str()) {
+			found.as_of = Some(DateTime::parse(date).ok_or_else(|| invalid(format!("{} is not a valid date", date)))?);
+		}
+
+		let rates = json.get("rates")
+			.and_then(|v| v.as_object())
+			.ok_or_else(|| invalid(r#"Expected a "rates" object"#.to_string()))?;
+
+		for (code, rate) in rates {
+			let rate = match rate {
+				serde_json::Value::Number(n) => n.to_string(),
+				serde_json::Value::String(s) => s.clone(),
+				_ => return Err(invalid(format!("The rate for {} isn't a number", code)))
+			};
+
+			found.rates.push((find_currency(code).map_err(invalid)?, parse_rate(&rate).map_err(invalid)?));
+		}
+
+		found.validate()
+	}
+
+	fn validate(self) -> Result<Self> {
+		if self.base.is_none() {
+			return Err(invalid("The base currency is missing".to_string()));
+		}
+
+		Ok(self)
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.rates.is_empty()
+	}
+
+	pub fn len(&self) -> usize {
+		self.rates.len()
+	}
+
+	pub fn base(&self) -> Option<&'static Currency> {
+		self.base
+	}
+
+	pub fn as_of(&self) -> Option<DateTime> {
+		self.as_of
+	}
+
+	/// Units of `to` for one unit of `from`.
+	pub fn rate(&self, from: &Currency, to: &Currency) -> Option<Decimal> {
+		let per_base = |currency: &Currency| {
+			if Some(currency) == self.base {
+				Some(Decimal::ONE)
+			} else {
+				self.rates.iter().find(|(c, _)| *c == currency).map(|(_, rate)| *rate)
+			}
+		};
+
+		per_base(to)?.checked_div(per_base(from)?)
+	}
+
+	/// Changes an amount of money into the currency of `to`. Other values are returned unchanged.
+	pub fn exchange(&self, value: Value, to: &Value) -> Result<Value> {
+		let value = value.into_plain();
+
+		if let Value::List(items) = value {
+			return Ok(Value::List(items.into_iter().map(|v| self.exchange(v, to)).collect::<Result<_>>()?));
+		}
+
+		let target = match to {
+			Value::Unit(unit) => currency_of(unit),
+			Value::Quantity(q) => q.unit().ok().and_then(currency_of),
+			_ => None
+		};
+
+		let (amount, from, to) = match (&value, target) {
+			(Value::Quantity(q), Some(to)) => match q.unit().ok().and_then(currency_of) {
+				Some(from) if from != to => (q.amount(), from, to),
+				_ => return Ok(value)
+			},
+			_ => return Ok(value)
+		};
+
+		let rate = self.rate(from, to).ok_or_else(|| Error::NoExchangeRate(from.code.to_string(), to.code.to_string()))?;
+
+		Ok(Value::Quantity(Quantity::new_unit(to.round(amount * rate), Some(Units::new(Box::new(to.clone()))))))
+	}
+}
+
+/// The rows of a CSV file.
+impl fmt::Display for ExchangeRates {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if let Some(base) = self.base {
+			writeln!(f, "base,{}", base.code)?;
+		}
+
+		if let Some(as_of) = self.as_of {
+			writeln!(f, "as_of,{}", as_of)?;
+		}
+
+		for (currency, rate) in &self.rates {
+			writeln!(f, "{},{}", currency.code, rate)?;
+		}
+
+		Ok(())
+	}
+}
+
+
+/// The currency of a plain amount of money. 5 USD, but not 5 USD/h
+pub fn currency_of(unit: &Units) -> Option<&'static Currency> {
+	match unit.terms() {
+		[(unit, 1)] => unit.short().and_then(Currency::find).filter(|c| c.name == unit.long()),
+		_ => None
+	}
+}
+
+/// Money is rounded to the minor unit of its currency. $10 / 3 = $3.33
+pub fn round_money(value: Quantity) -> Quantity {
+	match value.unit().ok().and_then(currency_of) {
+		Some(currency) => {
+			let amount = currency.round(value.amount());
+			let unit = value.into_unit();
+
+			Quantity::new_unit(amount, unit)
+		}
+		None => value
+	}
+}
+
+
+fn find_currency(code: &str) -> std::result::Result<&'static Currency, String> {
+	Currency::find(code).ok_or_else(|| format!(r#"Unknown currency "{}""#, code))
+}
+
+fn parse_rate(value: &str) -> std::result::Result<Decimal, String> {
+	Decimal::from_str(value)
+		.or_else(|_| Decimal::from_scientific(value))
+		.ok()
+		.filter(|rate| rate.is_sign_positive() && !rate.is_zero())
+		.ok_or_else(|| format!("{} is not a valid rate", value))
+}
+
+fn invalid(message: String) -> Error {
+	Error::Conversion(ConversionError::InvalidArgument(message))
+}
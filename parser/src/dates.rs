@@ -0,0 +1,191 @@
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+use conversion::{DateTime, Quantity, Units, TimeZone, is_convertable};
+use conversion::units::{Second, Timestamp};
+use conversion::Error as ConversionError;
+
+use crate::{Value, NumberFormat, ClockFormat, Result, Error};
+
+
+/// Functions taking dates. They aren't a FunctionEval as they return dates and weekdays.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DateFunction {
+	/// weekday(2026-10-18) = Sunday
+	Weekday,
+	/// add_months(2026-01-31, 1) = 2026-02-28
+	AddMonths,
+	/// add_years(2028-02-29, 1) = 2029-02-28
+	AddYears,
+	/// unix_ms(1700000000000) = 2023-11-14T22:13:20
+	Timestamp(Timestamp)
+}
+
+impl DateFunction {
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"weekday" => Some(DateFunction::Weekday),
+			"add_months" => Some(DateFunction::AddMonths),
+			"add_years" => Some(DateFunction::AddYears),
+			_ => Timestamp::from_name(name).map(DateFunction::Timestamp)
+		}
+	}
+
+	pub fn name(&self) -> &'static str {
+		match self {
+			DateFunction::Weekday => "weekday",
+			DateFunction::AddMonths => "add_months",
+			DateFunction::AddYears => "add_years",
+			DateFunction::Timestamp(timestamp) => timestamp.name()
+		}
+	}
+
+	pub fn arg_count(&self) -> usize {
+		match self {
+			DateFunction::Weekday | DateFunction::Timestamp(_) => 1,
+			DateFunction::AddMonths | DateFunction::AddYears => 2
+		}
+	}
+
+	pub fn call(&self, params: Vec<Value>) -> Result<Value> {
+		let mut params = params.into_iter().map(Value::into_plain);
+
+		if let DateFunction::Timestamp(timestamp) = self {
+			return match params.next().and_then(Value::into_quantity) {
+				Some(count) if count.unit().is_err() => timestamp.to_unix(count.amount())
+					.and_then(|seconds| DateTime::from_seconds(seconds, true))
+					.map(Value::DateTime)
+					.ok_or_else(|| Error::Conversion(ConversionError::InvalidArgument(format!("{} is too far from the epoch", count)))),
+				_ => Err(Error::Conversion(ConversionError::InvalidArgument(format!("{} expects a number", self.name()))))
+			};
+		}
+
+		let date = match params.next() {
+			Some(Value::DateTime(date)) => date,
+			_ => return Err(Error::Conversion(ConversionError::InvalidArgument(format!("{} expects a date", self.name()))))
+		};
+
+		match self {
+			DateFunction::Timestamp(_) => unreachable!(),
+
+			DateFunction::Weekday => Ok(Value::Formatted(Quantity::new(date.weekday().into()), NumberFormat::Weekday)),
+
+			DateFunction::AddMonths | DateFunction::AddYears => {
+				let count = params.next()
+					.and_then(Value::into_quantity)
+					.filter(|q| q.unit().is_err() && q.amount().fract().is_zero())
+					.and_then(|q| q.amount().to_i64())
+					.ok_or_else(|| Error::Conversion(ConversionError::InvalidArgument(format!("{} expects a whole number", self.name()))))?;
+
+				let months = if *self == DateFunction::AddYears { count.checked_mul(12) } else { Some(count) };
+
+				months.and_then(|m| date.add_months(m))
+					.map(Value::DateTime)
+					.ok_or_else(|| out_of_range(&date))
+			}
+		}
+	}
+}
+
+/// What a date or timestamp is converted into.
+///  - 1700000000 -> datetime = 2023-11-14T22:13:20
+///  - 2026-10-18T09:30 -> date = 2026-10-18
+///  - 2026-10-18T00:00Z -> unix = 1792281600
+///  - 2026-10-18 09:00 America/New_York -> Europe/Berlin = 2026-10-18T15:00+02:00 Europe/Berlin
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DateTarget {
+	DateTime,
+	Date,
+	Timestamp(Timestamp),
+	Zone(TimeZone)
+}
+
+impl DateTarget {
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"datetime" => Some(DateTarget::DateTime),
+			"date" => Some(DateTarget::Date),
+			_ => Timestamp::from_name(name).map(DateTarget::Timestamp)
+				.or_else(|| TimeZone::from_name(name).map(DateTarget::Zone))
+		}
+	}
+
+	/// Plain numbers are Unix seconds. Durations are counted from the Unix epoch.
+	pub fn convert(&self, value: Value) -> Result<Value> {
+		let date = match value.into_plain() {
+			Value::DateTime(date) => date,
+			Value::Quantity(q) => {
+				let seconds = match q.unit() {
+					Err(_) => q.amount(),
+					Ok(unit) if is_convertable(unit, &Units::new(Box::new(Second))) => q.total_amount(),
+					Ok(_) => return Err(Error::Conversion(ConversionError::InvalidArgument(format!("{} isn't a timestamp", q))))
+				};
+
+				DateTime::from_seconds(seconds, true)
+					.ok_or_else(|| Error::Conversion(ConversionError::InvalidArgument(format!("{} is too far from the epoch", q))))?
+			}
+			value => return Err(Error::Conversion(ConversionError::InvalidArgument(format!("{} isn't a date or timestamp", value))))
+		};
+
+		match self {
+			DateTarget::DateTime => Ok(Value::DateTime(date.with_time())),
+			DateTarget::Zone(zone) => date.with_zone(*zone).map(Value::DateTime).ok_or_else(|| unknown_offset(*zone)),
+			DateTarget::Date => Ok(Value::DateTime(date.start_of_day())),
+			DateTarget::Timestamp(timestamp) => timestamp.from_unix(date.seconds())
+				.map(|count| Value::Quantity(Quantity::new(count.normalize())))
+				.ok_or_else(|| out_of_range(&date))
+		}
+	}
+}
+
+/// A time of day or a duration written with colons.
+///  - 9:30am, 17:45 are times of day.
+///  - 1:30:00 is a duration of hours, minutes and seconds.
+pub fn parse_clock(value: &str) -> Option<Value> {
+	let lower = value.to_lowercase();
+
+	let (value, meridiem) = match lower.strip_suffix("am").or_else(|| lower.strip_suffix("pm")) {
+		Some(value) => (value.trim_end(), Some(lower.ends_with("pm"))),
+		None => (lower.as_str(), None)
+	};
+
+	let parts = value.split(':').collect::<Vec<_>>();
+
+	let hour: u64 = parts.first()?.parse().ok()?;
+	let minute: u64 = parts.get(1)?.parse().ok()?;
+	let second: Decimal = match parts.get(2) {
+		Some(second) => second.parse().ok()?,
+		None => Decimal::ZERO
+	};
+
+	if parts.len() > 3 || minute > 59 || second >= Decimal::from(60) {
+		return None;
+	}
+
+	let to_seconds = |hour: u64| Decimal::from(hour * 3600 + minute * 60) + second;
+
+	match meridiem {
+		Some(pm) if (1..=12).contains(&hour) => Some(Value::Time(to_seconds(hour % 12 + if pm { 12 } else { 0 }), ClockFormat::TwelveHour)),
+		Some(_) => None,
+		None if parts.len() == 2 && hour < 24 => Some(Value::Time(to_seconds(hour), ClockFormat::TwentyFourHour)),
+		None if parts.len() == 3 => Some(Value::Formatted(Quantity::new_unit(to_seconds(hour), Some(Units::new(Box::new(Second)))), NumberFormat::Duration)),
+		None => None
+	}
+}
+
+pub fn out_of_range(date: &DateTime) -> Error {
+	Error::Conversion(ConversionError::InvalidArgument(format!("The result is too far from {}", date)))
+}
+
+/// A date converted to a name which isn't a unit or a time zone. 2026-10-18 -> Foo/Bar
+pub fn unknown_zone(name: &str) -> Error {
+	Error::Conversion(ConversionError::InvalidArgument(format!(r#"Unknown time zone "{}""#, name)))
+}
+
+pub fn unknown_offset(zone: TimeZone) -> Error {
+	Error::Conversion(ConversionError::InvalidArgument(format!("The UTC offset of {} isn't known at that time", zone)))
+}
+
+pub fn invalid_date(value: &str) -> Error {
+	Error::Conversion(ConversionError::InvalidArgument(format!("{} is not a valid date or time", value)))
+}
@@ -0,0 +1,273 @@
+use std::fmt;
+
+use conversion::units::{RuntimeUnit, RuntimePrefix};
+use conversion::Error as ConversionError;
+
+use crate::{Factory, Value, Result, Error};
+
+
+/// The definition after the define keyword. "define furlong = 201.168 m" = "furlong = 201.168 m"
+pub fn strip_define(line: &str) -> Option<&str> {
+	strip_keyword(line, "define")
+}
+
+/// The definition after the prefix keyword. "prefix kibi- = 1024" = "kibi- = 1024"
+pub fn strip_prefix_keyword(line: &str) -> Option<&str> {
+	strip_keyword(line, "prefix")
+}
+
+fn strip_keyword<'a>(line: &'a str, keyword: &str) -> Option<&'a str> {
+	line.trim_start()
+		.strip_prefix(keyword)
+		.filter(|rest| rest.starts_with(char::is_whitespace))
+		.map(str::trim)
+}
+
+
+/// A unit or prefix read from a define or prefix line. Added with `Factory::add_definition`.
+#[derive(Debug, Clone)]
+pub enum Definition {
+	/// define furlong = 201.168 m
+	Unit(RuntimeUnit),
+	/// prefix kibi- (Ki-) = 1024
+	Prefix(RuntimePrefix)
+}
+
+impl fmt::Display for Definition {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Definition::Unit(unit) => f.write_str(&unit_definition(unit)),
+			Definition::Prefix(prefix) => f.write_str(&prefix_definition(prefix))
+		}
+	}
+}
+
+
+impl Factory {
+	/// The unit or prefix of a line starting with define or prefix. None for other lines.
+	pub fn parse_definition(&self, line: &str) -> Option<Result<Definition>> {
+		if let Some(definition) = strip_define(line) {
+			return Some(self.unit_from_definition(definition).map(Definition::Unit));
+		}
+
+		strip_prefix_keyword(line).map(|definition| self.prefix_from_definition(definition).map(Definition::Prefix))
+	}
+
+	pub fn add_definition(&mut self, definition: Definition) -> Result<()> {
+		match definition {
+			Definition::Unit(unit) => self.add_unit(unit),
+			Definition::Prefix(prefix) => self.add_prefix(prefix)
+		}
+	}
+
+	/// Adds a unit from a definition. The plural, short name and aliases are optional.
+	///
+	/// ```text
+	/// furlong = 201.168 m
+	/// story_point, story_points (sp, pts) = 6 h
+	/// widget
+	/// ```
+	///
+	/// Without an amount it's a dimension of its own. Only other widgets convert to widgets.
+	pub fn define_unit(&mut self, definition: &str) -> Result<RuntimeUnit> {
+		let unit = self.unit_from_definition(definition)?;
+
+		self.add_unit(unit.clone())?;
+
+		Ok(unit)
+	}
+
+	fn unit_from_definition(&self, definition: &str) -> Result<RuntimeUnit> {
+		let (names, eval) = match definition.split_once('=') {
+			Some((names, eval)) => (names, Some(eval)),
+			None => (definition, None)
+		};
+
+		// "story_point, story_points (sp, pts)"
+		let (names, short) = split_short(names)?;
+
+		let names = split_names(names)?;
+
+		let short = match short {
+			Some(short) => split_names(short)?,
+			None => Vec::new()
+		};
+
+		let name = match names.as_slice() {
+			[name] | [name, _] => name.to_string(),
+			_ => return Err(invalid(r#"Expected "name" or "name, plural" before the ="#.to_string()))
+		};
+
+		let mut unit = match eval {
+			Some(eval) => unit_from_value(name, self.parse_value(eval)?)?,
+			None => RuntimeUnit::new(name)
+		};
+
+		// Unknown names would become dimensions of their own. foo = 3 bar
+		if let Some(unknown) = unit.base.iter().flat_map(|base| base.terms()).find(|(base, _)| base.is_custom()) {
+			return Err(Error::UnknownUnit(unknown.0.long().to_string()));
+		}
+
+		if let Some(plural) = names.get(1) {
+			unit.plural = plural.to_string();
+		}
+
+		let mut short = short.into_iter().map(str::to_string);
+
+		unit.short = short.next();
+		unit.aliases = short.collect();
+
+		Ok(unit)
+	}
+
+	/// Adds a prefix from a definition. The short prefix is optional.
+	///
+	/// ```text
+	/// kibi- (Ki-) = 1024
+	/// ```
+	pub fn define_prefix(&mut self, definition: &str) -> Result<RuntimePrefix> {
+		let prefix = self.prefix_from_definition(definition)?;
+
+		self.add_prefix(prefix.clone())?;
+
+		Ok(prefix)
+	}
+
+	fn prefix_from_definition(&self, definition: &str) -> Result<RuntimePrefix> {
+		let (names, eval) = definition.split_once('=')
+			.ok_or_else(|| invalid(r#"Expected "prefix- = amount""#.to_string()))?;
+
+		let (name, short) = split_short(names)?;
+
+		let strip_dash = |name: &str| name.trim().strip_suffix('-')
+			.filter(|name| !name.is_empty() && name.chars().all(char::is_alphabetic))
+			.map(str::to_string)
+			.ok_or_else(|| invalid(format!(r#""{}" isn't a valid prefix. Prefixes end with a dash, kibi-"#, name.trim())));
+
+		let factor = match self.parse_value(eval)?.into_plain() {
+			Value::Quantity(q) if q.unit().is_err() => q.amount(),
+			value => return Err(invalid(format!("A prefix has to be a number, not {}", value)))
+		};
+
+		let mut prefix = RuntimePrefix::new(strip_dash(name)?, factor);
+
+		prefix.short = match short {
+			Some(short) => Some(strip_dash(short)?),
+			None => None
+		};
+
+		Ok(prefix)
+	}
+
+	/// Adds the units and prefixes of a definition file. Errors are given the file name and line. units.txt:3
+	///
+	/// ```text
+	/// # Astronomy
+	/// parsec (pc) = 3.0857e16 m
+	/// prefix kibi- (Ki-) = 1024
+	/// ```
+	///
+	/// Lines before an error are kept. Returns how many were added.
+	pub fn load_unit_definitions(&mut self, file_name: &str, contents: &str) -> Result<usize> {
+		let mut count = 0;
+
+		for (index, line) in contents.lines().enumerate() {
+			let line = line.trim();
+
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+
+			let result = match strip_prefix_keyword(line) {
+				Some(definition) => self.define_prefix(definition).map(|_| ()),
+				None => self.define_unit(strip_define(line).unwrap_or(line)).map(|_| ())
+			};
+
+			result.map_err(|e| invalid(format!("{}:{}: {}", file_name, index + 1, e)))?;
+
+			count += 1;
+		}
+
+		Ok(count)
+	}
+}
+
+
+/// The definition `define_unit` reads back. story_point, story_points (sp) = 6 h
+pub fn unit_definition(unit: &RuntimeUnit) -> String {
+	let mut names = unit.name.clone();
+
+	if unit.plural != format!("{}s", unit.name) {
+		names += &format!(", {}", unit.plural);
+	}
+
+	if let Some(short) = &unit.short {
+		names += &format!(" ({})", std::iter::once(short).chain(unit.aliases.iter()).cloned().collect::<Vec<_>>().join(", "));
+	}
+
+	match &unit.base {
+		Some(base) => format!("{} = {} {}", names, unit.factor, base),
+		None => names
+	}
+}
+
+fn unit_from_value(name: String, value: Value) -> Result<RuntimeUnit> {
+	let quantity = match value.into_plain() {
+		Value::Quantity(q) => q,
+		value => return Err(invalid(format!("A unit can't be defined as {}", value)))
+	};
+
+	let base = quantity.unit().cloned().map_err(|_| invalid(format!("{} needs a unit", name)))?;
+
+	if quantity.amount().is_zero() {
+		return Err(invalid(format!("{} can't be zero", name)));
+	}
+
+	let mut unit = RuntimeUnit::from_unit(name, quantity.amount(), base);
+
+	unit.exact = unit.exact && quantity.exact().is_some();
+
+	Ok(unit)
+}
+
+/// The definition `define_prefix` reads back. kibi- (Ki-) = 1024
+pub fn prefix_definition(prefix: &RuntimePrefix) -> String {
+	match &prefix.short {
+		Some(short) => format!("{}- ({}-) = {}", prefix.name, short, prefix.factor),
+		None => format!("{}- = {}", prefix.name, prefix.factor)
+	}
+}
+
+/// Names and what's in the brackets after them. "kibi- (Ki-)" = ("kibi-", Some("Ki-"))
+fn split_short(names: &str) -> Result<(&str, Option<&str>)> {
+	match names.split_once('(') {
+		Some((names, short)) => {
+			let short = short.trim().strip_suffix(')')
+				.ok_or_else(|| invalid(format!(r#"Expected ")" after "{}""#, short.trim())))?;
+
+			Ok((names, Some(short)))
+		}
+		None => Ok((names, None))
+	}
+}
+
+/// Comma separated names. Each is a single word which doesn't start with a digit.
+fn split_names(names: &str) -> Result<Vec<&str>> {
+	names.split(',')
+		.map(str::trim)
+		.map(|name| {
+			let is_name = name.chars().next().map(|c| !c.is_ascii_digit()).unwrap_or_default()
+				&& name.chars().all(|c| c.is_alphanumeric() || c == '_');
+
+			if is_name {
+				Ok(name)
+			} else {
+				Err(invalid(format!(r#""{}" isn't a valid unit name"#, name)))
+			}
+		})
+		.collect()
+}
+
+fn invalid(message: String) -> Error {
+	Error::Conversion(ConversionError::InvalidArgument(message))
+}
@@ -0,0 +1,316 @@
+// https://en.wikipedia.org/wiki/System_of_linear_equations
+// https://en.wikipedia.org/wiki/Gaussian_elimination
+
+use rust_decimal::Decimal;
+
+use conversion::{Quantity, Units, Rational};
+
+use crate::{Value, Result, Error};
+
+use super::{Polynomial, Monomial};
+use super::roots::{ROOT_PRECISION, EPSILON};
+
+
+/// One equation moved into the form `a·x + b·y + ... + constant = 0`. None for a missing term.
+struct Row {
+	coefficients: Vec<Option<Quantity>>,
+	constant: Option<Quantity>
+}
+
+enum Outcome<F> {
+	Solved(Vec<F>),
+	Inconsistent,
+	/// The rank, how many of the equations are independent.
+	Underdetermined(usize)
+}
+
+
+/// Solves every `polynomials[k] = 0` together. Values are returned in the order of `variables`.
+///
+/// The units of each unknown are found from the terms it's added to. Each equation is then divided
+/// into plain numbers and solved with exact fractions, falling back to Decimal if they grow too large.
+///
+/// 2x + 3y = 7 m; x - y = 1 m
+///   - x and y are in meters
+///   - [2, 3 | 7], [1, -1 | 1]
+///   - x = 2 m, y = 1 m
+pub fn solve_linear_system(polynomials: Vec<Polynomial>, variables: &[String]) -> Result<Vec<Value>> {
+	let rows = polynomials.iter()
+		.map(|poly| into_row(poly, variables))
+		.collect::<Result<Vec<_>>>()?;
+
+	let units = find_units(&rows, variables)?;
+	let known = units.iter().cloned().map(Some).collect::<Vec<_>>();
+
+	// Plain numbers. Each equation is divided by the units of its terms.
+	let mut rational = Vec::new();
+	let mut decimal = Vec::new();
+
+	for row in &rows {
+		let reference = Quantity::new_unit(Decimal::ONE, reference_unit(row, &known)?);
+
+		let mut values = Vec::new();
+
+		for (coefficient, unit) in row.coefficients.iter().zip(&units) {
+			values.push(match coefficient {
+				Some(coefficient) => ratio(multiply(coefficient.clone(), Quantity::new_unit(Decimal::ONE, unit.clone()))?, &reference)?,
+				None => Decimal::ZERO
+			});
+		}
+
+		// Moved to the other side. ax + by = -constant
+		values.push(match &row.constant {
+			Some(constant) => -ratio(constant.clone(), &reference)?,
+			None => Decimal::ZERO
+		});
+
+		rational.push(values.iter().map(|v| Rational::from_decimal(*v)).collect::<Option<Vec<_>>>());
+		decimal.push(values);
+	}
+
+	let exact = rational.into_iter()
+		.collect::<Option<Vec<_>>>()
+		.and_then(|matrix| eliminate(matrix, variables.len()))
+		.map(|outcome| match outcome {
+			Outcome::Solved(values) => values.into_iter().map(|v| v.to_decimal()).collect::<Option<Vec<_>>>().map(Outcome::Solved),
+			Outcome::Inconsistent => Some(Outcome::Inconsistent),
+			Outcome::Underdetermined(rank) => Some(Outcome::Underdetermined(rank))
+		});
+
+	let outcome = match exact {
+		Some(Some(outcome)) => outcome,
+		_ => eliminate(decimal, variables.len()).ok_or_else(|| Error::UnableToSolve("A value was too large to represent".to_string()))?
+	};
+
+	match outcome {
+		Outcome::Solved(values) => Ok(
+			values.into_iter()
+			.zip(units)
+			.map(|(value, unit)| {
+				let value = value.round_sf(ROOT_PRECISION).unwrap_or(value).normalize();
+
+				Value::Quantity(Quantity::new_unit(value, unit))
+			})
+			.collect()
+		),
+
+		Outcome::Inconsistent => Err(Error::UnableToSolve("The equations contradict each other, there is no solution".to_string())),
+
+		Outcome::Underdetermined(rank) => Err(Error::UnableToSolve(format!(
+			"There are infinitely many solutions. Found {} independent {} for {} unknowns",
+			rank,
+			if rank == 1 { "equation" } else { "equations" },
+			variables.len()
+		)))
+	}
+}
+
+
+fn into_row(polynomial: &Polynomial, variables: &[String]) -> Result<Row> {
+	let mut row = Row {
+		coefficients: vec![None; variables.len()],
+		constant: None
+	};
+
+	for (monomial, coefficient) in polynomial.terms() {
+		match monomial.as_slice() {
+			[] => row.constant = Some(coefficient.clone()),
+
+			[(name, 1)] => {
+				let index = variables.iter().position(|v| v == name).expect("variable of the polynomial");
+
+				row.coefficients[index] = Some(coefficient.clone());
+			}
+
+			_ => return Err(Error::UnableToSolve(format!("Only linear equations can be solved together. Found {}", display_monomial(monomial))))
+		}
+	}
+
+	Ok(row)
+}
+
+/// Units of each unknown. Every term of an equation has the same dimension, so once one term's
+/// units are known the unknowns in the other terms can be found.
+///
+/// Unknowns which are never next to a known unit are plain numbers.
+fn find_units(rows: &[Row], variables: &[String]) -> Result<Vec<Option<Units>>> {
+	let mut units: Vec<Option<Option<Units>>> = vec![None; variables.len()];
+
+	loop {
+		let mut changed = false;
+
+		for row in rows {
+			let reference = match reference_unit(row, &units) {
+				Ok(reference) => Quantity::new_unit(Decimal::ONE, reference),
+				Err(_) => continue
+			};
+
+			for (index, coefficient) in row.coefficients.iter().enumerate() {
+				if let (Some(coefficient), None) = (coefficient, &units[index]) {
+					units[index] = Some(divide(reference.clone(), coefficient.clone())?.into_unit());
+					changed = true;
+				}
+			}
+		}
+
+		if !changed {
+			break;
+		}
+	}
+
+	Ok(units.into_iter().map(|unit| unit.unwrap_or_default()).collect())
+}
+
+/// Units every term of the row is in. From the constant or a term with a known unknown.
+fn reference_unit(row: &Row, units: &[Option<Option<Units>>]) -> Result<Option<Units>> {
+	if let Some(constant) = &row.constant {
+		return Ok(constant.clone().into_unit());
+	}
+
+	for (coefficient, unit) in row.coefficients.iter().zip(units) {
+		if let (Some(coefficient), Some(unit)) = (coefficient, unit) {
+			return Ok(multiply(coefficient.clone(), Quantity::new_unit(Decimal::ONE, unit.clone()))?.into_unit());
+		}
+	}
+
+	// 0 = 0
+	if row.coefficients.iter().all(|c| c.is_none()) {
+		return Ok(None);
+	}
+
+	Err(Error::UnableToSolve("Unable to find the units of the equation".to_string()))
+}
+
+
+/// Gauss-Jordan elimination on an augmented matrix. `[a, b, ... | c]`
+///
+/// None if a value overflowed.
+fn eliminate<F: Field>(mut matrix: Vec<Vec<F>>, unknowns: usize) -> Option<Outcome<F>> {
+	let mut pivots = Vec::new();
+
+	for column in 0..unknowns {
+		let rank = pivots.len();
+
+		let found = match (rank..matrix.len()).find(|r| !matrix[*r][column].is_zero()) {
+			Some(found) => found,
+			None => continue
+		};
+
+		matrix.swap(rank, found);
+
+		let pivot = matrix[rank][column];
+
+		for value in matrix[rank][column..].iter_mut() {
+			*value = value.checked_div(pivot)?;
+		}
+
+		let pivot_row = matrix[rank].clone();
+
+		for (r, row) in matrix.iter_mut().enumerate() {
+			let factor = row[column];
+
+			if r != rank && !factor.is_zero() {
+				for (value, p) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+					*value = value.checked_sub(factor.checked_mul(*p)?)?;
+				}
+			}
+		}
+
+		pivots.push(column);
+	}
+
+	let rank = pivots.len();
+
+	// 0 = c
+	if matrix[rank..].iter().any(|row| !row[unknowns].is_zero()) {
+		return Some(Outcome::Inconsistent);
+	}
+
+	if rank < unknowns {
+		return Some(Outcome::Underdetermined(rank));
+	}
+
+	Some(Outcome::Solved(matrix.into_iter().take(rank).map(|row| row[unknowns]).collect()))
+}
+
+
+trait Field: Copy {
+	fn is_zero(&self) -> bool;
+	fn checked_sub(self, other: Self) -> Option<Self>;
+	fn checked_mul(self, other: Self) -> Option<Self>;
+	fn checked_div(self, other: Self) -> Option<Self>;
+}
+
+impl Field for Rational {
+	fn is_zero(&self) -> bool {
+		Rational::is_zero(self)
+	}
+
+	fn checked_sub(self, other: Self) -> Option<Self> {
+		Rational::checked_sub(self, other)
+	}
+
+	fn checked_mul(self, other: Self) -> Option<Self> {
+		Rational::checked_mul(self, other)
+	}
+
+	fn checked_div(self, other: Self) -> Option<Self> {
+		Rational::checked_div(self, other)
+	}
+}
+
+impl Field for Decimal {
+	fn is_zero(&self) -> bool {
+		Decimal::is_zero(self)
+	}
+
+	/// Differences which are only left over from rounding become zero.
+	fn checked_sub(self, other: Self) -> Option<Self> {
+		let value = Decimal::checked_sub(self, other)?;
+
+		if value.abs() <= self.abs().max(other.abs()) * EPSILON {
+			Some(Decimal::ZERO)
+		} else {
+			Some(value)
+		}
+	}
+
+	fn checked_mul(self, other: Self) -> Option<Self> {
+		Decimal::checked_mul(self, other)
+	}
+
+	fn checked_div(self, other: Self) -> Option<Self> {
+		Decimal::checked_div(self, other)
+	}
+}
+
+
+/// `term / reference` as a plain number. Errors if they have different dimensions.
+fn ratio(term: Quantity, reference: &Quantity) -> Result<Decimal> {
+	let value = divide(term.clone(), reference.clone())?;
+
+	if let Ok(unit) = value.unit() {
+		if !unit.is_dimensionless() {
+			let name = |q: &Quantity| q.unit().map(|u| u.long()).unwrap_or_else(|_| "number".to_string());
+
+			return Err(Error::UnableToConvertValues(name(&term), name(reference)));
+		}
+	}
+
+	Ok(value.total_amount())
+}
+
+fn display_monomial(monomial: &Monomial) -> String {
+	monomial.iter()
+		.map(|(name, power)| if *power == 1 { name.clone() } else { format!("{}^{}", name, power) })
+		.collect::<Vec<_>>()
+		.join("·")
+}
+
+fn multiply(left: Quantity, right: Quantity) -> Result<Quantity> {
+	Value::try_multiply(Value::Quantity(left), Value::Quantity(right))?.into_quantity().ok_or(Error::ExpectedQuantity)
+}
+
+fn divide(left: Quantity, right: Quantity) -> Result<Quantity> {
+	Value::try_divide(Value::Quantity(left), Value::Quantity(right))?.into_quantity().ok_or(Error::ExpectedQuantity)
+}
@@ -1,5 +1,18 @@
 // https://en.wikipedia.org/wiki/List_of_equations
 
+use rust_decimal::Decimal;
+
+use conversion::Quantity;
+
+use crate::{Value, ComplexForm, Result, Error};
+
+pub mod linear;
+pub mod polynomial;
+pub mod roots;
+
+pub use linear::solve_linear_system;
+pub use polynomial::{Polynomial, Monomial};
+
 // Folder will be used for attempting to solve Equations with Custom Units.
 // More Examples: http://www.sosmath.com/algebra/solve/solve0/solve0.html
 
@@ -28,10 +41,94 @@
 
 
 
-// x = (-b +- sqrt(b^2 - 4ac)) / (2a)
-pub fn solve_quadratic_formula(a: f64, b: f64, c: f64) -> (f64, f64) {
-	(
-		(-1.0 * b + (b.powf(2.0) - (4.0 * a * c)).sqrt()) / (2.0 * a),
-		(-1.0 * b - (b.powf(2.0) - (4.0 * a * c)).sqrt()) / (2.0 * a)
+/// Solves `polynomial = 0` for `variable`. Every root is returned, including complex ones.
+///
+/// The unit of `variable` is found from the leading term and another term. The equation is then
+/// divided into plain numbers to find the roots.
+///
+/// 5 km / x - 10 km/h = 0
+///   - 5 km - 10 km/h * x = 0     Multiply by x to remove negative powers.
+///   - x is in km / (km/h) = h
+///   - 0.5 - x = 0                Divide by -10 km/h * h
+///   - x = 0.5 h
+pub fn solve_polynomial(polynomial: Polynomial, variable: &str) -> Result<Vec<Value>> {
+	if let Some(other) = polynomial.variables().into_iter().find(|v| *v != variable) {
+		return Err(Error::UnableToSolve(format!(r#"Unable to solve for "{}" with another unknown "{}""#, variable, other)));
+	}
+
+	let power_of = |m: &Monomial| m.first().map(|v| v.1).unwrap_or_default();
+
+	let lowest = polynomial.terms().iter().map(|(m, _)| power_of(m)).min().unwrap_or_default();
+
+	// Multiplying by x can add a solution of zero. It's not valid since it'd be a division by zero.
+	let polynomial = if lowest < 0 { polynomial.shift(variable, -lowest) } else { polynomial };
+
+	let mut terms = polynomial.terms().iter().map(|(m, q)| (power_of(m), q.clone())).collect::<Vec<_>>();
+	terms.sort_by_key(|(power, _)| -power);
+
+	let (degree, leading) = match terms.first() {
+		Some((degree, leading)) if *degree > 0 => (*degree, leading.clone()),
+		Some(_) => return Err(Error::UnableToSolve("There is no solution".to_string())),
+		None => return Err(Error::UnableToSolve("Every value is a solution".to_string()))
+	};
+
+	// The unit of x. From a·x^n + b·x^k, x^(n-k) is in the units of b/a
+	let unit = match terms.get(1) {
+		Some((power, value)) => {
+			let ratio = divide(value.clone(), leading.clone())?;
+
+			match ratio.into_unit() {
+				Some(unit) => Some(
+					unit.root(degree - power).ok_or_else(|| Error::UnableToSolve(format!(r#"Unable to find the units of "{}""#, variable)))?
+				),
+				None => None
+			}
+		}
+
+		None => None
+	};
+
+	// Plain numbers. a_i / (a_n * unit^(n-i))
+	let mut coefficients = vec![Decimal::ZERO; degree as usize + 1];
+
+	for (power, value) in terms {
+		let divisor = match &unit {
+			Some(unit) => multiply(leading.clone(), Quantity::new_unit(Decimal::ONE, Some(unit.powi(degree - power))))?,
+			None => leading.clone()
+		};
+
+		let value = divide(value, divisor)?;
+
+		if let Ok(unit) = value.unit() {
+			if !unit.is_dimensionless() {
+				return Err(Error::UnableToConvertValues(unit.long(), "number".to_string()));
+			}
+		}
+
+		coefficients[power as usize] = value.total_amount();
+	}
+
+	let roots = roots::find_roots(&coefficients)?;
+
+	Ok(
+		roots.into_iter()
+		.filter(|root| lowest >= 0 || !root.is_zero())
+		.map(|root| {
+			if root.is_real() {
+				Value::Quantity(Quantity::new_unit(root.re, unit.clone()))
+			} else {
+				Value::Complex(root, unit.clone(), ComplexForm::Rectangular)
+			}
+		})
+		.collect()
 	)
-}
\ No newline at end of file
+}
+
+
+fn multiply(left: Quantity, right: Quantity) -> Result<Quantity> {
+	Value::try_multiply(Value::Quantity(left), Value::Quantity(right))?.into_quantity().ok_or(Error::ExpectedQuantity)
+}
+
+fn divide(left: Quantity, right: Quantity) -> Result<Quantity> {
+	Value::try_divide(Value::Quantity(left), Value::Quantity(right))?.into_quantity().ok_or(Error::ExpectedQuantity)
+}
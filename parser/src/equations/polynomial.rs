@@ -0,0 +1,211 @@
+use conversion::{Quantity, Units, UnitTerm};
+
+use crate::{Value, Result, Error};
+
+
+/// Variables and their powers. Sorted by name. Empty for the constant term.
+pub type Monomial = Vec<(String, i32)>;
+
+
+/// A sum of terms. Each term is a coefficient, which can have units, multiplied by a Monomial.
+///
+/// 3x - 6 km
+///   - [ ([("x", 1)], 3), ([], -6 km) ]
+#[derive(Debug, Clone, Default)]
+pub struct Polynomial(Vec<(Monomial, Quantity)>);
+
+impl Polynomial {
+	pub fn constant(value: Quantity) -> Self {
+		let mut poly = Polynomial::default();
+		poly.insert(Vec::new(), value).expect("empty polynomial");
+		poly
+	}
+
+	/// Units named in `variables` become powers of that variable.
+	///
+	/// 3 x²·km
+	///   - [ ([("x", 2)], 3 km) ]
+	pub fn from_value(value: Value, variables: &[String]) -> Result<Self> {
+		let quantity = match value {
+			Value::Quantity(q) | Value::Formatted(q, _) => q,
+			Value::Unit(u) => Quantity::new_unit(1.into(), Some(u)),
+			Value::List(_) => return Err(Error::UnableToSolve("Lists can't be used in equations".to_string())),
+			Value::Complex(..) => return Err(Error::UnableToSolve("Complex numbers can't be used in equations".to_string())),
+			Value::Uncertain(..) => return Err(Error::UnableToSolve("Uncertain values can't be used in equations".to_string())),
+			Value::Interval(..) => return Err(Error::UnableToSolve("Intervals can't be used in equations".to_string())),
+			Value::DateTime(_) | Value::Time(..) => return Err(Error::UnableToSolve("Dates can't be used in equations".to_string()))
+		};
+
+		let amount = quantity.amount();
+
+		let mut monomial = Vec::new();
+		let mut units: Vec<UnitTerm> = Vec::new();
+
+		for (unit, power) in quantity.into_unit().iter().flat_map(|u| u.terms()) {
+			if variables.iter().any(|v| v == unit.long()) {
+				monomial.push((unit.long().to_string(), *power));
+			} else {
+				units.push((unit.clone(), *power));
+			}
+		}
+
+		monomial.sort();
+
+		let mut poly = Polynomial::default();
+		poly.insert(monomial, Quantity::new_unit(amount, if units.is_empty() { None } else { Some(Units::new_powers(units)) }))?;
+		Ok(poly)
+	}
+
+
+	pub fn terms(&self) -> &[(Monomial, Quantity)] {
+		self.0.as_slice()
+	}
+
+	pub fn is_zero(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Sorted variable names.
+	pub fn variables(&self) -> Vec<&str> {
+		let mut found = self.0.iter()
+			.flat_map(|(m, _)| m.iter().map(|(name, _)| name.as_str()))
+			.collect::<Vec<_>>();
+
+		found.sort_unstable();
+		found.dedup();
+
+		found
+	}
+
+	/// Coefficient of the Monomial. None if it's zero.
+	pub fn coefficient(&self, monomial: &[(String, i32)]) -> Option<&Quantity> {
+		self.0.iter().find(|(m, _)| m == monomial).map(|(_, q)| q)
+	}
+
+
+	pub fn try_add(mut self, other: Polynomial) -> Result<Self> {
+		for (monomial, coefficient) in other.0 {
+			self.insert(monomial, coefficient)?;
+		}
+
+		Ok(self)
+	}
+
+	pub fn try_subtract(self, other: Polynomial) -> Result<Self> {
+		self.try_add(other.negate())
+	}
+
+	pub fn negate(self) -> Self {
+		Polynomial(
+			self.0.into_iter()
+			.map(|(m, q)| (m, Quantity::new_unit(-q.amount(), q.into_unit())))
+			.collect()
+		)
+	}
+
+	pub fn try_multiply(self, other: Polynomial) -> Result<Self> {
+		let mut poly = Polynomial::default();
+
+		for (l_mono, l_coef) in &self.0 {
+			for (r_mono, r_coef) in &other.0 {
+				let coefficient = into_quantity(Value::try_multiply(Value::Quantity(l_coef.clone()), Value::Quantity(r_coef.clone()))?);
+
+				poly.insert(multiply_monomials(l_mono, r_mono), coefficient)?;
+			}
+		}
+
+		Ok(poly)
+	}
+
+	/// Only single term divisors are able to be represented. 6x / 2x, 5 km / x
+	pub fn try_divide(self, other: Polynomial) -> Result<Self> {
+		let (divisor_mono, divisor_coef) = match other.0.as_slice() {
+			[term] => term.clone(),
+			[] => return Err(Error::UnableToSolve("Division by zero".to_string())),
+			_ => return Err(Error::UnableToSolve("Unable to divide by an expression containing an unknown".to_string()))
+		};
+
+		let reciprocal = divisor_mono.into_iter().map(|(name, power)| (name, -power)).collect::<Vec<_>>();
+
+		let mut poly = Polynomial::default();
+
+		for (monomial, coefficient) in self.0 {
+			let coefficient = into_quantity(Value::try_divide(Value::Quantity(coefficient), Value::Quantity(divisor_coef.clone()))?);
+
+			poly.insert(multiply_monomials(&monomial, &reciprocal), coefficient)?;
+		}
+
+		Ok(poly)
+	}
+
+	/// Multiplies every term by `variable^power`.
+	pub fn shift(self, variable: &str, power: i32) -> Self {
+		let factor = vec![(variable.to_string(), power)];
+
+		Polynomial(
+			self.0.into_iter()
+			.map(|(m, q)| (multiply_monomials(&m, &factor), q))
+			.collect()
+		)
+	}
+
+
+	/// Adds the term to an existing one with the same Monomial. Zero terms are removed.
+	fn insert(&mut self, monomial: Monomial, coefficient: Quantity) -> Result<()> {
+		if let Some(index) = self.0.iter().position(|(m, _)| m == &monomial) {
+			let existing = self.0[index].1.clone();
+
+			check_dimensions(&existing, &coefficient)?;
+
+			let sum = into_quantity(Value::try_add(Value::Quantity(existing), Value::Quantity(coefficient))?);
+
+			if sum.amount().is_zero() {
+				self.0.remove(index);
+			} else {
+				self.0[index].1 = sum;
+			}
+		} else if !coefficient.amount().is_zero() {
+			self.0.push((monomial, coefficient));
+		}
+
+		Ok(())
+	}
+}
+
+
+fn multiply_monomials(left: &[(String, i32)], right: &[(String, i32)]) -> Monomial {
+	let mut monomial = left.to_vec();
+
+	for (name, power) in right {
+		if let Some(existing) = monomial.iter_mut().find(|(n, _)| n == name) {
+			existing.1 += power;
+		} else {
+			monomial.push((name.clone(), *power));
+		}
+	}
+
+	monomial.retain(|(_, power)| *power != 0);
+	monomial.sort();
+
+	monomial
+}
+
+/// Terms of an equation must have the same dimension. "x + 5 km = 3" can't be solved.
+fn check_dimensions(left: &Quantity, right: &Quantity) -> Result<()> {
+	let dimension = |q: &Quantity| q.unit().map(|u| u.dimension().into_iter().map(|(n, p)| (n.to_string(), p)).collect()).unwrap_or_else(|_| Vec::new());
+
+	if dimension(left) == dimension(right) {
+		Ok(())
+	} else {
+		let name = |q: &Quantity| q.unit().map(|u| u.long()).unwrap_or_else(|_| "number".to_string());
+
+		Err(Error::UnableToConvertValues(name(left), name(right)))
+	}
+}
+
+fn into_quantity(value: Value) -> Quantity {
+	match value {
+		Value::Quantity(q) => q,
+		_ => unreachable!("Quantity operations return a Quantity")
+	}
+}
@@ -0,0 +1,337 @@
+// https://en.wikipedia.org/wiki/Quadratic_formula
+// https://en.wikipedia.org/wiki/Cubic_equation#General_cubic_formula
+// https://en.wikipedia.org/wiki/Durand%E2%80%93Kerner_method
+
+use rust_decimal::Decimal;
+
+use conversion::Complex;
+use conversion::quantity::transcendental::{self, TWO_PI};
+
+use crate::{Result, Error};
+
+
+/// Roots are rounded to this many significant digits to remove numerical noise.
+pub const ROOT_PRECISION: u32 = 20;
+
+const MAX_ITERATIONS: usize = 500;
+
+/// Relative size where a discriminant or the part of a root is treated as zero.
+pub const EPSILON: Decimal = Decimal::from_parts(1, 0, 0, false, 18);
+
+
+/// Roots of a polynomial with real coefficients. `coefficients[i]` is the coefficient of x^i.
+///
+/// Each root is listed once. Real roots come first, smallest to largest.
+pub fn find_roots(coefficients: &[Decimal]) -> Result<Vec<Complex>> {
+	let mut coefficients = coefficients.to_vec();
+
+	while coefficients.last().map(|c| c.is_zero()).unwrap_or_default() {
+		coefficients.pop();
+	}
+
+	let mut roots = Vec::new();
+
+	// x³ - x = x(x² - 1)
+	let zeros = coefficients.iter().take_while(|c| c.is_zero()).count();
+
+	if zeros > 0 {
+		roots.push(Complex::default());
+		coefficients.drain(..zeros);
+	}
+
+	let lead = match coefficients.last() {
+		Some(lead) => *lead,
+		None => return Ok(roots)
+	};
+
+	// x^n + ... + c
+	let mut monic = coefficients.iter().map(|c| c / lead).collect::<Vec<_>>();
+
+	// Repeated roots slow down the numerical method. Only the unique roots are needed.
+	if monic.len() > 4 {
+		monic = square_free(&monic);
+	}
+
+	roots.append(&mut match monic.len() - 1 {
+		0 => Vec::new(),
+		1 => vec![Complex::real(-monic[0])],
+		2 => quadratic(monic[1], monic[0])?,
+		3 => cubic(monic[2], monic[1], monic[0])?,
+		_ => durand_kerner(&monic)?
+	});
+
+	let mut found: Vec<Complex> = Vec::new();
+
+	for root in roots.into_iter().map(clean) {
+		if !found.contains(&root) {
+			found.push(root);
+		}
+	}
+
+	found.sort_by_key(|c| (!c.is_real(), c.re, c.im));
+
+	Ok(found)
+}
+
+
+/// x² + bx + c = 0
+///
+/// A large b is factored out of the discriminant, (b/2)²(1 - c/(b/2)²), so it doesn't overflow.
+fn quadratic(b: Decimal, c: Decimal) -> Result<Vec<Complex>> {
+	let half = b / Decimal::TWO;
+
+	// The discriminant divided by scale² along with its size.
+	let (discriminant, size, scale) = if half.abs() > Decimal::ONE {
+		(Decimal::ONE - c / half / half, Decimal::ONE.max(c.abs() / half / half), half.abs())
+	} else {
+		(half * half - c, (half * half).max(c.abs()), Decimal::ONE)
+	};
+
+	if discriminant.abs() <= size * EPSILON {
+		return Ok(vec![Complex::real(-half)]);
+	}
+
+	// Half of the square root of b² - 4c.
+	let root = scale.checked_mul(sqrt(discriminant.abs())?).ok_or_else(undefined)?;
+
+	if discriminant.is_sign_negative() {
+		let value = Complex::new(-half, root);
+
+		return Ok(vec![value, value.conj()]);
+	}
+
+	// Avoids subtracting numbers of a similar size.
+	let q = if b.is_sign_negative() { root.checked_sub(half) } else { (-half).checked_sub(root) }.ok_or_else(undefined)?;
+
+	Ok(vec![Complex::real(q), Complex::real(c / q)])
+}
+
+/// x³ + ax² + bx + c = 0
+///
+/// Substitutes x = t - a/3 to get t³ + pt + q = 0.
+fn cubic(a: Decimal, b: Decimal, c: Decimal) -> Result<Vec<Complex>> {
+	let three = Decimal::from(3);
+
+	let shift = a / three;
+
+	// p = b - a²/3, q = 2a³/27 - ab/3 + c
+	let p = shift.checked_mul(a).and_then(|v| b.checked_sub(v)).ok_or_else(undefined)?;
+	let q = shift.checked_mul(shift)
+		.and_then(|v| v.checked_mul(shift))
+		.and_then(|v| v.checked_mul(Decimal::TWO))
+		.and_then(|v| v.checked_sub(shift.checked_mul(b)?))
+		.and_then(|v| v.checked_add(c))
+		.ok_or_else(undefined)?;
+
+	let half_q = q / Decimal::TWO;
+	let third_p = p / three;
+
+	let square = half_q.checked_mul(half_q).ok_or_else(undefined)?;
+	let cube = third_p.checked_mul(third_p).and_then(|v| v.checked_mul(third_p)).ok_or_else(undefined)?;
+	let discriminant = square.checked_add(cube).ok_or_else(undefined)?;
+
+	let roots = if p.is_zero() && q.is_zero() {
+		vec![Complex::default()]
+	} else if discriminant.abs() <= square.abs().saturating_add(cube.abs()) * EPSILON {
+		// A repeated root.
+		let ratio = q / p;
+
+		vec![
+			Complex::real(ratio.checked_mul(three).ok_or_else(undefined)?),
+			Complex::real(-ratio * three / Decimal::TWO)
+		]
+	} else if discriminant.is_sign_positive() {
+		// One real root and a complex pair.
+		let root = sqrt(discriminant)?;
+
+		let u = cbrt(root.checked_sub(half_q).ok_or_else(undefined)?)?;
+		let v = cbrt((-half_q).checked_sub(root).ok_or_else(undefined)?)?;
+
+		let pair = Complex::new(-(u + v) / Decimal::TWO, (u - v) * sqrt(three)? / Decimal::TWO);
+
+		vec![Complex::real(u + v), pair, pair.conj()]
+	} else {
+		// Three real roots.
+		let m = Decimal::TWO * sqrt(-third_p)?;
+
+		let arg = (three * q / (Decimal::TWO * p) * sqrt(-three / p)?).clamp(-Decimal::ONE, Decimal::ONE);
+		let theta = transcendental::acos(arg).ok_or_else(undefined)? / three;
+
+		(0..3)
+			.map(|k| {
+				let angle = theta - TWO_PI * Decimal::from(k) / three;
+
+				transcendental::cos(angle).map(|v| Complex::real(m * v)).ok_or_else(undefined)
+			})
+			.collect::<Result<Vec<_>>>()?
+	};
+
+	Ok(roots.into_iter().map(|t| t - Complex::real(shift)).collect())
+}
+
+/// Finds every root at once. `monic` has a leading coefficient of 1.
+fn durand_kerner(monic: &[Decimal]) -> Result<Vec<Complex>> {
+	let degree = monic.len() - 1;
+
+	// Large roots are scaled into the unit circle to stop Decimal from overflowing.
+	// Small ones aren't since Decimal has a fixed number of decimal places.
+	// https://en.wikipedia.org/wiki/Geometrical_properties_of_polynomial_roots#Fujiwara's_bound
+	let mut bound = Decimal::ZERO;
+
+	for (i, c) in monic.iter().enumerate().take(degree) {
+		if !c.is_zero() {
+			let value = transcendental::powf(c.abs(), Decimal::ONE / Decimal::from(degree - i)).ok_or_else(undefined)?;
+
+			bound = bound.max(Decimal::TWO * value);
+		}
+	}
+
+	let scale = match transcendental::powi(bound, degree as i64) {
+		Some(value) if value < Decimal::from(1_000_000_000_000_000_000_u64) => Decimal::ONE,
+		_ => bound
+	};
+
+	let mut factor = Decimal::ONE;
+	let mut scaled = vec![Decimal::ZERO; degree + 1];
+
+	for i in (0..=degree).rev() {
+		scaled[i] = monic[i] / factor;
+		factor *= scale;
+	}
+
+	let evaluate = |x: Complex| scaled.iter().rev().fold(Complex::default(), |acc, c| acc * x + Complex::real(*c));
+
+	// Evenly spaced starting points. Offset so none start on the real axis.
+	let mut roots = (0..degree)
+		.map(|k| {
+			let angle = TWO_PI * Decimal::from(k) / Decimal::from(degree) + Decimal::new(4, 1);
+
+			Some(Complex::new(transcendental::cos(angle)?, transcendental::sin(angle)?))
+		})
+		.collect::<Option<Vec<_>>>()
+		.ok_or_else(undefined)?;
+
+	let tolerance = Decimal::new(1, 24);
+
+	for _ in 0..MAX_ITERATIONS {
+		let mut largest_change = Decimal::ZERO;
+
+		for k in 0..degree {
+			let denominator = roots.iter()
+				.enumerate()
+				.filter(|(j, _)| *j != k)
+				.fold(Complex::real(Decimal::ONE), |acc, (_, root)| acc * (roots[k] - *root));
+
+			if denominator.is_zero() {
+				return Err(Error::UnableToSolve("Unable to separate the roots".to_string()));
+			}
+
+			let change = evaluate(roots[k]) / denominator;
+
+			roots[k] = roots[k] - change;
+
+			largest_change = largest_change.max(change.re.abs() + change.im.abs());
+		}
+
+		if largest_change < tolerance {
+			return Ok(roots.into_iter().map(|root| root * Complex::real(scale)).collect());
+		}
+	}
+
+	Err(Error::UnableToSolve("The roots didn't converge".to_string()))
+}
+
+
+/// Divides out repeated roots. p / gcd(p, p')
+///
+/// (x - 1)²(x - 2) -> (x - 1)(x - 2)
+fn square_free(monic: &[Decimal]) -> Vec<Decimal> {
+	let derivative = monic.iter()
+		.enumerate()
+		.skip(1)
+		.map(|(i, c)| c * Decimal::from(i))
+		.collect::<Vec<_>>();
+
+	let mut a = monic.to_vec();
+	let mut b = derivative;
+
+	while !b.is_empty() {
+		let (_, remainder) = divide(&a, &b);
+
+		a = b;
+		b = remainder;
+	}
+
+	if a.len() < 2 {
+		return monic.to_vec();
+	}
+
+	let quotient = divide(monic, &a).0;
+	let lead = quotient[quotient.len() - 1];
+
+	quotient.into_iter().map(|c| c / lead).collect()
+}
+
+/// Polynomial long division. Returns (quotient, remainder).
+///
+/// Coefficients which are only left over from rounding are removed from the remainder.
+fn divide(dividend: &[Decimal], divisor: &[Decimal]) -> (Vec<Decimal>, Vec<Decimal>) {
+	let lead = divisor[divisor.len() - 1];
+	let size = dividend.iter().map(|c| c.abs()).max().unwrap_or_default();
+
+	let mut remainder = dividend.to_vec();
+	let mut quotient = vec![Decimal::ZERO; (dividend.len() + 1).saturating_sub(divisor.len())];
+
+	for i in (0..quotient.len()).rev() {
+		let factor = remainder[i + divisor.len() - 1] / lead;
+
+		quotient[i] = factor;
+
+		for (j, c) in divisor.iter().enumerate() {
+			remainder[i + j] -= factor * c;
+		}
+	}
+
+	remainder.truncate(divisor.len() - 1);
+
+	for c in remainder.iter_mut() {
+		if c.abs() <= size * EPSILON {
+			*c = Decimal::ZERO;
+		}
+	}
+
+	while remainder.last().map(|c| c.is_zero()).unwrap_or_default() {
+		remainder.pop();
+	}
+
+	(quotient, remainder)
+}
+
+/// Removes parts which are only numerical noise. 2 + 1e-25i -> 2
+fn clean(root: Complex) -> Complex {
+	let size = root.re.abs() + root.im.abs();
+
+	let mut root = root.round_sf(ROOT_PRECISION);
+
+	if root.im.abs() <= size * EPSILON {
+		root.im = Decimal::ZERO;
+	}
+
+	if root.re.abs() <= size * EPSILON {
+		root.re = Decimal::ZERO;
+	}
+
+	root
+}
+
+fn sqrt(value: Decimal) -> Result<Decimal> {
+	transcendental::sqrt(value).ok_or_else(undefined)
+}
+
+fn cbrt(value: Decimal) -> Result<Decimal> {
+	transcendental::cbrt(value).ok_or_else(undefined)
+}
+
+fn undefined() -> Error {
+	Error::UnableToSolve("A value was too large to represent".to_string())
+}
@@ -1,5 +1,7 @@
 use std::fmt;
 
+use rust_decimal::Decimal;
+
 use conversion::Error as ConversionError;
 use crate::{ExprToken, Operator};
 
@@ -16,7 +18,17 @@ pub enum Error {
 	UnexpectedToken(ExprToken),
 	Conversion(ConversionError),
 	UnableToOperateValues(Operator),
-	UnableToConvertValues(String, String)
+	UnableToConvertValues(String, String),
+	MismatchedListLength(usize, usize),
+	InvalidIndex(Decimal, usize),
+	UnknownUnit(String),
+	/// Constant names can't be the same as a unit or function.
+	ReservedName(String),
+	UnableToSolve(String),
+	/// Function name, expected arguments, given arguments.
+	ArgumentCount(String, String, usize),
+	/// From and to currency codes.
+	NoExchangeRate(String, String)
 }
 
 
@@ -31,6 +43,13 @@ impl fmt::Display for Error {
 			Error::ExpectedQuantity => write!(f, "Expected Quantity"),
 			Error::InvalidFunction => write!(f, "Invalid Function"),
 			Error::InvalidOperator => write!(f, "Invalid Operator"),
+			Error::MismatchedListLength(a, b) => write!(f, "Unable to operate on lists of length {} and {}", a, b),
+			Error::InvalidIndex(i, len) => write!(f, "Index {} is invalid for a list of length {}", i, len),
+			Error::ArgumentCount(name, expects, got) => write!(f, "{} expects {}, got {}", name, expects, got),
+			Error::UnknownUnit(name) => write!(f, r#"Unknown unit "{}""#, name),
+			Error::UnableToSolve(reason) => write!(f, "Unable to solve: {}", reason),
+			Error::ReservedName(name) => write!(f, r#""{}" is already a unit or function name"#, name),
+			Error::NoExchangeRate(from, to) => write!(f, "No exchange rate from {} to {} has been loaded", from, to),
 			Error::Conversion(e) => e.fmt(f)
 		}
 	}
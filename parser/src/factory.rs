@@ -1,14 +1,27 @@
+use std::rc::Rc;
+
 use rust_decimal::Decimal;
 
-use conversion::{BaseUnit, FunctionEval};
+use conversion::{BaseUnit, FunctionEval, Units, Complex, DateTime, MonthLength, is_convertable, physical_constants};
+use conversion::units::{Radian, RuntimeUnit, RuntimePrefix};
+use conversion::Error as ConversionError;
 
-use crate::{Parser, ParseValue, functions, units, consts, Result};
+use crate::{Parser, ParseValue, Settings, Rounding, Notation, ClockFormat, Value, ComplexForm, Clock, SystemClock, ExchangeRates, functions, units, consts, Result, Error};
 
 
 pub struct Factory {
 	functions: Vec<(String, Box<dyn FunctionEval>)>,
 	units: Vec<Box<dyn BaseUnit>>,
-	consts: Vec<(String, Decimal)>
+	/// Units added with `add_unit`. They're also in `units`.
+	user_units: Vec<RuntimeUnit>,
+	/// Prefixes tried on names which aren't units. kibi- = 1024
+	prefixes: Vec<RuntimePrefix>,
+	consts: Vec<(String, Value)>,
+	/// Names of the constants added after the defaults. Saved with the session.
+	user_consts: Vec<String>,
+	settings: Settings,
+	clock: Box<dyn Clock>,
+	exchange_rates: Rc<ExchangeRates>
 }
 
 impl Factory {
@@ -25,9 +38,202 @@ impl Factory {
 		self.create_parser(eval).parse()
 	}
 
+	/// Parses a value to be stored. It isn't rounded to the precision.
+	pub fn parse_value(&self, eval: &str) -> Result<Value> {
+		let mut parser = self.create_parser(eval);
+		parser.options.full_precision = true;
+
+		parser.parse()?
+			.into_value()
+			.ok_or_else(|| Error::Conversion(ConversionError::InvalidArgument(format!("{} isn't a value", eval.trim()))))
+	}
+
+
+	/// Adds or replaces a constant. Names can't shadow units or functions.
+	pub fn add_constant(&mut self, name: String, value: Value) -> Result<()> {
+		if self.find_unit(&name).is_some() || self.find_func(&name).is_some() {
+			return Err(Error::ReservedName(name));
+		}
+
+		if let Some(existing) = self.consts.iter_mut().find(|c| c.0 == name) {
+			existing.1 = value;
+		} else {
+			self.consts.push((name.clone(), value));
+		}
+
+		if !self.user_consts.contains(&name) {
+			self.user_consts.push(name);
+		}
+
+		Ok(())
+	}
+
+
+	/// Adds or replaces a unit added before. Names can't shadow other units, functions or constants.
+	pub fn add_unit(&mut self, unit: RuntimeUnit) -> Result<()> {
+		let replaced = self.user_units.iter().position(|u| u.name == unit.name);
+
+		for name in unit.names() {
+			let is_replaced = replaced.map(|i| self.user_units[i].names().contains(&name)).unwrap_or_default();
+
+			if (self.find_unit(name).is_some() && !is_replaced) || self.find_func(name).is_some() || self.find_const(name).is_some() {
+				return Err(Error::ReservedName(name.to_string()));
+			}
+		}
+
+		// Units and constants made from it keep the old one. Saving and loading them would use the new one.
+		if let Some(index) = replaced {
+			let name = &self.user_units[index].name;
+
+			let user = self.user_units.iter()
+				.filter(|u| &u.name != name)
+				.find(|u| u.is_defined_from(name))
+				.map(|u| u.name.clone())
+				.or_else(|| self.user_consts.iter().find(|c| self.find_const(c).is_some_and(|v| uses_unit(&v, name))).cloned());
+
+			if let Some(user) = user {
+				return Err(Error::Conversion(ConversionError::InvalidArgument(format!(r#""{}" can't be redefined while "{}" is made from it"#, name, user))));
+			}
+		}
+
+		// Replaced in place so units defined from it are still saved after it.
+		match replaced {
+			Some(index) => {
+				if let Some(existing) = self.units.iter_mut().find(|u| u.long() == unit.name) {
+					*existing = Box::new(unit.clone());
+				}
+
+				self.user_units[index] = unit;
+			}
+			None => {
+				self.units.push(Box::new(unit.clone()));
+				self.user_units.push(unit);
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Adds or replaces a prefix with the same name.
+	pub fn add_prefix(&mut self, prefix: RuntimePrefix) -> Result<()> {
+		if prefix.name.is_empty() || prefix.factor.is_zero() {
+			return Err(Error::Conversion(ConversionError::InvalidArgument(format!(r#"Invalid prefix "{}""#, prefix.name))));
+		}
+
+		match self.prefixes.iter_mut().find(|p| p.name == prefix.name) {
+			Some(existing) => *existing = prefix,
+			None => self.prefixes.push(prefix)
+		}
+
+		Ok(())
+	}
+
+
+	pub fn settings(&self) -> &Settings {
+		&self.settings
+	}
+
+	/// Sets the unit bare numbers are in when passed into trig functions.
+	pub fn set_angle_unit(&mut self, name: &str) -> Result<()> {
+		let unit = self.find_unit(name).ok_or_else(|| Error::UnknownUnit(name.to_string()))?;
+
+		let (from, to) = (Units::new(unit.clone()), Units::new(Box::new(Radian)));
+
+		if !is_convertable(&from, &to) {
+			return Err(Error::UnableToConvertValues(from.long(), to.long()));
+		}
+
+		self.settings.angle_unit = unit;
+
+		Ok(())
+	}
+
+	/// Digits results are rounded to. None shows every digit.
+	pub fn set_precision(&mut self, digits: Option<u32>) -> Result<()> {
+		if digits.map(|d| d > 28).unwrap_or_default() {
+			return Err(Error::Conversion(ConversionError::InvalidArgument("The precision can be at most 28 digits".to_string())));
+		}
+
+		self.settings.precision = digits;
+
+		Ok(())
+	}
+
+	pub fn set_rounding(&mut self, name: &str) -> Result<()> {
+		self.settings.rounding = Rounding::from_name(name)
+			.ok_or_else(|| Error::Conversion(ConversionError::InvalidArgument(format!(r#"Unknown rounding mode "{}". Expected even, up or zero"#, name))))?;
 
-	pub fn add_constant(&mut self, name: String, value: Decimal) {
-		self.consts.push((name, value));
+		Ok(())
+	}
+
+	pub fn set_notation(&mut self, name: &str) -> Result<()> {
+		self.settings.notation = Notation::from_name(name)
+			.ok_or_else(|| Error::Conversion(ConversionError::InvalidArgument(format!(r#"Unknown notation "{}". Expected fixed, sigfig or eng"#, name))))?;
+
+		Ok(())
+	}
+
+	/// Shows times of day in 12 or 24 hour time. "auto" shows them as they were written.
+	pub fn set_clock_format(&mut self, name: &str) -> Result<()> {
+		self.settings.clock_format = match name {
+			"auto" => None,
+			name => Some(
+				ClockFormat::from_name(name)
+					.ok_or_else(|| Error::Conversion(ConversionError::InvalidArgument(format!(r#"Unknown clock format "{}". Expected 12, 24 or auto"#, name))))?
+			)
+		};
+
+		Ok(())
+	}
+
+	/// Months used when splitting durations. "off" uses weeks instead.
+	pub fn set_duration_months(&mut self, name: &str) -> Result<()> {
+		self.settings.duration_months = match name {
+			"off" => None,
+			name => Some(
+				MonthLength::from_name(name)
+					.ok_or_else(|| Error::Conversion(ConversionError::InvalidArgument(format!(r#"Unknown month length "{}". Expected average, 30d, calendar or off"#, name))))?
+			)
+		};
+
+		Ok(())
+	}
+
+	/// Replaces the exchange rates with ones from a CSV or JSON file's contents.
+	pub fn load_exchange_rates(&mut self, contents: &str) -> Result<&ExchangeRates> {
+		self.exchange_rates = Rc::new(ExchangeRates::parse(contents)?);
+
+		Ok(&self.exchange_rates)
+	}
+
+	pub fn exchange_rates(&self) -> Rc<ExchangeRates> {
+		Rc::clone(&self.exchange_rates)
+	}
+
+	/// Shows exact results as fractions.
+	pub fn set_rational(&mut self, enabled: bool) {
+		self.settings.rational = enabled;
+	}
+
+	/// Sets the largest denominator "-> frac" rounds to. 16 for sixteenths of an inch.
+	pub fn set_max_denominator(&mut self, value: i128) -> Result<()> {
+		if value < 1 {
+			return Err(Error::Conversion(ConversionError::InvalidArgument("The largest denominator has to be at least 1".to_string())));
+		}
+
+		self.settings.max_denominator = value;
+
+		Ok(())
+	}
+
+
+	/// Replaces where "now" and "today" come from.
+	pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+		self.clock = clock;
+	}
+
+	pub fn now(&self) -> DateTime {
+		self.clock.now()
 	}
 
 
@@ -35,20 +241,32 @@ impl Factory {
 		self.functions.as_slice()
 	}
 
-	pub fn get_constants(&self) -> &[(String, Decimal)] {
+	pub fn get_constants(&self) -> &[(String, Value)] {
 		self.consts.as_slice()
 	}
 
+	pub fn get_user_constants(&self) -> &[String] {
+		self.user_consts.as_slice()
+	}
+
 	pub fn get_units(&self) -> &[Box<dyn BaseUnit>] {
 		self.units.as_slice()
 	}
 
+	pub fn get_user_units(&self) -> &[RuntimeUnit] {
+		self.user_units.as_slice()
+	}
+
+	pub fn get_prefixes(&self) -> &[RuntimePrefix] {
+		self.prefixes.as_slice()
+	}
+
 
-	pub fn find_const(&self, name: &str) -> Option<Decimal> {
+	pub fn find_const(&self, name: &str) -> Option<Value> {
 		self.consts
 		.iter()
 		.find(|u| u.0 == name)
-		.map(|i| i.1)
+		.map(|i| i.1.clone())
 	}
 
 
@@ -64,6 +282,15 @@ impl Factory {
 		.iter()
 		.find(|u| u == &name)
 		.cloned()
+		.or_else(|| self.find_prefixed_unit(name))
+	}
+
+	/// A unit with an added prefix in front. kibibyte, KiB
+	fn find_prefixed_unit(&self, name: &str) -> Option<Box<dyn BaseUnit>> {
+		self.prefixes
+		.iter()
+		.find_map(|prefix| prefix.apply(name, |rest| self.units.iter().find(|u| u == &rest).map(|u| u.as_ref())))
+		.map(|unit| Box::new(unit) as Box<dyn BaseUnit>)
 	}
 
 	pub fn is_custom_unit(&self, name: &str) -> bool {
@@ -75,10 +302,45 @@ impl Factory {
 
 impl Default for Factory {
 	fn default() -> Factory {
-		Factory {
+		let mut factory = Factory {
 			functions: functions::default_functions().into_iter().map(|f| (f.0.to_string(), f.1)).collect(),
-			consts: consts::default_constants().into_iter().map(|f| (f.0.to_string(), f.1)).collect(),
+			consts: Vec::new(),
+			user_consts: Vec::new(),
 			units: units::default_units(),
+			user_units: Vec::new(),
+			prefixes: Vec::new(),
+			settings: Settings::default(),
+			clock: Box::new(SystemClock),
+			exchange_rates: Rc::new(ExchangeRates::default())
+		};
+
+		// Constants are parsed after units are loaded so they can use them.
+		// Every default constant is checked in the tests.
+		for (name, eval) in consts::default_constants() {
+			// Irrational, they're only as exact as the Decimal.
+			if let Ok(Some(Value::Quantity(value))) = factory.parse(eval).map(ParseValue::into_value) {
+				factory.consts.push((name.to_string(), Value::Quantity(value.into_inexact())));
+			}
+		}
+
+		// Imaginary unit. 3 + 4i
+		for name in &["i", "j"] {
+			factory.consts.push((name.to_string(), Value::Complex(Complex::new(Decimal::ZERO, Decimal::ONE), None, ComplexForm::Rectangular)));
 		}
+
+		for constant in physical_constants() {
+			factory.consts.push((constant.name.to_string(), Value::Quantity(constant.value)));
+		}
+
+		factory
 	}
-}
\ No newline at end of file
+}
+
+
+/// A unit of the value is `name` or is defined from it.
+fn uses_unit(value: &Value, name: &str) -> bool {
+	match value {
+		Value::List(items) => items.iter().any(|item| uses_unit(item, name)),
+		value => value.as_base_unit().is_ok_and(|units| units.terms().iter().any(|(unit, _)| unit.is_defined_from(name)))
+	}
+}
@@ -1,5 +1,6 @@
 use conversion::FunctionEval;
 use conversion::quantity::math::*;
+use conversion::quantity::calculus::*;
 
 
 
@@ -7,39 +8,72 @@ pub fn default_functions<'a>() -> Vec<(&'a str, Box<dyn FunctionEval>)> {
 	vec![
 		("min", Box::new(Min)),
 		("max", Box::new(Max)),
+		("sum", Box::new(Sum)),
+		("avg", Box::new(Average)),
+		("mean", Box::new(Average)),
+		("median", Box::new(Median)),
+		("mode", Box::new(Mode)),
+		("count", Box::new(Count)),
+		("product", Box::new(Product)),
+		("variance", Box::new(Variance)),
+		("pvariance", Box::new(PopulationVariance)),
+		("stdev", Box::new(StandardDeviation)),
+		("stddev", Box::new(StandardDeviation)),
+		("pstdev", Box::new(PopulationStandardDeviation)),
+		("percentile", Box::new(Percentile)),
+		("zscore", Box::new(ZScore)),
+		("slope", Box::new(RegressionSlope)),
+		("intercept", Box::new(RegressionIntercept)),
+		("correlation", Box::new(Correlation)),
 		("ceil", Box::new(Ceil)),
 		("floor", Box::new(Floor)),
 		("round", Box::new(Round)),
+		("round_to", Box::new(RoundTo)),
+		("sigfig", Box::new(SignificantFigures)),
 		("trunc", Box::new(Trunc)),
 		("fract", Box::new(Fract)),
 		("abs", Box::new(Abs)),
-		// ("signum", Box::new(Signum)),
-		// ("sqrt", Box::new(Sqrt)),
-		// ("exp", Box::new(Exp)),
-		// ("exp2", Box::new(Exp2)),
-		// ("ln", Box::new(NaturalLogarithm)),
-		// ("log2", Box::new(Log2)),
-		// ("log10", Box::new(Log10)),
-		// ("cbrt", Box::new(CubicRoot)),
-		// ("sin", Box::new(Sine)),
-		// ("cos", Box::new(Cosine)),
-		// ("tan", Box::new(Tangent)),
-		// ("asin", Box::new(Arcsine)),
-		// ("atan", Box::new(Arctangent)),
-		// ("acos", Box::new(Arccosine)),
-		// ("sinh", Box::new(HyperbolicSine)),
-		// ("tanh", Box::new(HyperbolicTangent)),
-		// ("cosh", Box::new(HyperbolicCosine)),
-		// ("asinh", Box::new(InverseHyperbolicSine)),
-		// ("atanh", Box::new(InverseHyperbolicTangent)),
-		// ("acosh", Box::new(InverseHyperbolicCosine)),
+		("re", Box::new(RealPart)),
+		("im", Box::new(ImaginaryPart)),
+		("arg", Box::new(ComplexArgument)),
+		("conj", Box::new(Conjugate)),
+		("lo", Box::new(LowerBound)),
+		("hi", Box::new(UpperBound)),
+		("mid", Box::new(Midpoint)),
+		("width", Box::new(Width)),
+		("certainly", Box::new(LowerBound)),
+		("possibly", Box::new(UpperBound)),
+		("signum", Box::new(Signum)),
+		("sqrt", Box::new(Sqrt)),
+		("exp", Box::new(Exp)),
+		("exp2", Box::new(Exp2)),
+		("ln", Box::new(NaturalLogarithm)),
+		("log2", Box::new(Log2)),
+		("log10", Box::new(Log10)),
+		("cbrt", Box::new(CubicRoot)),
+		("sin", Box::new(Sine)),
+		("cos", Box::new(Cosine)),
+		("tan", Box::new(Tangent)),
+		("asin", Box::new(Arcsine)),
+		("atan", Box::new(Arctangent)),
+		("acos", Box::new(Arccosine)),
+		("sinh", Box::new(HyperbolicSine)),
+		("tanh", Box::new(HyperbolicTangent)),
+		("cosh", Box::new(HyperbolicCosine)),
+		("asinh", Box::new(InverseHyperbolicSine)),
+		("atanh", Box::new(InverseHyperbolicTangent)),
+		("acosh", Box::new(InverseHyperbolicCosine)),
 
-		// ("copysign", Box::new(Copysign)),
-		// ("divEuclid", Box::new(DivEuclid)),
-		// ("remEuclid", Box::new(RemEuclid)),
-		// ("powf", Box::new(Pow)),
-		// ("log", Box::new(Log)),
-		// ("hypot", Box::new(Hypot)),
-		// ("atan2", Box::new(Atan2))
+		("copysign", Box::new(Copysign)),
+		("divEuclid", Box::new(DivEuclid)),
+		("remEuclid", Box::new(RemEuclid)),
+		("powf", Box::new(Pow)),
+		("log", Box::new(Log)),
+		("hypot", Box::new(Hypot)),
+		("atan2", Box::new(Atan2)),
+
+		("root", Box::new(Root)),
+		("deriv", Box::new(Derivative)),
+		("integrate", Box::new(Integrate)),
 	]
 }
\ No newline at end of file
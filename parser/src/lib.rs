@@ -30,15 +30,25 @@ pub mod units;
 pub mod functions;
 pub mod consts;
 pub mod equations;
+pub mod settings;
+pub mod clock;
+pub mod dates;
+pub mod currency;
+pub mod session;
+pub mod define;
 
 
 pub use factory::Factory;
+pub use settings::{Settings, Rounding, Notation, ClockFormat};
+pub use clock::{Clock, SystemClock, FixedClock};
 pub use error::{Error, Result};
 pub use tokens::{Operator, ExprToken, TokenType};
 pub use parser::{Parser, ParseValue, TokenSlicer};
 pub use tokenizer::Tokenizer;
-pub use value::Value;
+pub use value::{Value, ComplexForm, NumberFormat};
 pub use operations::ExpressionArg;
+pub use currency::ExchangeRates;
+pub use define::{Definition, strip_define, strip_prefix_keyword, unit_definition, prefix_definition};
 
 
 
@@ -46,6 +56,7 @@ pub use operations::ExpressionArg;
 #[cfg(test)]
 mod tests {
 	use rust_decimal_macros::dec;
+	use conversion::{DateTime, Quantity, Units};
 
 	use super::*;
 
@@ -64,8 +75,9 @@ mod tests {
 		test!(factory, "1 - 1", 0.0);
 		test!(factory, "2 * 2", 4.0);
 		test!(factory, "10 / 2", 5.0);
-		// test!(factory, "2^2", 4.0);
-		// test!(factory, "2^2^2", 16.0);
+		test!(factory, "2^2", 4.0);
+		test!(factory, "2^2^2", 16.0);
+		test!(factory, "2^-2", 0.25);
 
 		test!(factory, "1 + (1 + 1)", 3.0);
 		test!(factory, "(1 - 1) + 1", 1.0);
@@ -86,6 +98,10 @@ mod tests {
 		test!(factory, "2GB >= 1GB", 1.0);
 		test!(factory, "1GB < 2GB", 1.0);
 		test!(factory, "1GB <= 2GB", 1.0);
+
+		test!(factory, "200 + 20%", 240.0);
+		test!(factory, "200 * 20%", 8000.0);
+		test!(factory, "200 / 20%", 5.0);
 	}
 
 	#[test]
@@ -94,5 +110,1006 @@ mod tests {
 
 		test!(factory, "max(1.5, 10.0)", 10.0);
 		test!(factory, "max(1.5, 10.0, 30.0, 15.0)", 30.0);
+		test!(factory, "max(1 + 2, 2) * 2", 6.0);
+	}
+
+	#[test]
+	fn test_lists() {
+		let factory  = Factory::new();
+
+		assert_eq!(
+			factory.parse("[1, 2, 3] * 2").unwrap(),
+			Value::List(vec![
+				Value::new_quantity(dec!(2.0)),
+				Value::new_quantity(dec!(4.0)),
+				Value::new_quantity(dec!(6.0))
+			])
+		);
+
+		test!(factory, "([1, 2] + [3, 4])[1]", 6.0);
+		test!(factory, "[1, 2, 3][0]", 1.0);
+		test!(factory, "[1, 2, 3][-1]", 3.0);
+		test!(factory, "([1 km, 500 m] -> m)[0]", 1000.0);
+
+		test!(factory, "sum([1 km, 500 m])", 1.5);
+		test!(factory, "round(sum([1 km, 500 m, 2 mi]) -> m)", 4719.0);
+		test!(factory, "avg([1, 2, 3, 4])", 2.5);
+		test!(factory, "median([3, 1, 2])", 2.0);
+		test!(factory, "count([1, 2, 3], 4)", 4.0);
+		test!(factory, "product([2, 3, 4])", 24.0);
+		test!(factory, "stddev([2, 4, 6])", 2.0);
+
+		assert!(factory.parse("[1, 2] + [1, 2, 3]").is_err());
+		assert!(factory.parse("[1, 2][2]").is_err());
+		assert!(factory.parse("sum([1 km, 1 kg])").is_err());
+	}
+
+	#[test]
+	fn test_unit_algebra() {
+		let factory  = Factory::new();
+
+		let display = |eval: &str| factory.parse(eval).unwrap().to_string();
+
+		assert_eq!(display("3 m * 2 m"), "6 m²");
+		assert_eq!(display("10 m² / 2 m"), "5 m");
+		assert_eq!(display("5 m/s * 10 s"), "50 m");
+		assert_eq!(display("36 km/h -> m/s"), "10 m/s");
+		assert_eq!(display("100 km/2h"), "50 km/h");
+
+		test!(factory, "5 km / (10 km/h)", 0.5);
+		test!(factory, "4 GB / 2 GB", 2.0);
+	}
+
+	#[test]
+	fn test_statistics() {
+		let factory  = Factory::new();
+
+		let display = |eval: &str| factory.parse(eval).unwrap().to_string();
+
+		test!(factory, "mean([1, 2, 3, 4])", 2.5);
+		test!(factory, "mode([1, 2, 2, 3])", 2.0);
+		test!(factory, "variance([2, 4, 6])", 4.0);
+		test!(factory, "pvariance([2, 4, 4, 4, 5, 5, 7, 9])", 4.0);
+		test!(factory, "stdev([2, 4, 6])", 2.0);
+		test!(factory, "pstdev([2, 4, 4, 4, 5, 5, 7, 9])", 2.0);
+		test!(factory, "percentile(25, [1, 2, 3, 4, 5])", 2.0);
+		test!(factory, "percentile(50, [1, 2, 3, 4])", 2.5);
+		test!(factory, "zscore(6, [2, 4, 6])", 1.0);
+		test!(factory, "slope([1, 2, 3], [3, 5, 7])", 2.0);
+		test!(factory, "intercept([1, 2, 3], [3, 5, 7])", 1.0);
+		test!(factory, "correlation([1, 2, 3], [3, 5, 7])", 1.0);
+
+		assert_eq!(display("variance([2 m, 4 m, 6 m])"), "4 m²");
+		assert_eq!(display("stdev([2 m, 4 m, 6 m])"), "2 m");
+		assert_eq!(display("slope([1 s, 2 s, 3 s], [2 m, 4 m, 6 m])"), "2 m/s");
+
+		assert!(factory.parse("max(1 km, 1 kg)").is_err());
+		assert!(factory.parse("variance([1])").is_err());
+		assert!(factory.parse("percentile(101, [1, 2])").is_err());
+		assert!(factory.parse("slope([1, 2, 3], [1, 2])").is_err());
+
+		// Overflowing squares are errors instead of panics.
+		let error = |eval: &str| factory.parse(eval).unwrap_err().to_string();
+
+		assert_eq!(error("stdev(1e15, -1e15)"), "Invalid Argument: stdev values are too large to represent");
+		assert_eq!(error("variance(1e20, -1e20)"), "Invalid Argument: variance values are too large to represent");
+		assert!(factory.parse("slope([1e15, 2e15], [1e15, 3e15])").is_err());
+		assert!(factory.parse("sum(7e28, 7e28)").is_err());
+		assert_eq!(display("avg(7e28, 7e28)"), "70000000000000000000000000000");
+		assert!(display("correlation([1e14, 2e14], [1e14, 3e14])").starts_with("0.99999999999999999999"));
+	}
+
+	#[test]
+	fn test_transcendental() {
+		let factory  = Factory::new();
+
+		let display = |eval: &str| factory.parse(eval).unwrap().to_string();
+
+		test!(factory, "sqrt(2)", 1.41421356237309504880169);
+		test!(factory, "exp(1)", 2.71828182845904523536029);
+		test!(factory, "ln(10)", 2.30258509299404568401799);
+		test!(factory, "log2(1024)", 10.0);
+		test!(factory, "log10(0.001)", -3.0);
+		test!(factory, "log(8, 2)", 3.0);
+		test!(factory, "sin(1)", 0.841470984807896506652502);
+		test!(factory, "cos(1)", 0.540302305868139717400937);
+		test!(factory, "atan(1)", 0.78539816339744830961566085);
+		test!(factory, "tanh(1)", 0.761594155955764888119458);
+		test!(factory, "powf(2, 0.5)", 1.41421356237309504880169);
+		test!(factory, "cbrt(-8)", -2.0);
+		test!(factory, "sin(PI/2)", 1.0);
+		test!(factory, "sin(10000000000)", -0.487506025087510691527794);
+		test!(factory, "cos(1e20)", 0.763970404441728300400147);
+
+		assert_eq!(display("sqrt(9 m²)"), "3 m");
+		assert_eq!(display("cbrt(27 m³)"), "3 m");
+		assert_eq!(display("hypot(3 m, 4 m)"), "5 m");
+		assert_eq!(display("powf(3 m, 2)"), "9 m²");
+
+		assert!(factory.parse("exp(5 m)").is_err());
+		assert!(factory.parse("ln(5 m)").is_err());
+		assert!(factory.parse("ln(0)").is_err());
+		assert!(factory.parse("sqrt(9 m)").is_err());
+		assert!(factory.parse("asin(2)").is_err());
+	}
+
+	#[test]
+	fn test_angles() {
+		let mut factory  = Factory::new();
+
+		let display = |factory: &Factory, eval: &str| factory.parse(eval).unwrap().to_string();
+
+		test!(factory, "90 deg -> rad", 1.57079632679489661923132);
+		test!(factory, "1 turn -> deg", 360.0);
+		test!(factory, "200 grad -> deg", 180.0);
+		test!(factory, "3600 arcsec -> arcmin", 60.0);
+		test!(factory, "sin(30 deg)", 0.5);
+		test!(factory, "cos(200 grad)", -1.0);
+		test!(factory, "tan(0.125 turn)", 1.0);
+		test!(factory, "asin(0.5) -> deg", 30.0);
+		assert_eq!(display(&factory, "asin(1) -> deg"), "90 deg");
+		assert_eq!(display(&factory, "acos(0.5) -> deg"), "60 deg");
+
+		assert_eq!(display(&factory, "acos(-1)"), "3.1415926535897932384626434 rad");
+		assert_eq!(display(&factory, "atan2(1, 1) -> deg"), "45 deg");
+		assert!(factory.parse("sin(5 m)").is_err());
+
+		// Bare numbers use the default angle unit.
+		test!(factory, "sin(0)", 0.0);
+		factory.set_angle_unit("deg").unwrap();
+		test!(factory, "sin(90)", 1.0);
+		test!(factory, "sin(1 rad)", 0.841470984807896506652502);
+
+		assert!(factory.set_angle_unit("m").is_err());
+	}
+
+	#[test]
+	fn test_function_info() {
+		let factory  = Factory::new();
+
+		test!(factory, "round(3.14159, 2)", 3.14);
+		test!(factory, "round(1234, -2)", 1200.0);
+
+		assert_eq!(
+			factory.parse("round(1, 2, 3)").unwrap_err().to_string(),
+			"round expects 1-2 arguments, got 3"
+		);
+
+		assert_eq!(
+			factory.parse("sin()").unwrap_err().to_string(),
+			"sin expects 1 argument, got 0"
+		);
+
+		assert_eq!(
+			factory.parse("sum()").unwrap_err().to_string(),
+			"sum expects at least 1 argument, got 0"
+		);
+
+		// Fixed arity functions are applied to each item.
+		assert_eq!(factory.parse("sqrt([4, 9])").unwrap().to_string(), "[2, 3]");
+		assert!(factory.parse("hypot([3, 5], [4])").is_err());
+
+		let round = factory.find_func("round").unwrap().info();
+		assert_eq!(round.signature(), "round(x, [places: number])");
+
+		let sum = factory.find_func("sum").unwrap().info();
+		assert_eq!(sum.signature(), "sum(values...)");
+		assert_eq!(factory.find_func("mean").unwrap().info().signature_as("mean"), "mean(values...)");
+	}
+
+	#[test]
+	fn test_constants() {
+		let mut factory  = Factory::new();
+
+		let display = |factory: &Factory, eval: &str| factory.parse(eval).unwrap().to_string();
+
+		test!(factory, "2 * PI", 6.2831853071795864769252867666);
+		assert_eq!(display(&factory, "c"), "299792458 m/s");
+		assert_eq!(display(&factory, "c -> km/s"), "299792.458 km/s");
+		assert_eq!(display(&factory, "c * 2 s"), "599584916 m");
+		assert_eq!(display(&factory, "g0 * 2 kg"), "19.61330 m·kg/s²");
+
+		// Physical constants.
+		assert_eq!(display(&factory, "R"), "8.31446261815324 kg·m²/(s²·mol·K)");
+		assert_eq!(display(&factory, "e"), "0.0000000000000000001602176634 A·s");
+		assert_eq!(display(&factory, "N_A * 2 mol"), "1204428152000000000000000");
+		assert_eq!(display(&factory, "2 h"), "2 h");
+		assert_eq!(display(&factory, "m_p -> ng"), "0.00000000000000167262192595 ng");
+		// 9.1093837139e-31 kg is past Decimal's 28 decimal places.
+		assert!(factory.parse("m_e -> kg").is_err());
+		assert!(factory.parse("planck -> kg·m²/s").is_err());
+
+		// Units take priority. "E" is Euler's number, "EB" is still exabytes.
+		assert_eq!(display(&factory, "1 EB -> PB"), "1000 PB");
+
+		let value = factory.parse("30 km/h").unwrap().into_value().unwrap();
+		factory.add_constant("v".to_string(), value).unwrap();
+		assert_eq!(display(&factory, "v * 2 h"), "60 km");
+
+		assert!(factory.add_constant("m".to_string(), Value::new_quantity(dec!(1))).is_err());
+		assert!(factory.add_constant("sqrt".to_string(), Value::new_quantity(dec!(1))).is_err());
+	}
+
+	#[test]
+	fn test_default_constants() {
+		let factory  = Factory::new();
+
+		let names = consts::default_constants().into_iter()
+			.map(|(name, _)| name)
+			.chain(vec!["i", "j"])
+			.chain(conversion::physical_constants().into_iter().map(|c| c.name));
+
+		// Defaults skip the checks add_constant does.
+		for name in names {
+			assert!(factory.find_const(name).is_some(), "{} isn't a constant", name);
+			assert!(factory.find_unit(name).is_none(), "{} is also a unit", name);
+			assert!(factory.find_func(name).is_none(), "{} is also a function", name);
+		}
+
+		assert!(factory.get_user_constants().is_empty());
+		assert_eq!(factory.parse("planck").unwrap().to_string(), "0.000000000000000000662607015 pg·m²/s");
+	}
+
+	#[test]
+	fn test_linear_equations() {
+		let factory  = Factory::new();
+
+		let display = |eval: &str| factory.parse(eval).unwrap().to_string();
+
+		test!(factory, "solve(3x - 6 = 9, x)", 5.0);
+		test!(factory, "3x - 6 = 9", 5.0);
+		test!(factory, "x - 2 = 4", 6.0);
+		test!(factory, "5x - 6 = 3x - 8", -1.0);
+		test!(factory, "2 * (x + 1) = 8", 3.0);
+		test!(factory, "x / 2 + x / 3 = 5", 6.0);
+
+		// Units are kept.
+		assert_eq!(display("5 km / x = 10 km/h"), "0.5 h");
+		assert_eq!(display("solve(5 km / x = 10 km/h, x) -> min"), "30 min");
+		assert_eq!(display("5x - 6 km = 9 km"), "3 km");
+		assert_eq!(display("solve(2 apples * x = 10 apples, x)"), "5");
+
+		assert!(factory.parse("x + 5 km = 3").is_err());
+		assert!(factory.parse("x + y = 2").is_err());
+		assert!(factory.parse("solve(x = 1, km)").is_err());
+
+		assert_eq!(factory.parse("x = x + 1").unwrap_err().to_string(), "Unable to solve: There is no solution");
+		assert_eq!(factory.parse("x = x").unwrap_err().to_string(), "Unable to solve: Every value is a solution");
+		assert_eq!(factory.parse("5 / x = 0").unwrap_err().to_string(), "Unable to solve: There is no solution");
+	}
+
+	#[test]
+	fn test_polynomial_equations() {
+		let factory  = Factory::new();
+
+		let display = |eval: &str| factory.parse(eval).unwrap().to_string();
+
+		test!(factory, "2^-1", 0.5);
+		test!(factory, "-2^2", -4.0);
+		test!(factory, "2^3^2", 512.0);
+		assert_eq!(display("3 m^2"), "3 m²");
+		assert_eq!(display("(3 m)^2"), "9 m²");
+
+		// The power is only on the unit before it.
+		assert_eq!(display("2 m/s^2"), "2 m/s²");
+		assert_eq!(display("1 kg·m/s^2"), "1 kg·m/s²");
+		assert_eq!(display("1 m^2/s"), "1 m²/s");
+		assert_eq!(display("g0 -> m/s^2"), "9.80665 m/s²");
+		assert_eq!(display("integrate(x * 2 m/s^2, x, 0 s, 3 s)"), "9 m");
+
+		// Products are expanded.
+		assert_eq!(display("(x - 3)(x - 2) = 2"), "[1, 4]");
+		assert_eq!(display("x^2 = 4"), "[-2, 2]");
+		assert_eq!(display("x² = 2"), "[-1.4142135623730950488, 1.4142135623730950488]");
+		assert_eq!(display("x^2 + 2x + 5 = 0"), "[-1 - 2i, -1 + 2i]");
+		assert_eq!(display("x^2 + 1e27 x + 1 = 0"), "[-1000000000000000000000000000, -0.000000000000000000000000001]");
+		assert!(factory.parse("x^3 + 1e20 x^2 + 1 = 0").is_err());
+
+		// Cubic
+		assert_eq!(display("x^3 - 6x^2 + 11x - 6 = 0"), "[1, 2, 3]");
+		assert_eq!(display("x^3 - x = 0"), "[-1, 0, 1]");
+		assert_eq!(display("(x - 1)^2 (x - 2) = 0"), "[1, 2]");
+		assert_eq!(display("x^3 = 8"), "[2, -1 - 1.7320508075688772935i, -1 + 1.7320508075688772935i]");
+
+		// Numerical
+		assert_eq!(display("(x - 1)(x - 2)(x - 3)(x - 4)(x - 5) = 0"), "[1, 2, 3, 4, 5]");
+		assert_eq!(display("(x - 1)^3 (x - 2)^2 = 0"), "[1, 2]");
+		assert_eq!(display("x^4 - 10x^2 + 9 = 0"), "[-3, -1, 1, 3]");
+		assert_eq!(display("x^4 = 1e20"), "[-100000, 100000, -100000i, 100000i]");
+
+		// Units
+		assert_eq!(display("x^2 = 4 m²"), "[-2 m, 2 m]");
+		assert_eq!(display("x^2 + 4 m² = 0"), "[-2i m, 2i m]");
+		assert_eq!(display("1 / x^2 = 4"), "[-0.5, 0.5]");
+		assert!(factory.parse("x^2 = 4 m").is_err());
 	}
-}
\ No newline at end of file
+
+	#[test]
+	fn test_linear_systems() {
+		let factory  = Factory::new();
+
+		let display = |eval: &str| factory.parse(eval).unwrap().to_string();
+
+		assert_eq!(display("2x + 3y = 7; x - y = 1"), "[2, 1]");
+		assert_eq!(display("p + q + r = 6; p - q = 0; q - r = 0"), "[2, 2, 2]");
+		assert_eq!(display("solve(x + y = 3; x - y = 1, y, x)"), "[1, 2]");
+		assert_eq!(display("0.1x + 0.2y = 0.3; 0.3x - 0.1y = 0.2"), "[1, 1]");
+		assert_eq!(display("2x = 4; 3x = 6"), "2");
+
+		// Units
+		assert_eq!(display("2x + 3y = 7 m; x - y = 1 m"), "[2 m, 1 m]");
+		assert_eq!(display("x + y = 10 km; x - y = 2000 m"), "[6 km, 4 km]");
+		assert!(factory.parse("x + y = 2 m; x - y = 1 s").is_err());
+
+		// Singular and underdetermined
+		assert!(factory.parse("x + y = 2; x + y = 3").is_err());
+		assert!(factory.parse("x + y = 2; 2x + 2y = 4").is_err());
+		assert!(factory.parse("x * y = 2; x + y = 3").is_err());
+	}
+
+	#[test]
+	fn test_calculus() {
+		let factory  = Factory::new();
+
+		let display = |eval: &str| factory.parse(eval).unwrap().to_string();
+
+		assert_eq!(display("2 PI"), "6.2831853071795864769252867666");
+
+		assert_eq!(display("root(x^2 - 2, x, 1)"), "1.4142135623730950488");
+		assert_eq!(display("root(cos(x) - x, x, 1)"), "0.73908513321516064166");
+		assert_eq!(display("root(x^2 - 4 m², x, 1 m)"), "2 m");
+		assert_eq!(display("root(x - 1 m, x, 0)"), "1 m");
+		assert_eq!(display("root(2 m * x - 4 m, x, 0)"), "2");
+		assert!(factory.parse("root(abs(x) + 1, x, 1)").is_err());
+
+		assert_eq!(display("deriv(x^3, x, 2)"), "12");
+		assert_eq!(display("deriv(sin(x), x, 0)"), "1");
+		assert_eq!(display("deriv(5 m/s² * t^2, t, 2 s)"), "20 m/s");
+
+		assert_eq!(display("integrate(x^2, x, 0, 3)"), "9");
+		assert_eq!(display("integrate(sin(x), x, 0, PI)"), "2");
+		assert_eq!(display("integrate(1 / x, x, 1, 2)"), "0.693147180559945");
+
+		// Integrating a velocity over time is a distance.
+		assert_eq!(display("integrate(9.8 m/s² * t, t, 0 s, 10 s)"), "490 m");
+		assert!(factory.parse("integrate(x, x, 0 s, 1 m)").is_err());
+		assert!(factory.parse("deriv(x^2, 2, 3)").is_err());
+
+		assert_eq!(display("integrate(sqrt(x), x, 0, 1)"), "0.666666666666667");
+		assert_eq!(display("integrate(sin(x) / x, x, 1, 100)"), "0.616142396521873");
+
+		// Singularities are errors instead of panics.
+		let error = |eval: &str| factory.parse(eval).unwrap_err().to_string();
+
+		assert_eq!(error("1 / 0"), "Division by zero");
+		assert!(error("integrate(1/x, x, -1, 1)").contains("singularity at 0"));
+		assert!(error("integrate(1/x, x, 1, 0)").contains("singularity at 0"));
+		assert_eq!(error("integrate(x, x, 0, 1e20)"), "Invalid Argument: the integral is too large to represent");
+		assert!(error("root(1/x, x, 1)").contains("singularity"));
+		assert!(error("integrate(1/(x - 0.3), x, -1, 1)").contains("didn't converge"));
+	}
+
+	#[test]
+	fn test_complex_numbers() {
+		let factory  = Factory::new();
+
+		let display = |eval: &str| factory.parse(eval).unwrap().to_string();
+
+		assert_eq!(display("3 + 4i"), "3 + 4i");
+		assert_eq!(display("2j"), "2i");
+		assert_eq!(display("i^2"), "-1");
+		assert_eq!(display("(3 + 4i)(1 - 2i)"), "11 - 2i");
+		assert_eq!(display("1 / (2 + 2i)"), "0.25 - 0.25i");
+		assert_eq!(display("(1 + i)^-1"), "0.5 - 0.5i");
+
+		assert_eq!(display("re(3 + 4i)"), "3");
+		assert_eq!(display("im(3 + 4i)"), "4");
+		assert_eq!(display("abs(3 + 4i)"), "5");
+		assert_eq!(display("conj(3 + 4i)"), "3 - 4i");
+		assert_eq!(display("arg(1 + i) -> deg"), "45 deg");
+
+		// Units
+		assert_eq!(display("3 Ω + 4i Ω"), "(3 + 4i) Ω");
+		assert_eq!(display("10 V / (3 Ω + 4i Ω)"), "(1.2 - 1.6i) V/Ω");
+		assert_eq!(display("3 km + 4i km -> m"), "(3000 + 4000i) m");
+		assert!(factory.parse("3 m + 4i s").is_err());
+
+		// Polar
+		assert_eq!(display("5∠30deg"), "5 ∠ 30°");
+		assert_eq!(display("2∠-90"), "2 ∠ -90°");
+		assert_eq!(display("2∠-90 -> rect"), "-2i");
+		assert_eq!(display("-2i -> polar"), "2 ∠ -90°");
+		assert_eq!(display("3 Ω + 4i Ω -> polar"), "5 Ω ∠ 53.130102354155978703°");
+		assert_eq!(display("2∠90 * 3∠90 -> rect"), "-6");
+
+		assert_eq!(display("(3 + 4i) == (3 + 4i)"), "1");
+		assert!(factory.parse("i > 1").is_err());
+		assert!(factory.parse("sin(i)").is_err());
+
+		assert_eq!(display("(3 + 4i) / (1e-15 + 1e-15i)"), "3500000000000000 + 500000000000000i");
+		assert_eq!(factory.parse("(3 + 4i) / 0").unwrap_err().to_string(), "Division by zero");
+		assert_eq!(factory.parse("10 V / (0 Ω + 0i Ω)").unwrap_err().to_string(), "Division by zero");
+	}
+
+	#[test]
+	fn test_fractions() {
+		let mut factory  = Factory::new();
+
+		let display = |factory: &Factory, eval: &str| factory.parse(eval).unwrap().to_string();
+
+		assert_eq!(display(&factory, "1/3 * 3"), "1");
+		assert_eq!(display(&factory, "1/3 + 1/6"), "0.5");
+		assert_eq!(display(&factory, "0.1 + 0.2"), "0.3");
+		assert_eq!(display(&factory, "1 ft / 3 -> in"), "4 in");
+
+		assert_eq!(display(&factory, "1/3 + 1/6 -> frac"), "1/2");
+		assert_eq!(display(&factory, "(2/3)^-2 -> frac"), "2 1/4");
+		assert_eq!(display(&factory, "-2.375 -> frac"), "-2 3/8");
+		assert_eq!(display(&factory, "-0.375 in -> frac"), "-3/8 in");
+		assert_eq!(display(&factory, "0.3125 in -> frac"), "5/16 in");
+		assert_eq!(display(&factory, "[0.5, 0.25] -> frac"), "[1/2, 1/4]");
+		assert_eq!(display(&factory, "(0.5 -> frac) + 1"), "1.5");
+
+		// Inexact values are rounded to the closest fraction.
+		assert_eq!(display(&factory, "PI -> frac"), "3 16/113");
+		assert_eq!(display(&factory, "90 deg -> rad -> frac"), "1 129/226 rad");
+
+		factory.set_max_denominator(16).unwrap();
+		assert_eq!(display(&factory, "0.3126 in -> frac"), "5/16 in");
+		assert_eq!(display(&factory, "PI -> frac"), "3 1/7");
+		assert!(factory.set_max_denominator(0).is_err());
+
+		factory.set_rational(true);
+		assert_eq!(display(&factory, "1/3 + 1/6"), "1/2");
+		assert_eq!(display(&factory, "1/3 * 3"), "1");
+		assert_eq!(display(&factory, "2 m / 3"), "2/3 m");
+		assert_eq!(display(&factory, "sqrt(2)"), "1.41421356237309504880169");
+	}
+
+	#[test]
+	fn test_rounding() {
+		let mut factory  = Factory::new();
+
+		let display = |factory: &Factory, eval: &str| factory.parse(eval).unwrap().to_string();
+
+		assert_eq!(display(&factory, "round(2.5)"), "2");
+		assert_eq!(display(&factory, "round(3.14159, 2)"), "3.14");
+		assert_eq!(display(&factory, "trunc(-1.8)"), "-1");
+		assert_eq!(display(&factory, "trunc(3.14159, 3)"), "3.141");
+		assert_eq!(display(&factory, "round_to(1.23, 0.05)"), "1.25");
+		assert_eq!(display(&factory, "round_to(1.234 m, 5 cm)"), "1.25 m");
+		assert_eq!(display(&factory, "sigfig(123456, 2)"), "120000");
+		assert_eq!(display(&factory, "sigfig(0.0012345, 3)"), "0.00123");
+		assert!(factory.parse("round_to(1 m, 1 s)").is_err());
+		assert!(factory.parse("round_to(1, 0)").is_err());
+		assert!(factory.parse("sigfig(1, 0)").is_err());
+
+		// Session settings
+		assert_eq!(display(&factory, "E"), "2.7182818284590452353602874714");
+
+		factory.set_precision(Some(4)).unwrap();
+		assert_eq!(display(&factory, "E"), "2.7183");
+		assert_eq!(display(&factory, "0.00005"), "0");
+		assert_eq!(display(&factory, "[1/3, 2/3] * 1 m"), "[0.3333 m, 0.6667 m]");
+
+		factory.set_rounding("zero").unwrap();
+		assert_eq!(display(&factory, "2/3"), "0.6666");
+
+		factory.set_notation("sigfig").unwrap();
+		factory.set_rounding("up").unwrap();
+		assert_eq!(display(&factory, "123456"), "123500");
+		assert_eq!(display(&factory, "0.000123456"), "0.0001235");
+
+		factory.set_notation("eng").unwrap();
+		assert_eq!(display(&factory, "12345 m"), "12.35e3 m");
+		assert_eq!(display(&factory, "0.00004567"), "45.67e-6");
+		assert_eq!(display(&factory, "42"), "42");
+
+		factory.set_precision(None).unwrap();
+		assert_eq!(display(&factory, "1500 km -> m"), "1.5e6 m");
+
+		assert!(factory.set_precision(Some(29)).is_err());
+		assert!(factory.set_rounding("sideways").is_err());
+		assert!(factory.set_notation("roman").is_err());
+	}
+
+	#[test]
+	fn test_uncertainty() {
+		let factory = Factory::new();
+
+		let display = |eval: &str| factory.parse(eval).unwrap().to_string();
+
+		assert_eq!(display("5.00 ± 0.02 m"), "5.00 ± 0.02 m");
+		assert_eq!(display("5.00 ± 0.02 m * 2.50 ± 0.01 m"), "12.50 ± 0.07 m²");
+		assert_eq!(display("5.00 +/- 0.02 m * 2.50 +/- 0.01 m"), "12.50 ± 0.07 m²");
+
+		// Absolute uncertainties add in quadrature.
+		assert_eq!(display("5.00 ± 0.03 m + 200 ± 4 cm"), "7.00 ± 0.05 m");
+		assert_eq!(display("5.00 ± 0.03 m - 200 ± 4 cm"), "3.00 ± 0.05 m");
+
+		// Relative uncertainties add in quadrature.
+		assert_eq!(display("10 ± 0.3 / 4 ± 0.2"), "2.50 ± 0.15");
+		assert_eq!(display("(2 ± 0.1)^3"), "8.0 ± 1.2");
+		assert_eq!(display("3 * (2 ± 0.1)"), "6.0 ± 0.3");
+
+		// f(x ± σ) = f(x) ± |f'(x)| σ
+		assert_eq!(display("sqrt(4 ± 0.1)"), "2.00 ± 0.03");
+		assert_eq!(display("ln(10 ± 1)"), "2.30 ± 0.10");
+		assert_eq!(display("sin(30 deg ± 1 deg)"), "0.50 ± 0.02");
+
+		assert_eq!(display("5 ± 2%"), "5.00 ± 0.10");
+		assert_eq!(display("1234 ± 230"), "1200 ± 200");
+		assert_eq!(display("5.00 ± 0.02 m -> cm"), "500 ± 2 cm");
+		assert_eq!(display("-(5 ± 0.2)"), "-5.0 ± 0.2");
+
+		assert!(factory.parse("5 m ± 1 s").is_err());
+		assert!(factory.parse("(3 + 4i) ± 1").is_err());
+	}
+
+	#[test]
+	fn test_intervals() {
+		let factory = Factory::new();
+
+		let display = |eval: &str| factory.parse(eval).unwrap().to_string();
+
+		assert_eq!(display("[10 kg .. 12 kg] * 3"), "[30 kg .. 36 kg]");
+		assert_eq!(display("[12 .. 10 kg]"), "[10 kg .. 12 kg]");
+		assert_eq!(display("[10 kg .. 12 kg] + [500 g..1 kg]"), "[10.5 kg .. 13 kg]");
+		assert_eq!(display("[1 m .. 2 m] - [50 cm .. 60 cm]"), "[0.4 m .. 1.5 m]");
+		assert_eq!(display("[-2 .. 3] * [4 .. 5]"), "[-10 .. 15]");
+		assert_eq!(display("1 / [2 .. 4]"), "[0.25 .. 0.5]");
+		assert_eq!(display("[-2 .. 3]^2"), "[0 .. 9]");
+		assert_eq!(display("-[1 .. 2]"), "[-2 .. -1]");
+		assert_eq!(display("[10 kg .. 12 kg] -> g"), "[10000 g .. 12000 g]");
+		assert_eq!(display("[[1 .. 2], 3]"), "[[1 .. 2], 3]");
+		assert!(factory.parse("[2 .. 3] / [-1 .. 1]").is_err());
+		assert!(factory.parse("1 / [0 .. 0]").is_err());
+		assert!(factory.parse("[1 .. 2] / 0").is_err());
+		assert!(factory.parse("[1 m .. 2 s]").is_err());
+
+		assert_eq!(display("lo([10 kg .. 12 kg])"), "10 kg");
+		assert_eq!(display("hi([10 kg .. 12 kg])"), "12 kg");
+		assert_eq!(display("mid([10 kg .. 12 kg])"), "11 kg");
+		assert_eq!(display("width([10 kg .. 12 kg])"), "2 kg");
+		assert_eq!(display("abs([-3 .. 2])"), "[0 .. 3]");
+
+		// Certainly and possibly true.
+		assert_eq!(display("[1 .. 3] > 2"), "[0 .. 1]");
+		assert_eq!(display("[1 .. 3] > 0"), "1");
+		assert_eq!(display("[1 .. 3] < 0"), "0");
+		assert_eq!(display("certainly([1 .. 3] > 2)"), "0");
+		assert_eq!(display("possibly([1 .. 3] > 2)"), "1");
+		assert_eq!(display("[1 m .. 2 m] == [150 cm .. 3 m]"), "[0 .. 1]");
+	}
+
+	#[test]
+	fn test_dates() {
+		let mut factory = Factory::new();
+
+		factory.set_clock(Box::new(FixedClock(DateTime::parse("2026-10-18T09:30:15").unwrap())));
+
+		let display = |factory: &Factory, eval: &str| factory.parse(eval).unwrap().to_string();
+
+		assert_eq!(display(&factory, "2026-10-18"), "2026-10-18");
+		assert_eq!(display(&factory, "2026-10-18T09:30"), "2026-10-18T09:30");
+		assert_eq!(display(&factory, "2026-10-18 + 3 weeks"), "2026-11-08");
+		assert_eq!(display(&factory, "2026-10-18 + 6 h"), "2026-10-18T06:00");
+		assert_eq!(display(&factory, "2026-10-18T23:30 + 45 min"), "2026-10-19T00:15");
+		assert_eq!(display(&factory, "2026-03-01 - 1 d"), "2026-02-28");
+		assert_eq!(display(&factory, "2027-01-01 - 2026-10-18 -> days"), "75 d");
+		assert_eq!(display(&factory, "2026-10-18T09:30 - 2026-10-18 -> h"), "9.5 h");
+		assert_eq!(display(&factory, "2026-10-18 < 2027-01-01"), "1");
+		assert_eq!(display(&factory, "1969-12-31 + 1 d"), "1970-01-01");
+
+		assert_eq!(display(&factory, "weekday(2026-10-18)"), "Sunday");
+		assert_eq!(display(&factory, "weekday(2000-01-01)"), "Saturday");
+
+		// Calendar months are not the fixed length month unit.
+		assert_eq!(display(&factory, "add_months(2026-01-31, 1)"), "2026-02-28");
+		assert_eq!(display(&factory, "add_months(2026-10-18, -10)"), "2025-12-18");
+		assert_eq!(display(&factory, "add_years(2028-02-29, 1)"), "2029-02-28");
+		assert_eq!(display(&factory, "2026-01-31 + 1 month"), "2026-03-02T10:30");
+
+		assert_eq!(display(&factory, "now"), "2026-10-18T09:30:15");
+		assert_eq!(display(&factory, "today"), "2026-10-18");
+		assert_eq!(display(&factory, "today + 1 d - now -> min"), "869.75 min");
+
+		assert!(factory.parse("2026-02-30").is_err());
+		assert!(factory.parse("2026-10-18 + 5 m").is_err());
+		assert!(factory.parse("2026-10-18 + 2026-10-18").is_err());
+		assert!(factory.parse("weekday(5)").is_err());
+
+		// Dates are kept within the years -999999 to 999999.
+		assert_eq!(factory.parse("2026-10-18 - 1e20 s").unwrap_err().to_string(), "Invalid Argument: The result is too far from 2026-10-18");
+		assert!(factory.parse("2026-10-18 + 1e27 s").is_err());
+		assert!(factory.parse("add_years(2026-10-18, 1000000)").is_err());
+	}
+
+	#[test]
+	fn test_clock_times() {
+		let mut factory = Factory::new();
+
+		let display = |factory: &Factory, eval: &str| factory.parse(eval).unwrap().to_string();
+
+		assert_eq!(display(&factory, "9:30am + 4 h 45 min"), "2:15pm");
+		assert_eq!(display(&factory, "9:30 AM"), "9:30am");
+		assert_eq!(display(&factory, "12:15am"), "12:15am");
+		assert_eq!(display(&factory, "12:15pm - 3 h"), "9:15am");
+		assert_eq!(display(&factory, "17:45 - 08:10"), "9 h 35 min");
+		assert_eq!(display(&factory, "(17:45 - 08:10) -> min"), "575 min");
+		assert_eq!(display(&factory, "23:30 + 45 min"), "00:15");
+		assert_eq!(display(&factory, "06:00 - 22:00"), "8 h");
+		assert_eq!(display(&factory, "9:30am -> min"), "570 min");
+		assert_eq!(display(&factory, "17:45 > 08:10"), "1");
+		assert_eq!(display(&factory, "2026-10-18 + 9:30"), "2026-10-18T09:30");
+
+		// h:mm:ss durations
+		assert_eq!(display(&factory, "1:30:00"), "1 h 30 min");
+		assert_eq!(display(&factory, "1:30:00 -> min"), "90 min");
+		assert_eq!(display(&factory, "1:30:00 + 1:30:00"), "3 h");
+		assert_eq!(display(&factory, "1:30:00 - 2:15:30"), "-45 min 30 s");
+		assert_eq!(display(&factory, "(17:45 - 08:10) + 0:25:00"), "10 h");
+		assert_eq!(display(&factory, "10:00 - 1:30:15"), "08:29:45");
+
+		factory.set_clock_format("12").unwrap();
+		assert_eq!(display(&factory, "17:45"), "5:45pm");
+
+		factory.set_clock_format("24").unwrap();
+		assert_eq!(display(&factory, "9:30pm"), "21:30");
+
+		assert!(factory.set_clock_format("36").is_err());
+		assert!(factory.parse("24:00").is_err());
+		assert!(factory.parse("13:00pm").is_err());
+		assert!(factory.parse("9:75").is_err());
+		assert!(factory.parse("9:30am + 5 kg").is_err());
+	}
+
+	#[test]
+	fn test_epochs() {
+		let factory = Factory::new();
+
+		let display = |eval: &str| factory.parse(eval).unwrap().to_string();
+
+		assert_eq!(display("1700000000 -> datetime"), "2023-11-14T22:13:20");
+		assert_eq!(display("1700000000000 ms -> datetime"), "2023-11-14T22:13:20");
+		assert_eq!(display("1700000000 -> date"), "2023-11-14");
+		assert_eq!(display("2026-10-18T00:00Z -> unix"), "1792281600");
+		assert_eq!(display("2026-10-18T02:00+02:00 -> unix"), "1792281600");
+		assert_eq!(display("2026-10-18 -> unix_ns"), "1792281600000000000");
+		assert_eq!(display("unix(1792281600)"), "2026-10-18T00:00");
+		assert_eq!(display("unix_ms(1700000000000)"), "2023-11-14T22:13:20");
+		assert_eq!(display("unix_ns(1700000000000000000) -> unix_ms"), "1700000000000");
+
+		// GPS counts the 18 leap seconds since 1980.
+		assert_eq!(display("1980-01-06 -> gps"), "0");
+		assert_eq!(display("2017-01-01T00:00Z -> gps"), "1167264018");
+		assert_eq!(display("gps(1167264018)"), "2017-01-01T00:00");
+		assert_eq!(display("1900-01-01 -> ntp"), "0");
+		assert_eq!(display("ntp(3970000000)"), "2025-10-21T01:46:40");
+
+		assert!(factory.parse("5 m -> unix").is_err());
+		assert_eq!(factory.parse("1e20 -> datetime").unwrap_err().to_string(), "Invalid Argument: 100000000000000000000 is too far from the epoch");
+		assert!(factory.parse("1e27 -> datetime").is_err());
+		assert!(factory.parse("-1e27 -> datetime").is_err());
+		assert!(factory.parse("unix_ns(-1e28)").is_err());
+		assert!(factory.parse("unix(5 m)").is_err());
+		assert!(factory.parse("2026-10-18T09:30+25:00").is_err());
+	}
+
+	#[test]
+	fn test_humanize() {
+		let mut factory = Factory::new();
+
+		let display = |factory: &Factory, eval: &str| factory.parse(eval).unwrap().to_string();
+
+		assert_eq!(display(&factory, "93784 s -> human"), "1 day 2 hours 3 minutes 4 seconds");
+		assert_eq!(display(&factory, "93784 s -> dhms"), "1d 2h 3m 4s");
+		assert_eq!(display(&factory, "93784 s -> human(2)"), "1 day 2 hours");
+		assert_eq!(display(&factory, "1 h 59 min 59.6 s -> human(2)"), "2 hours");
+		assert_eq!(display(&factory, "1000000 s -> human"), "1 week 4 days 13 hours 46 minutes 40 seconds");
+		assert_eq!(display(&factory, "-90 min -> human"), "-1 hour 30 minutes");
+		assert_eq!(display(&factory, "1.5 s -> human"), "1.5 seconds");
+		assert_eq!(display(&factory, "0 s -> dhms"), "0s");
+		assert_eq!(display(&factory, "2027-01-01 - 2026-10-18 -> human"), "10 weeks 5 days");
+
+		factory.set_duration_months("30d").unwrap();
+		assert_eq!(display(&factory, "75 d -> human"), "2 months 15 days");
+		assert_eq!(display(&factory, "400 d -> dhms"), "1y 1mo 5d");
+
+		factory.set_duration_months("average").unwrap();
+		assert_eq!(display(&factory, "2 months 3 h -> human"), "2 months 3 hours");
+
+		factory.set_duration_months("calendar").unwrap();
+		assert_eq!(display(&factory, "2027-01-01 - 2026-10-18 -> human"), "2 months 14 days");
+		assert_eq!(display(&factory, "2026-10-18 - 2027-01-01 -> dhms"), "-2mo 14d");
+		assert_eq!(display(&factory, "2028-03-01T12:00 - 2026-10-18 -> human(2)"), "1 year 4 months");
+		assert_eq!(display(&factory, "2026-11-17T23:59:59 - 2026-10-18 -> human(3)"), "1 month");
+		assert_eq!(display(&factory, "2027-01-01 - 2026-10-18 -> days"), "75 d");
+		// Without a start date months are the average.
+		assert_eq!(display(&factory, "2 months 3 h -> human"), "2 months 3 hours");
+
+		assert!(factory.parse("5 m -> human").is_err());
+		assert!(factory.parse("93784 s -> dhms(0)").is_err());
+		assert!(factory.set_duration_months("lunar").is_err());
+		assert!(factory.parse("1e30 s -> human").is_err());
+
+		// Tokens fall back to the plain quantity when it isn't a duration.
+		let length = Value::Formatted(Quantity::new_unit(dec!(5), factory.find_unit("m").map(Units::new)), NumberFormat::Humanized(Default::default()));
+		assert_eq!(length.into_tokens(), Value::Quantity(Quantity::new_unit(dec!(5), factory.find_unit("m").map(Units::new))).into_tokens());
+	}
+
+	#[test]
+	fn test_time_zones() {
+		let mut factory = Factory::new();
+
+		factory.set_clock(Box::new(FixedClock(DateTime::parse("2026-10-18T09:30:15").unwrap())));
+
+		let display = |factory: &Factory, eval: &str| factory.parse(eval).unwrap().to_string();
+
+		assert_eq!(display(&factory, "2026-10-18 09:00 America/New_York -> Europe/Berlin"), "2026-10-18T15:00+02:00 Europe/Berlin");
+		assert_eq!(display(&factory, "now in Tokyo"), "2026-10-18T18:30:15+09:00 Asia/Tokyo");
+		assert_eq!(display(&factory, "2026-10-18T09:30Z -> UTC+5:30"), "2026-10-18T15:00+05:30");
+		assert_eq!(display(&factory, "2026-10-18T09:30 UTC-8 -> utc"), "2026-10-18T17:30Z");
+		assert_eq!(display(&factory, "2026-10-18T09:30+02:00"), "2026-10-18T09:30+02:00");
+		assert_eq!(display(&factory, "1792281600 -> Asia/Kolkata"), "2026-10-18T05:30+05:30 Asia/Kolkata");
+		assert_eq!(display(&factory, "2026-10-18 09:00 Tokyo - 2026-10-18 09:00 London -> h"), "-8 h");
+
+		// Skipped times move forward. Repeated times are the first one.
+		assert_eq!(display(&factory, "2026-03-08 02:30 America/New_York"), "2026-03-08T03:30-04:00 America/New_York");
+		assert_eq!(display(&factory, "2026-11-01 01:30 America/New_York"), "2026-11-01T01:30-04:00 America/New_York");
+		assert_eq!(display(&factory, "2026-11-01 01:30 America/New_York + 1 h"), "2026-11-01T01:30-05:00 America/New_York");
+		assert_eq!(display(&factory, "2026-03-28 12:00 Europe/Berlin + 1 d"), "2026-03-29T13:00+02:00 Europe/Berlin");
+		assert_eq!(display(&factory, "add_months(2026-02-15 12:00 Europe/Berlin, 1)"), "2026-03-15T12:00+01:00 Europe/Berlin");
+
+		assert_eq!(display(&factory, "weekday(2026-10-18 23:00 Pacific/Auckland)"), "Sunday");
+		assert_eq!(display(&factory, "2026-10-18 23:00 Pacific/Auckland -> date"), "2026-10-18");
+		assert_eq!(display(&factory, "5 in -> cm"), "12.7 cm");
+
+		let error = factory.parse("2026-10-18 09:00 UTC -> Foo/Bar").unwrap_err();
+		assert_eq!(error.to_string(), r#"Invalid Argument: Unknown time zone "Foo/Bar""#);
+		assert!(factory.parse("2026-10-18 -> Mars/Olympus").is_err());
+
+		// Outside the years dates are kept within rather than UTC.
+		let error = factory.parse("9999999999999999999999 -> Tokyo").unwrap_err();
+		assert_eq!(error.to_string(), "Invalid Argument: 9999999999999999999999 is too far from the epoch");
+	}
+
+	#[test]
+	fn test_currency() {
+		let mut factory = Factory::new();
+
+		let display = |factory: &Factory, eval: &str| factory.parse(eval).unwrap().to_string();
+
+		assert_eq!(display(&factory, "$1049"), "1049 USD");
+		assert_eq!(display(&factory, "$1,049.99 * 2"), "2099.98 USD");
+		assert_eq!(display(&factory, "€5 + 2.50 EUR"), "7.5 EUR");
+		assert_eq!(display(&factory, "C$20"), "20 CAD");
+
+		// Rounded to the minor unit.
+		assert_eq!(display(&factory, "$10 / 3"), "3.33 USD");
+		assert_eq!(display(&factory, "¥1000 / 3"), "333 JPY");
+		assert_eq!(display(&factory, "1 KWD / 3"), "0.333 KWD");
+		assert_eq!(display(&factory, "12 USD/h * 2.5 h"), "30 USD");
+
+		assert!(matches!(factory.parse("$5 -> EUR"), Err(Error::NoExchangeRate(..))));
+		assert!(factory.parse("$5 + €5").is_err());
+
+		factory.load_exchange_rates("base,USD\nas_of,2026-10-18\nEUR,0.92\nGBP,0.79\nJPY,150.5").unwrap();
+
+		assert_eq!(display(&factory, "$10 -> EUR"), "9.2 EUR");
+		assert_eq!(display(&factory, "€10 -> USD"), "10.87 USD");
+		assert_eq!(display(&factory, "£1 -> JPY"), "191 JPY");
+		assert_eq!(display(&factory, "[$1, $2] -> EUR"), "[0.92 EUR, 1.84 EUR]");
+		assert!(matches!(factory.parse("$5 -> CHF"), Err(Error::NoExchangeRate(..))));
+
+		let rates = factory.load_exchange_rates(r#"{ "base": "EUR", "date": "2026-10-18", "rates": { "USD": 1.0870, "CHF": "0.94" } }"#).unwrap();
+		assert_eq!(rates.len(), 2);
+		assert_eq!(rates.as_of().unwrap().to_string(), "2026-10-18");
+
+		assert_eq!(display(&factory, "9.40 CHF -> EUR"), "10 EUR");
+		assert_eq!(display(&factory, "$10.87 -> CHF"), "9.4 CHF");
+
+		assert!(factory.load_exchange_rates("base,USD\nXYZ,2").is_err());
+		assert!(factory.load_exchange_rates("EUR,0.92").is_err());
+		assert!(factory.load_exchange_rates("base,USD\nEUR,-1").is_err());
+	}
+
+	#[test]
+	fn test_session() {
+		let mut factory = Factory::new();
+
+		let constants = [
+			("v", "30 km/h"),
+			("third", "1 m / 3"),
+			("z", "3 + 4i"),
+			("polar", "5 ∠ 30°"),
+			("mass", "5.00 ± 0.02 kg"),
+			("bounds", "[10 kg .. 12 kg]"),
+			("sizes", "[1 in / 8, 2 m]"),
+			("launch", "2026-10-18T09:30 Europe/Berlin"),
+			("alarm", "6:45am"),
+			("rest", "weekday(2026-10-18)"),
+			("shift", "17:45 - 08:10"),
+			("flight", "-1:30:00 - 0:00:05.5"),
+			("drill", "0.3125 in -> frac"),
+			("uptime", "93784 s -> dhms(2)"),
+			("lifetime", "1000000 s -> human"),
+			("trip", "2027-01-01 - 2026-10-18")
+		];
+
+		for (name, eval) in constants.iter() {
+			let value = factory.parse(eval).unwrap().into_value().unwrap();
+			factory.add_constant(name.to_string(), value).unwrap();
+		}
+
+		factory.set_angle_unit("deg").unwrap();
+		factory.set_precision(Some(4)).unwrap();
+		factory.set_notation("sigfig").unwrap();
+		factory.set_clock_format("12").unwrap();
+		factory.set_duration_months("average").unwrap();
+		factory.load_exchange_rates("base,USD\nas_of,2026-10-18\nEUR,0.92\nGBP,0.79").unwrap();
+		factory.set_max_denominator(8).unwrap();
+
+		let script = factory.save_session();
+
+		assert!(script.contains("angle degree\n"));
+		assert!(script.contains("polar = 4.3301270189"));
+		assert!(script.contains("third = 1 m / 3\n"));
+		assert!(script.contains("rest = weekday(1970-01-11)\n"));
+		assert!(script.contains("flight = -1:30:05.5\n"));
+		assert!(script.contains("trip = 2027-01-01 - 2026-10-18\n"));
+		// Made with other settings.
+		assert!(script.contains("frac 16\ndrill = 5 in / 16 -> frac\nfrac 8\n"));
+		assert!(script.contains("months off\nuptime = 93784 s -> dhms(2)\nmonths average\n"));
+		assert!(script.contains("rate EUR,0.92\n"));
+		// Only constants added after the defaults are saved.
+		assert!(!script.contains("PI ="));
+
+		let mut loaded = Factory::new();
+		loaded.load_session(&script).unwrap();
+
+		assert_eq!(loaded.save_session(), script);
+		assert_eq!(loaded.settings().angle_unit.long(), "degree");
+		assert_eq!(loaded.settings().precision, Some(4));
+
+		for (name, _) in constants.iter() {
+			assert_eq!(loaded.find_const(name).map(|v| v.to_string()), factory.find_const(name).map(|v| v.to_string()), "{}", name);
+		}
+
+		assert_eq!(loaded.parse("third * 3").unwrap().to_string(), "1 m");
+		assert_eq!(loaded.parse("100 USD -> GBP").unwrap().to_string(), "79 GBP");
+		assert_eq!(loaded.exchange_rates().as_of(), factory.exchange_rates().as_of());
+
+		let error = loaded.load_session("# Settings\nprecision 2\n\nrounding sideways").unwrap_err();
+		// Lines before the error are kept.
+		assert!(error.to_string().contains("Line 4"));
+		assert_eq!(loaded.settings().precision, Some(2));
+
+		assert!(loaded.load_session("m = 5").is_err());
+		assert!(loaded.load_session("volume 11").is_err());
+
+		// Settings on their own, as typed into a console.
+		assert!(loaded.apply_setting("precision 3").unwrap().is_ok());
+		assert_eq!(loaded.settings().precision, Some(3));
+		assert!(loaded.apply_setting("rounding sideways").unwrap().is_err());
+		assert!(loaded.apply_setting("volume 11").is_none());
+		assert!(loaded.apply_setting("2 + 2").is_none());
+	}
+
+	#[test]
+	fn test_define() {
+		let mut factory = Factory::new();
+
+		let display = |factory: &Factory, eval: &str| factory.parse(eval).unwrap().to_string();
+
+		factory.define_unit("furlong = 201.168 m").unwrap();
+		factory.define_unit("sprint = 2 weeks").unwrap();
+		factory.define_unit("story_point, story_points (sp, pts) = 6 h").unwrap();
+
+		assert_eq!(display(&factory, "1320 ft -> furlong"), "2 furlong");
+		assert_eq!(display(&factory, "1 furlong + 1 m -> m"), "202.168 m");
+		assert_eq!(display(&factory, "3 sprints -> days"), "42 d");
+		assert_eq!(display(&factory, "1 sprint -> sp"), "56 sp");
+		assert_eq!(display(&factory, "8 pts -> story_points"), "8 sp");
+
+		// Made from several units.
+		factory.define_unit("knot (kn) = 1.852 km/h").unwrap();
+		assert_eq!(display(&factory, "10 kn -> km/h"), "18.52 km/h");
+		assert_eq!(display(&factory, "2 kn * 3 h -> km"), "11.112 km");
+		assert_eq!(display(&factory, "1 kn / (1 km/h)"), "1.852");
+		assert!(factory.parse("1 kn -> km").is_err());
+
+		// Definitions also come through the parser.
+		let Ok(ParseValue::Definition(definition)) = factory.parse("define furlong = 201.168 m") else {
+			panic!("define wasn't parsed as a definition");
+		};
+		factory.add_definition(definition).unwrap();
+		assert_eq!(display(&factory, "1 furlong -> m"), "201.168 m");
+
+		let Ok(ParseValue::Definition(definition)) = factory.parse("prefix kibi- = 1024") else {
+			panic!("prefix wasn't parsed as a definition");
+		};
+		factory.add_definition(definition).unwrap();
+		assert_eq!(display(&factory, "1 kibimeter -> m"), "1024 m");
+
+		// Its own dimension.
+		factory.define_unit("widget").unwrap();
+		assert_eq!(display(&factory, "3 widgets * 2"), "6 widget");
+		assert!(factory.parse("3 widgets -> m").is_err());
+
+		// Names which aren't units don't become dimensions of their own.
+		assert_eq!(factory.define_unit("foo = 3 bar").unwrap_err().to_string(), r#"Unknown unit "bar""#);
+		assert!(factory.find_unit("foo").is_none());
+
+		// Replacing a unit added before.
+		factory.define_unit("sprint = 3 weeks").unwrap();
+		assert_eq!(display(&factory, "1 sprint -> days"), "21 d");
+
+		// Units and constants made from a unit keep it, so it can't be replaced.
+		factory.define_unit("release = 3 sprint").unwrap();
+		assert!(factory.define_unit("sprint = 2 weeks").is_err());
+		factory.define_unit("release = 4 sprint").unwrap();
+		factory.add_constant("launch".to_string(), factory.parse_value("[1 release, 2 kg]").unwrap()).unwrap();
+		assert!(factory.define_unit("release = 2 sprint").is_err());
+		assert_eq!(display(&factory, "1 release -> weeks"), "12 w");
+
+		assert!(factory.define_unit("m = 2 ft").is_err());
+		assert!(factory.define_unit("PI = 3 m").is_err());
+		assert!(factory.define_unit("sqrt = 3 m").is_err());
+		assert!(factory.define_unit("dozen = 12").is_err());
+		assert!(factory.define_unit("2fast = 3 m").is_err());
+
+		assert_eq!(strip_define("define furlong = 201.168 m"), Some("furlong = 201.168 m"));
+		assert_eq!(strip_define("defined = 2"), None);
+
+		factory.set_precision(Some(1)).unwrap();
+		factory.define_unit("chain = 20.1168 m").unwrap();
+		assert_eq!(unit_definition(&factory.get_user_units()[3]), "knot (kn) = 1.852 km/h");
+		assert_eq!(unit_definition(&factory.get_user_units()[6]), "chain = 20.1168 m");
+
+		let mut loaded = Factory::new();
+		loaded.load_session(&factory.save_session()).unwrap();
+
+		assert_eq!(display(&loaded, "1 sprint -> days"), "21 d");
+		assert_eq!(display(&loaded, "10 chain -> furlong"), "1 furlong");
+		assert_eq!(loaded.save_session(), factory.save_session());
+	}
+
+	#[test]
+	fn test_unit_definitions() {
+		let mut factory = Factory::new();
+
+		let display = |factory: &Factory, eval: &str| factory.parse(eval).unwrap().to_string();
+
+		let contents = "# Astronomy\nparsec (pc) = 3.0857e16 m\nlight_year (ly) = 9460730472580800 m\n\nprefix kibi- (Ki-) = 1024\ndefine league = 3 mile\n";
+
+		assert_eq!(factory.load_unit_definitions("units.txt", contents).unwrap(), 4);
+
+		assert_eq!(display(&factory, "1 pc -> ly"), "3.2615874735497559363682823404 ly");
+		assert_eq!(display(&factory, "2 kibibytes -> B"), "2048 B");
+		assert_eq!(display(&factory, "1 MB -> KiB"), "1000 KiB");
+		assert_eq!(display(&factory, "1 kibiparsec -> pc"), "1024 pc");
+		assert_eq!(display(&factory, "1 league -> mile"), "3 mile");
+
+		// Short prefixes only go before short names.
+		assert!(factory.find_unit("kibiB").is_none());
+		assert!(factory.find_unit("Kibyte").is_none());
+
+		let error = factory.load_unit_definitions("units.txt", "furlong = 201.168 m\n\ndozen = 12").unwrap_err();
+		assert!(error.to_string().contains("units.txt:3:"));
+		assert!(factory.find_unit("furlong").is_some());
+
+		let error = factory.load_unit_definitions("units.txt", "foo = 3 bar").unwrap_err();
+		assert!(error.to_string().ends_with(r#"units.txt:1: Unknown unit "bar""#));
+		assert!(factory.find_unit("foo").is_none());
+
+		assert!(factory.load_unit_definitions("units.txt", "prefix kibi = 1024").is_err());
+		assert!(factory.load_unit_definitions("units.txt", "prefix mega- = 1000 m").is_err());
+
+		let mut loaded = Factory::new();
+		loaded.load_session(&factory.save_session()).unwrap();
+
+		assert_eq!(display(&loaded, "2 KiB -> B"), "2048 B");
+		assert_eq!(loaded.save_session(), factory.save_session());
+	}
+}
@@ -1,14 +1,34 @@
 use std::fmt;
+use std::rc::Rc;
+use std::cell::RefCell;
 
-use conversion::{Quantity, FunctionEval};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
 
-use crate::{Result, Error, Value, Operator};
+use conversion::{Quantity, Units, Complex, DurationFormat, FunctionEval, Dimension, Lambda, Argument, FunctionResult};
+use conversion::{Radian, is_convertable};
+use conversion::quantity::transcendental::PI;
+use conversion::Error as ConversionError;
+
+use crate::{Result, Error, Value, ComplexForm, Operator, ExchangeRates};
+use crate::dates::{DateFunction, DateTarget};
+use crate::equations::{self, Polynomial};
 
 
 pub type ExpressionArg = Box<dyn Expression>;
 
 pub trait Expression: fmt::Debug {
 	fn eval(&self) -> Result<Value>;
+
+	/// The expression as a Polynomial of `variables`. Used for solving equations.
+	fn polynomial(&self, variables: &[String]) -> Result<Polynomial> {
+		Polynomial::from_value(self.eval()?, variables)
+	}
+
+	/// The argument given to functions which evaluate their own arguments. integrate(x^2, x, 0, 1)
+	fn as_argument(&self) -> Option<Argument<'_>> {
+		None
+	}
 }
 
 
@@ -29,6 +49,10 @@ impl Expression for Add {
 
 		Ok(Value::try_add(left, right)?)
 	}
+
+	fn polynomial(&self, variables: &[String]) -> Result<Polynomial> {
+		self.0.polynomial(variables)?.try_add(self.1.polynomial(variables)?)
+	}
 }
 
 
@@ -49,6 +73,10 @@ impl Expression for Subtract {
 
 		Ok(Value::try_subtract(left, right)?)
 	}
+
+	fn polynomial(&self, variables: &[String]) -> Result<Polynomial> {
+		self.0.polynomial(variables)?.try_subtract(self.1.polynomial(variables)?)
+	}
 }
 
 
@@ -69,6 +97,10 @@ impl Expression for Multiply {
 
 		Ok(Value::try_multiply(left, right)?)
 	}
+
+	fn polynomial(&self, variables: &[String]) -> Result<Polynomial> {
+		self.0.polynomial(variables)?.try_multiply(self.1.polynomial(variables)?)
+	}
 }
 
 
@@ -89,43 +121,262 @@ impl Expression for Divide {
 
 		Ok(Value::try_divide(left, right)?)
 	}
+
+	fn polynomial(&self, variables: &[String]) -> Result<Polynomial> {
+		self.0.polynomial(variables)?.try_divide(self.1.polynomial(variables)?)
+	}
 }
 
 
 
-// #[derive(Debug)]
-// pub struct Exponentiate(ExpressionArg, ExpressionArg);
+#[derive(Debug)]
+pub struct Exponentiate(ExpressionArg, ExpressionArg);
 
-// impl Exponentiate {
-// 	pub fn new(left: ExpressionArg, right: ExpressionArg) -> Self {
-// 		Exponentiate(left, right)
-// 	}
-// }
+impl Exponentiate {
+	pub fn new(left: ExpressionArg, right: ExpressionArg) -> Self {
+		Exponentiate(left, right)
+	}
+}
+
+impl Expression for Exponentiate {
+	fn eval(&self) -> Result<Value> {
+		let left = self.0.eval()?;
+		let right = self.1.eval()?;
+
+		Value::try_exponentiate(left, right)
+	}
+
+	/// Only whole number exponents. (x - 3)^2
+	fn polynomial(&self, variables: &[String]) -> Result<Polynomial> {
+		let base = self.0.polynomial(variables)?;
 
-// impl Expression for Exponentiate {
-// 	fn eval(&self) -> Result<Value> {
-// 		let left = self.0.eval()?;
-// 		let right = self.1.eval()?;
+		if base.variables().is_empty() {
+			return Polynomial::from_value(self.eval()?, variables);
+		}
 
-// 		Ok(Value::try_exponentiate(left, right)?)
-// 	}
-// }
+		let exp = self.1.eval()?
+			.into_quantity()
+			.filter(|q| q.unit().is_err() && q.amount().fract().is_zero())
+			.and_then(|q| q.amount().to_i32())
+			.ok_or_else(|| Error::UnableToSolve("Unknowns can only be raised to whole numbers".to_string()))?;
 
+		let mut value = Polynomial::constant(Quantity::new(Decimal::ONE));
 
+		for _ in 0..exp.abs() {
+			value = if exp < 0 { value.try_divide(base.clone())? } else { value.try_multiply(base.clone())? };
+		}
 
+		Ok(value)
+	}
+}
+
+
+
+/// Magnitude and angle. Angles without units are degrees. 5∠30
 #[derive(Debug)]
-pub struct Conversion(ExpressionArg, ExpressionArg);
+pub struct Polar(ExpressionArg, ExpressionArg);
+
+impl Polar {
+	pub fn new(magnitude: ExpressionArg, angle: ExpressionArg) -> Self {
+		Polar(magnitude, angle)
+	}
+}
+
+impl Expression for Polar {
+	fn eval(&self) -> Result<Value> {
+		let magnitude = self.0.eval()?.into_quantity().ok_or(Error::ExpectedQuantity)?;
+		let angle = self.1.eval()?.into_quantity().ok_or(Error::ExpectedQuantity)?;
+
+		let radians = match angle.unit() {
+			Ok(unit) if is_convertable(unit, &Units::new(Box::new(Radian))) => angle.total_amount(),
+			Ok(unit) => return Err(Error::UnableToConvertValues(unit.long(), "radians".to_string())),
+			Err(_) => angle.amount() * PI / Decimal::from(180)
+		};
+
+		let value = Complex::from_polar(magnitude.amount(), radians)
+			.ok_or_else(|| Error::Conversion(ConversionError::InvalidArgument("The polar value is too large".to_string())))?;
+
+		Ok(Value::new_complex(value, magnitude.into_unit(), ComplexForm::Polar))
+	}
+}
+
+
+
+/// A value and its standard uncertainty. 5.00 ± 0.02 m
+#[derive(Debug)]
+pub struct Uncertainty(ExpressionArg, ExpressionArg);
+
+impl Uncertainty {
+	pub fn new(value: ExpressionArg, uncertainty: ExpressionArg) -> Self {
+		Uncertainty(value, uncertainty)
+	}
+}
+
+impl Expression for Uncertainty {
+	fn eval(&self) -> Result<Value> {
+		let value = self.0.eval()?.into_quantity().ok_or(Error::ExpectedQuantity)?;
+		let uncertainty = self.1.eval()?.into_quantity().ok_or(Error::ExpectedQuantity)?;
+
+		Value::new_uncertain(value, uncertainty)
+	}
+}
+
+
+
+/// Lower and upper bounds. [10 kg .. 12 kg]
+#[derive(Debug)]
+pub struct Interval(ExpressionArg, ExpressionArg);
+
+impl Interval {
+	pub fn new(lo: ExpressionArg, hi: ExpressionArg) -> Self {
+		Interval(lo, hi)
+	}
+}
+
+impl Expression for Interval {
+	fn eval(&self) -> Result<Value> {
+		let lo = self.0.eval()?.into_quantity().ok_or(Error::ExpectedQuantity)?;
+		let hi = self.1.eval()?.into_quantity().ok_or(Error::ExpectedQuantity)?;
+
+		Value::new_interval(lo, hi)
+	}
+}
+
+
+
+/// weekday(2026-10-18), add_months(2026-01-31, 1)
+#[derive(Debug)]
+pub struct DateCall(DateFunction, Vec<ExpressionArg>);
+
+impl DateCall {
+	pub fn new(func: DateFunction, args: Vec<ExpressionArg>) -> Self {
+		DateCall(func, args)
+	}
+}
+
+impl Expression for DateCall {
+	fn eval(&self) -> Result<Value> {
+		let params = self.1.iter()
+			.map(|i| i.eval())
+			.collect::<Result<Vec<Value>>>()?;
+
+		self.0.call(params)
+	}
+}
+
+
+
+/// Converts between dates and timestamps. 1700000000 -> datetime, now -> unix_ms
+#[derive(Debug)]
+pub struct DateConversion(ExpressionArg, DateTarget);
+
+impl DateConversion {
+	pub fn new(value: ExpressionArg, target: DateTarget) -> Self {
+		DateConversion(value, target)
+	}
+}
+
+impl Expression for DateConversion {
+	fn eval(&self) -> Result<Value> {
+		self.1.convert(self.0.eval()?)
+	}
+}
+
+
+
+/// Shows a duration in named units. 93784 s -> human, 93784 s -> dhms
+#[derive(Debug)]
+pub struct Humanize(ExpressionArg, DurationFormat);
+
+impl Humanize {
+	pub fn new(value: ExpressionArg, format: DurationFormat) -> Self {
+		Humanize(value, format)
+	}
+}
+
+impl Expression for Humanize {
+	fn eval(&self) -> Result<Value> {
+		self.0.eval()?.into_humanized(self.1)
+	}
+}
+
+
+
+/// Changes how a complex value is shown. 3 + 4i -> polar
+#[derive(Debug)]
+pub struct ComplexFormat(ExpressionArg, ComplexForm);
+
+impl ComplexFormat {
+	pub fn new(value: ExpressionArg, form: ComplexForm) -> Self {
+		ComplexFormat(value, form)
+	}
+}
+
+impl Expression for ComplexFormat {
+	fn eval(&self) -> Result<Value> {
+		Ok(self.0.eval()?.with_complex_form(self.1))
+	}
+}
+
+
+
+/// Shows the value as a fraction. 0.3125 in -> frac
+#[derive(Debug)]
+pub struct Fraction(ExpressionArg, i128);
+
+impl Fraction {
+	pub fn new(value: ExpressionArg, max_denominator: i128) -> Self {
+		Fraction(value, max_denominator)
+	}
+}
+
+impl Expression for Fraction {
+	fn eval(&self) -> Result<Value> {
+		self.0.eval()?.into_fraction(self.1)
+	}
+}
+
+
+
+#[derive(Debug)]
+pub struct Negate(ExpressionArg);
+
+impl Negate {
+	pub fn new(value: ExpressionArg) -> Self {
+		Negate(value)
+	}
+}
+
+impl Expression for Negate {
+	fn eval(&self) -> Result<Value> {
+		Value::try_negate(self.0.eval()?)
+	}
+
+	fn polynomial(&self, variables: &[String]) -> Result<Polynomial> {
+		Ok(self.0.polynomial(variables)?.negate())
+	}
+}
+
+
+
+#[derive(Debug)]
+pub struct Conversion(ExpressionArg, ExpressionArg, Rc<ExchangeRates>);
 
 impl Conversion {
 	pub fn new(left: ExpressionArg, right: ExpressionArg) -> Self {
-		Conversion(left, right)
+		Conversion(left, right, Rc::new(ExchangeRates::default()))
+	}
+
+	/// Money is exchanged into the target currency first. 5 EUR -> USD
+	pub fn with_rates(left: ExpressionArg, right: ExpressionArg, rates: Rc<ExchangeRates>) -> Self {
+		Conversion(left, right, rates)
 	}
 }
 
 impl Expression for Conversion {
 	fn eval(&self) -> Result<Value> {
-		let left = self.0.eval()?;
 		let right = self.1.eval()?;
+		let left = self.2.exchange(self.0.eval()?, &right)?;
 
 		Ok(Value::try_conversion(left, right)?)
 	}
@@ -154,25 +405,278 @@ impl Expression for Comparison {
 
 
 #[derive(Debug)]
-pub struct Function(Box<dyn FunctionEval>, Vec<ExpressionArg>);
+pub struct Function(Box<dyn FunctionEval>, Vec<ExpressionArg>, Option<Units>);
 
 impl Function {
 	pub fn new(func: Box<dyn FunctionEval>, args: Vec<ExpressionArg>) -> Self {
-		Function(func, args)
+		Function(func, args, None)
+	}
+
+	/// Unit given to parameters without one.
+	pub fn with_default_unit(mut self, unit: Units) -> Self {
+		self.2 = Some(unit);
+		self
+	}
+}
+
+impl Function {
+	fn call(&self, params: Vec<Value>) -> Result<Value> {
+		let info = self.0.info();
+
+		// Functions with a set amount of parameters are applied to each item in a list.
+		// sqrt([4, 9]) = [2, 3]
+		let takes_list = info.is_variadic() || info.params.iter().any(|p| p.dimension == Dimension::List);
+
+		if !takes_list {
+			let lengths = params.iter()
+				.filter_map(|p| if let Value::List(items) = p { Some(items.len()) } else { None })
+				.collect::<Vec<_>>();
+
+			if let Some(&length) = lengths.first() {
+				if let Some(&other) = lengths.iter().find(|l| **l != length) {
+					return Err(Error::MismatchedListLength(length, other));
+				}
+
+				return Ok(Value::List(
+					(0..length)
+					.map(|i| self.call(
+						params.iter()
+						.map(|p| if let Value::List(items) = p { items[i].clone() } else { p.clone() })
+						.collect()
+					))
+					.collect::<Result<_>>()?
+				));
+			}
+		}
+
+		// abs(3 + 4i) = 5
+		if params.iter().any(|p| matches!(p, Value::Complex(..))) {
+			let mut form = ComplexForm::Rectangular;
+
+			let params = params.into_iter()
+				.map(|p| {
+					let (value, unit, f) = p.into_complex().ok_or(Error::ExpectedQuantity)?;
+
+					if f == ComplexForm::Polar {
+						form = f;
+					}
+
+					Ok((value, unit))
+				})
+				.collect::<Result<Vec<_>>>()?;
+
+			let (value, unit) = self.0.eval_complex(params)?;
+
+			return Ok(Value::new_complex(value, unit, form));
+		}
+
+		// lo([10 kg .. 12 kg]) = 10 kg
+		if params.iter().any(|p| matches!(p, Value::Interval(..))) {
+			let params = params.into_iter()
+				.map(|p| p.into_interval().ok_or(Error::ExpectedQuantity))
+				.collect::<Result<Vec<_>>>()?;
+
+			let (lo, hi) = self.0.eval_interval(params)?;
+
+			return Value::new_interval(lo, hi);
+		}
+
+		// sqrt(4 ± 0.1) = 2.000 ± 0.025
+		if params.iter().any(|p| matches!(p, Value::Uncertain(..))) {
+			return self.call_uncertain(params);
+		}
+
+		// Lists are flattened into the parameters.
+		let params = params.into_iter()
+			.map(Value::into_quantities)
+			.collect::<Result<Vec<Vec<Quantity>>>>()?
+			.concat();
+
+		Ok(Value::Quantity(self.eval_quantities(params)?))
+	}
+
+	/// First order propagation. Each partial derivative is found with a central difference
+	/// a small fraction of that parameter's uncertainty wide.
+	fn call_uncertain(&self, params: Vec<Value>) -> Result<Value> {
+		let params = params.into_iter()
+			.map(|p| p.into_uncertain().ok_or(Error::ExpectedQuantity))
+			.collect::<Result<Vec<_>>>()?;
+
+		let values = params.iter().map(|(q, _)| q.clone()).collect::<Vec<_>>();
+
+		let value = self.eval_quantities(values.clone())?;
+
+		let mut terms = Vec::new();
+
+		for (i, (param, uncertainty)) in params.iter().enumerate() {
+			if uncertainty.is_zero() {
+				continue;
+			}
+
+			let step = *uncertainty / Decimal::ONE_THOUSAND;
+
+			let shifted = |offset: Decimal| {
+				let mut values = values.clone();
+				values[i] = Quantity::new_unit(param.amount() + offset, param.unit().ok().cloned());
+				self.eval_quantities(values).map(|q| q.amount())
+			};
+
+			let slope = (shifted(step)? - shifted(-step)?) / (step * Decimal::TWO);
+
+			terms.push(slope * uncertainty);
+		}
+
+		let sum = terms.iter()
+			.try_fold(Decimal::ZERO, |sum, term| sum.checked_add(term.checked_mul(*term)?))
+			.and_then(conversion::quantity::transcendental::sqrt)
+			.ok_or_else(|| Error::Conversion(ConversionError::InvalidArgument("The uncertainty is too large".to_string())))?;
+
+		Ok(Value::Uncertain(value, sum.normalize()))
+	}
+
+	/// Gives parameters without units the default unit.
+	fn eval_quantities(&self, params: Vec<Quantity>) -> Result<Quantity> {
+		let params = match self.2.as_ref() {
+			Some(unit) => params.into_iter()
+				.map(|q| if q.unit().is_ok() { q } else { Quantity::new_unit(q.amount(), Some(unit.clone())) })
+				.collect(),
+			None => params
+		};
+
+		Ok(self.0.eval(params)?)
 	}
 }
 
 impl Expression for Function {
 	fn eval(&self) -> Result<Value> {
+		let info = self.0.info();
+
+		if info.params.iter().any(|p| p.dimension == Dimension::Expression) {
+			let args = self.1.iter()
+				.enumerate()
+				.map(|(i, arg)| {
+					let dimension = info.params.get(i).or_else(|| info.params.last()).map(|p| p.dimension);
+
+					match dimension {
+						Some(Dimension::Expression) | Some(Dimension::Unknown) => arg.as_argument().ok_or(Error::ExpectedArgument),
+						_ => Ok(Argument::Value(arg.eval()?.into_quantity().ok_or(Error::ExpectedQuantity)?))
+					}
+				})
+				.collect::<Result<Vec<_>>>()?;
+
+			return Ok(Value::Quantity(self.0.eval_args(args)?));
+		}
+
 		let params = self.1.iter()
 			.map(|i| i.eval())
 			.collect::<Result<Vec<Value>>>()?;
 
-		let params = params.into_iter()
-			.map(|i| i.into_quantity().ok_or_else(|| Error::ExpectedQuantity))
-			.collect::<Result<Vec<Quantity>>>()?;
+		self.call(params)
+	}
+}
+
+
+
+/// The value of an unknown inside an expression argument. x in integrate(x^2, x, 0, 1)
+#[derive(Debug)]
+pub struct Unknown(String, Rc<RefCell<Option<Quantity>>>);
+
+impl Unknown {
+	pub fn new(name: String, value: Rc<RefCell<Option<Quantity>>>) -> Self {
+		Unknown(name, value)
+	}
+}
 
-		Ok(Value::Quantity(self.0.eval(params)?))
+impl Expression for Unknown {
+	fn eval(&self) -> Result<Value> {
+		match self.1.borrow().as_ref() {
+			Some(value) => Ok(Value::Quantity(value.clone())),
+			None => Err(Error::Conversion(ConversionError::InvalidArgument(format!(r#""{}" only has a value inside the expression"#, self.0))))
+		}
+	}
+
+	fn as_argument(&self) -> Option<Argument<'_>> {
+		Some(Argument::Unknown(self.0.clone()))
+	}
+}
+
+
+
+/// An expression evaluated by the function it's given to. Sets its unknown before evaluating.
+#[derive(Debug)]
+pub struct ExpressionLambda(ExpressionArg, Rc<RefCell<Option<Quantity>>>);
+
+impl ExpressionLambda {
+	pub fn new(expression: ExpressionArg, value: Rc<RefCell<Option<Quantity>>>) -> Self {
+		ExpressionLambda(expression, value)
+	}
+}
+
+impl Expression for ExpressionLambda {
+	fn eval(&self) -> Result<Value> {
+		Err(Error::Conversion(ConversionError::InvalidArgument("Expected a value, found an expression".to_string())))
+	}
+
+	fn as_argument(&self) -> Option<Argument<'_>> {
+		Some(Argument::Expression(self))
+	}
+}
+
+impl Lambda for ExpressionLambda {
+	fn call(&self, value: Quantity) -> FunctionResult {
+		let previous = self.1.replace(Some(value));
+
+		let result = self.0.eval();
+
+		self.1.replace(previous);
+
+		match result {
+			Ok(Value::Quantity(value)) | Ok(Value::Formatted(value, _)) => Ok(value),
+			Ok(_) => Err(ConversionError::InvalidArgument("The expression has to be a single value".to_string())),
+			Err(Error::Conversion(e)) => Err(e),
+			Err(e) => Err(ConversionError::InvalidArgument(e.to_string()))
+		}
+	}
+}
+
+
+
+#[derive(Debug)]
+pub struct List(Vec<ExpressionArg>);
+
+impl List {
+	pub fn new(items: Vec<ExpressionArg>) -> Self {
+		List(items)
+	}
+}
+
+impl Expression for List {
+	fn eval(&self) -> Result<Value> {
+		Ok(Value::List(
+			self.0.iter()
+			.map(|i| i.eval())
+			.collect::<Result<Vec<Value>>>()?
+		))
+	}
+}
+
+
+
+#[derive(Debug)]
+pub struct Index(ExpressionArg, ExpressionArg);
+
+impl Index {
+	pub fn new(value: ExpressionArg, index: ExpressionArg) -> Self {
+		Index(value, index)
+	}
+}
+
+impl Expression for Index {
+	fn eval(&self) -> Result<Value> {
+		let value = self.0.eval()?;
+		let index = self.1.eval()?;
+
+		Value::try_index(value, index)
 	}
 }
 
@@ -208,4 +712,55 @@ impl Expression for Grouping {
 	fn eval(&self) -> Result<Value> {
 		Ok(self.0.eval()?)
 	}
-}
\ No newline at end of file
+
+	fn polynomial(&self, variables: &[String]) -> Result<Polynomial> {
+		self.0.polynomial(variables)
+	}
+}
+
+
+
+/// Equations separated by ";". Solves for the unknowns when evaluated.
+///
+/// A single unknown can have more than one solution. Several unknowns are solved together as a
+/// linear system and their values are listed in the order of the unknowns.
+#[derive(Debug)]
+pub struct Solve(Vec<(ExpressionArg, ExpressionArg)>, Vec<String>);
+
+impl Solve {
+	pub fn new(equations: Vec<(ExpressionArg, ExpressionArg)>, variables: Vec<String>) -> Self {
+		Solve(equations, variables)
+	}
+}
+
+impl Expression for Solve {
+	fn eval(&self) -> Result<Value> {
+		let polynomials = self.0.iter()
+			.map(|(left, right)| left.polynomial(&self.1)?.try_subtract(right.polynomial(&self.1)?))
+			.collect::<Result<Vec<_>>>()?;
+
+		match (polynomials.as_slice(), self.1.as_slice()) {
+			(_, []) => Err(Error::UnableToSolve("There is no unknown to solve for".to_string())),
+
+			([polynomial], [variable]) => {
+				let mut roots = equations::solve_polynomial(polynomial.clone(), variable)?;
+
+				match roots.len() {
+					0 => Err(Error::UnableToSolve("There is no solution".to_string())),
+					1 => Ok(roots.remove(0)),
+					_ => Ok(Value::List(roots))
+				}
+			}
+
+			_ => {
+				let mut values = equations::solve_linear_system(polynomials, &self.1)?;
+
+				if values.len() == 1 {
+					Ok(values.remove(0))
+				} else {
+					Ok(Value::List(values))
+				}
+			}
+		}
+	}
+}
@@ -1,14 +1,19 @@
 use std::fmt;
+use std::rc::Rc;
+use std::cell::RefCell;
 use std::ops::RangeBounds;
 
 use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
 
-use conversion::{Quantity, Units};
+use conversion::{Quantity, Units, BaseUnit, DateTime, DurationFormat, TimeZone, FunctionInfo, Dimension, split_superscript};
+use conversion::Error as ConversionError;
 use conversion::units::CustomUnit;
 
 
-use crate::{Factory, Operator, ExprToken, Tokenizer, Result, Error, Value};
-use crate::operations::{Literal, Function, ExpressionArg};
+use crate::{Factory, Operator, ExprToken, Tokenizer, Result, Error, Value, ComplexForm, Definition};
+use crate::dates::{DateFunction, DateTarget, parse_clock, invalid_date, unknown_offset};
+use crate::operations::{Literal, Function, Grouping, Negate, List, Index, Solve, Unknown, Interval, Conversion, DateCall, DateConversion, Humanize, ExpressionLambda, ComplexFormat, Fraction, ExpressionArg};
 
 #[derive(Debug)]
 pub struct Expression {
@@ -57,14 +62,24 @@ macro_rules! return_value {
 #[derive(Debug)]
 pub enum ParseValue {
 	Single(Value),
-	Multi(Vec<ExprToken>)
+	Multi(Vec<ExprToken>),
+	/// A unit or prefix to add. define furlong = 201.168 m
+	Definition(Definition)
 }
 
 impl ParseValue {
 	pub fn into_tokens(self) -> Vec<ExprToken> {
 		match self {
 			ParseValue::Single(v) => v.into_tokens(),
-			ParseValue::Multi(v) => v
+			ParseValue::Multi(v) => v,
+			ParseValue::Definition(d) => vec![ExprToken::Literal(d.to_string())]
+		}
+	}
+
+	pub fn into_value(self) -> Option<Value> {
+		match self {
+			ParseValue::Single(v) => Some(v),
+			ParseValue::Multi(_) | ParseValue::Definition(_) => None
 		}
 	}
 }
@@ -80,6 +95,7 @@ impl fmt::Display for ParseValue {
 
 				Ok(())
 			}
+			ParseValue::Definition(d) => d.fmt(f)
 		}
 	}
 }
@@ -101,6 +117,8 @@ pub struct ParserOpts {
 	pub ignore_exact_unit_name: bool,
 	/// Ignore Whitespaces when parsing.
 	pub ignore_white_space: bool,
+	/// Results aren't rounded to the precision or shown as fractions. Used for values which are stored.
+	pub full_precision: bool
 }
 
 impl Default for ParserOpts {
@@ -108,19 +126,24 @@ impl Default for ParserOpts {
 		ParserOpts {
 			ignore_custom_units: false,
 			ignore_exact_unit_name: false,
-			ignore_white_space: true
+			ignore_white_space: true,
+			full_precision: false
 		}
 	}
 }
 
 
 
+type UnknownValue = Rc<RefCell<Option<Quantity>>>;
+
 pub struct Parser<'a> {
 	factory: &'a Factory,
 	pub tokenizer: Tokenizer<'a>,
 	pub steps: Vec<Vec<ExprToken>>,
-	pub options: ParserOpts
+	pub options: ParserOpts,
 
+	/// Unknowns of the expression arguments being parsed. x in integrate(x^2, x, 0, 1)
+	unknowns: RefCell<Vec<(String, UnknownValue)>>
 }
 
 impl<'a> Parser<'a> {
@@ -129,7 +152,8 @@ impl<'a> Parser<'a> {
 			factory,
 			steps: Vec::new(),
 			tokenizer: Tokenizer::new(eval, factory),
-			options: ParserOpts::default()
+			options: ParserOpts::default(),
+			unknowns: RefCell::new(Vec::new())
 		}
 	}
 
@@ -138,7 +162,8 @@ impl<'a> Parser<'a> {
 			factory,
 			tokenizer,
 			steps: Vec::new(),
-			options: ParserOpts::default()
+			options: ParserOpts::default(),
+			unknowns: RefCell::new(Vec::new())
 		}
 	}
 
@@ -147,6 +172,11 @@ impl<'a> Parser<'a> {
 	}
 
 	pub fn parse(&mut self) -> Result<ParseValue> {
+		// Added by the caller. define furlong = 201.168 m, prefix kibi- = 1024
+		if let Some(definition) = self.factory.parse_definition(self.tokenizer.get_remaining_str()) {
+			return definition.map(ParseValue::Definition);
+		}
+
 		self.tokenizer.parse()?;
 
 		print_dbg!("Parsed Tokens: {:?}", self.get_parsed_tokens());
@@ -163,52 +193,33 @@ impl<'a> Parser<'a> {
 			self.steps.push(slicer.tokens.clone());
 		}
 
-		loop {
-			slicer.reset_pos();
-			slicer.forward();
-
-			let current_operation = self.parse_tokens(&mut slicer)?;
-
-			// This should be what it JUST did.
-			match current_operation {
-				Some(to_parse) => {
-					if !slicer.is_finished() {
-						if let Some(replace_range) = to_parse.range {
-							let eval = to_parse.args.eval()?;
-							slicer.replace(replace_range.0..replace_range.1, eval.into_tokens());
-						}
-
-						if !slicer.tokens.is_empty() {
-							self.steps.push(slicer.tokens.clone());
-						}
+		slicer.forward();
+		slicer.reset_pos();
 
-						print_dbg!("");
-					} else {
-						print_dbg!("");
+		match self.parse_equation(&mut slicer)? {
+			Some(expr) if slicer.is_finished() => {
+				print_dbg!("Expression: {:?}", expr.args);
 
-						print_dbg!("Steps:");
-						print_dbg!(" - {:?}", self.get_parsed_tokens().iter().map(|t| format!("{}", t)).collect::<Vec<String>>().join(" "));
-						for step in self.steps.as_slice() {
-							print_dbg!(" - {:?}", step.iter().map(|t| format!("{}", t)).collect::<Vec<String>>().join(" "));
-						}
+				let mut value = expr.args.eval()?;
 
-						return Ok(ParseValue::Single(to_parse.args.eval()?));
+				if !self.options.full_precision {
+					if self.factory.settings().rational {
+						value = value.into_exact_fraction();
 					}
+
+					value = value.into_display(self.factory.settings());
 				}
 
-				None => {
-					print_dbg!("");
-					print_dbg!("Unable to continue parsing...");
-					print_dbg!("{:?}", slicer.tokens);
+				self.steps.push(value.clone().into_tokens());
 
-					print_dbg!("Steps:");
-					print_dbg!(" - {:?}", self.get_parsed_tokens().iter().map(|t| format!("{}", t)).collect::<Vec<String>>().join(" "));
-					for step in self.steps.as_slice() {
-						print_dbg!(" - {:?}", step.iter().map(|t| format!("{}", t)).collect::<Vec<String>>().join(" "));
-					}
+				Ok(ParseValue::Single(value))
+			}
 
-					return Ok(ParseValue::Multi(slicer.tokens));
-				}
+			_ => {
+				print_dbg!("Unable to continue parsing at {}", slicer.get_pos());
+				print_dbg!("{:?}", slicer.tokens);
+
+				Ok(ParseValue::Multi(slicer.tokens))
 			}
 		}
 	}
@@ -220,7 +231,7 @@ impl<'a> Parser<'a> {
 
 		while let Some(token) = slicer.peek() {
 			// Number, Literal
-			if token.is_literal() && slicer.peek_previous().map(|p| p.is_number()).unwrap_or_default() {
+			if token.is_literal() && !self.is_constant(token) && slicer.peek_previous().map(|p| p.is_number()).unwrap_or_default() {
 				slicer.prev_pos();
 
 				let start_pos = slicer.get_pos();
@@ -257,284 +268,668 @@ impl<'a> Parser<'a> {
 		Ok(updated)
 	}
 
-	pub fn parse_tokens(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
-		print_dbg!(" - Parse: {:?}", slicer.tokens);
 
-		// EXPONENTS ^
-		let found_exp = slicer.find_indexes(&Operator::Caret.into());
-		if let Some(pos) = found_exp.first() {
-			return self.parse_exponents(*pos, slicer);
+	// Order of Operations (lowest to highest):
+	//  - Equation      =
+	//  - Comparisons   == != > >= < <=
+	//  - Conversion    ->
+	//  - Additive      + -
+	//  - Multiplicative * /
+	//  - Unary         -
+	//  - Implied       (x - 3)(x - 2), 2 PI
+	//  - Power         ^
+	//  - Postfix       list[index]
+	//  - Primary       5 km, (...), [...], func(...)
+
+	/// An expression. Or an equation to solve if it contains "=". 3x - 6 = 9
+	pub fn parse_equation(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
+		let start_pos = slicer.get_pos();
+
+		let left = match self.parse_expression(slicer)? {
+			Some(expr) => expr,
+			None => return Ok(None)
+		};
+
+		if !slicer.consume_if_next(&Operator::Equal.into()) {
+			return Ok(Some(left));
 		}
 
-		// GROUPINGS ( [ {  } ] )
-		let found_grps = slicer.find_multiple_indexes(&[ExprToken::StartGrouping, ExprToken::EndGrouping]).into_iter();
-		for pos in found_grps {
-			let found = self.parse_parentheses(pos, slicer)?;
-			if found.is_some() { return Ok(found); }
+		let right = self.expect_expression(slicer, Self::parse_expression)?;
+
+		let mut equations = vec![(left.args, right)];
+
+		// 2x + 3y = 7; x - y = 1
+		while slicer.consume_if_next(&ExprToken::Semicolon) {
+			equations.push(self.parse_equation_sides(slicer)?);
 		}
 
+		// Every custom unit is an unknown.
+		let variables = self.find_variables(&slicer.get_tokens()[start_pos..slicer.get_pos()]);
+
+		print_dbg!(" - Equations: {:?} for {:?}", equations, variables);
+
+		Ok(Some(Expression::new_range(Box::new(Solve::new(equations, variables)), (start_pos, slicer.get_pos()))))
+	}
+
+	/// left = right
+	fn parse_equation_sides(&self, slicer: &mut TokenSlicer) -> Result<(ExpressionArg, ExpressionArg)> {
+		let left = self.expect_expression(slicer, Self::parse_expression)?;
+
+		self.expect_token(slicer, Operator::Equal.into())?;
+
+		let right = self.expect_expression(slicer, Self::parse_expression)?;
+
+		Ok((left, right))
+	}
+
+	pub fn parse_expression(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
+		self.parse_comparison(slicer)
+	}
+
+	pub fn parse_comparison(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
+		self.parse_binary(
+			slicer,
+			&[
+				Operator::GreaterThan,
+				Operator::GreaterThanOrEqual,
+				Operator::LessThan,
+				Operator::LessThanOrEqual,
+				Operator::DoubleEqual,
+				Operator::DoesNotEqual
+			],
+			Self::parse_conversion
+		)
+	}
+
+	pub fn parse_conversion(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
+		let start_pos = slicer.get_pos();
+
+		let mut value = match self.parse_additive(slicer)? {
+			Some(expr) => expr.args,
+			None => return Ok(None)
+		};
+
+		while slicer.consume_if_next(&Operator::ConvertInto.into()) {
+			let name = match slicer.peek() {
+				Some(ExprToken::Literal(name)) => Some(name.clone()),
+				_ => None
+			};
 
-		let mut found_ops = slicer.find_multiple_indexes(&[Operator::Multiply.into(), Operator::Divide.into()]);
-		found_ops.append(&mut slicer.find_multiple_indexes(&[Operator::Plus.into(), Operator::Minus.into()]));
-		found_ops.append(&mut slicer.find_indexes(&Operator::ConvertInto.into()));
-		found_ops.append(&mut slicer.find_multiple_indexes(&[
-			Operator::GreaterThan.into(),
-			Operator::GreaterThanOrEqual.into(),
-			Operator::LessThan.into(),
-			Operator::LessThanOrEqual.into(),
-			Operator::DoubleEqual.into(),
-			Operator::DoesNotEqual.into(),
-		]));
+			// 3 + 4i -> polar
+			if let Some(form) = name.as_deref().and_then(ComplexForm::from_name) {
+				slicer.next_pos();
 
-		let found_ops = found_ops.into_iter();
+				value = Box::new(ComplexFormat::new(value, form));
+			} else if name.as_deref() == Some("frac") {
+				// 0.3125 in -> frac
+				slicer.next_pos();
+
+				value = Box::new(Fraction::new(value, self.factory.settings().max_denominator));
+			} else if let Some(target) = name.as_deref().filter(|name| self.factory.find_unit(name).is_none()).and_then(DateTarget::from_name) {
+				// 1700000000 -> datetime, 2026-10-18T00:00Z -> unix
+				slicer.next_pos();
+
+				value = Box::new(DateConversion::new(value, target));
+			} else if name.as_deref() == Some("human") || name.as_deref() == Some("dhms") {
+				// 93784 s -> human, 93784 s -> dhms(2)
+				slicer.next_pos();
+
+				let pos = slicer.get_pos();
+
+				let max_parts = match (slicer.get(pos), slicer.get(pos + 1), slicer.get(pos + 2)) {
+					(Some(ExprToken::StartGrouping), Some(ExprToken::Number(count)), Some(ExprToken::EndGrouping)) => {
+						let count = count.to_usize().filter(|c| *c > 0 && count.fract().is_zero())
+							.ok_or_else(|| Error::Conversion(ConversionError::InvalidArgument(format!("{} isn't a number of units", count))))?;
+
+						slicer.consume(3);
+
+						Some(count)
+					}
+					_ => None
+				};
 
-		for pos in found_ops {
-			let found = self.parse_operation(pos, slicer)?;
-			if found.is_some() { return Ok(found); }
+				let format = DurationFormat {
+					compact: name.as_deref() == Some("dhms"),
+					max_parts,
+					months: self.factory.settings().duration_months,
+					start: None
+				};
+
+				value = Box::new(Humanize::new(value, format));
+			} else {
+				let right = self.expect_expression(slicer, Self::parse_additive)?;
+
+				value = Box::new(Conversion::with_rates(value, right, self.factory.exchange_rates()));
+			}
 		}
 
-		self.parse_finished(slicer)
+		Ok(Some(Expression::new_range(value, (start_pos, slicer.get_pos()))))
+	}
+
+	pub fn parse_additive(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
+		self.parse_binary(slicer, &[Operator::Plus, Operator::Minus], Self::parse_multiplicative)
+	}
+
+	pub fn parse_multiplicative(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
+		self.parse_binary(slicer, &[Operator::Multiply, Operator::Divide], Self::parse_unary)
 	}
 
+	/// Left associative operations. Calls `next` for each side of the operator.
+	fn parse_binary<F>(&self, slicer: &mut TokenSlicer, operators: &[Operator], next: F) -> ExpressionResult where F: Fn(&Self, &mut TokenSlicer) -> ExpressionResult {
+		let start_pos = slicer.get_pos();
+
+		let mut left = match next(self, slicer)? {
+			Some(expr) => expr.args,
+			None => return Ok(None)
+		};
+
+		while let Some(ExprToken::Operator(op)) = slicer.peek().cloned() {
+			if !operators.contains(&op) {
+				break;
+			}
+
+			slicer.next_pos();
+
+			let right = self.expect_expression(slicer, &next)?;
 
-	pub fn parse_exponents(&self, pos: usize, slicer: &mut TokenSlicer) -> ExpressionResult {
-		print_dbg!("parse_exponents");
+			print_dbg!(" - Operation: {:?} {} {:?}", left, op, right);
 
-		let pos_from_end = slicer.tokens.len() - pos;
+			left = op.compare(left, right);
+		}
 
-		// Work backward to get what's before the expontent.
-		slicer.backward();
-		slicer.set_pos(pos);
-		slicer.next_pos();
+		Ok(Some(Expression::new_range(left, (start_pos, slicer.get_pos()))))
+	}
 
+	pub fn parse_unary(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
+		let start_pos = slicer.get_pos();
 
-		let paren = slicer.peek().unwrap() == &ExprToken::EndGrouping;
+		if slicer.consume_if_next(&Operator::Minus.into()) {
+			let value = self.expect_expression(slicer, Self::parse_unary)?;
 
-		let base = if paren {
-			self.parse_parentheses(slicer.get_pos(), slicer)?
+			Ok(Some(Expression::new_range(Box::new(Negate::new(value)), (start_pos, slicer.get_pos()))))
+		} else if slicer.consume_if_next(&Operator::Plus.into()) {
+			self.parse_unary(slicer)
 		} else {
-			self.parse_number_expression(slicer)?
+			self.parse_implied(slicer)
+		}
+	}
+
+	/// Implied multiplication. (x - 3)(x - 2), (x - 1)^2 (x - 2)
+	pub fn parse_implied(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
+		let start_pos = slicer.get_pos();
+
+		let mut value = match self.parse_power(slicer)? {
+			Some(expr) => expr.args,
+			None => return Ok(None)
 		};
 
-		// Return if range is defined in parentheses.
-		if paren && base.as_ref().map(|e| e.range.is_some()).unwrap_or_default() {
-			return Ok(base);
+		// (x - 3)(x - 2), 2 PI, 2x with a bound unknown
+		while slicer.is_next_value(&ExprToken::StartGrouping) || slicer.peek().map(|t| self.is_constant(t)).unwrap_or_default() {
+			let right = self.expect_expression(slicer, Self::parse_power)?;
+
+			value = Operator::Multiply.compare(value, right);
 		}
 
+		Ok(Some(Expression::new_range(value, (start_pos, slicer.get_pos()))))
+	}
+
+	/// Right associative. 2^3^2 = 2^9
+	pub fn parse_power(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
 		let start_pos = slicer.get_pos();
 
-		slicer.forward();
-		slicer.set_pos(slicer.tokens.len() - pos_from_end + 1);
+		let mut value = match self.parse_postfix(slicer)? {
+			Some(expr) => expr.args,
+			None => return Ok(None)
+		};
 
-		let power = self.parse_number_expression(slicer)?;
-		let end_pos = slicer.get_pos();
+		if slicer.consume_if_next(&Operator::Caret.into()) {
+			// Allows for negative exponents. 2^-1
+			let exp = if slicer.consume_if_next(&Operator::Minus.into()) {
+				Box::new(Negate::new(self.expect_expression(slicer, Self::parse_power)?))
+			} else {
+				self.expect_expression(slicer, Self::parse_power)?
+			};
 
-		slicer.reset_pos();
+			value = Operator::Caret.compare(value, exp);
+		}
 
-		let expr = Operator::Caret.compare(
-			base.ok_or(Error::InputEmpty)?.args,
-			power.ok_or(Error::InputEmpty)?.args
-		);
+		// 5∠30, 5 Ω ∠ -30deg
+		if slicer.consume_if_next(&Operator::Angle.into()) {
+			let angle = if slicer.consume_if_next(&Operator::Minus.into()) {
+				Box::new(Negate::new(self.expect_expression(slicer, Self::parse_power)?))
+			} else {
+				self.expect_expression(slicer, Self::parse_power)?
+			};
+
+			value = Operator::Angle.compare(value, angle);
+		}
 
-		Ok(Some(Expression::new_range(expr, (start_pos, end_pos))))
+		// 5.00 ± 0.02 m, 5 +/- 0.1
+		if slicer.consume_if_next(&Operator::PlusMinus.into()) {
+			let uncertainty = self.expect_expression(slicer, Self::parse_power)?;
+
+			value = Operator::PlusMinus.compare(value, uncertainty);
+		}
+
+		Ok(Some(Expression::new_range(value, (start_pos, slicer.get_pos()))))
 	}
 
-	pub fn parse_parentheses(&self, mut start_pos: usize, slicer: &mut TokenSlicer) -> ExpressionResult {
-		print_dbg!("parse_parentheses: {} - {}", start_pos, slicer.is_reversed());
+	pub fn parse_postfix(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
+		let start_pos = slicer.get_pos();
+
+		let mut value = match self.parse_primary(slicer)? {
+			Some(expr) => expr.args,
+			None => return Ok(None)
+		};
 
-		slicer.set_pos(start_pos);
-		// slicer.next_pos();
+		// Indexing: [1, 2, 3][0]
+		while slicer.consume_if_next(&ExprToken::StartList) {
+			let index = self.expect_expression(slicer, Self::parse_expression)?;
 
-		// If is reversed find the StartGrouping and continue from there.
-		if slicer.is_reversed() {
-			let mut nest_depth = 0;
+			self.expect_token(slicer, ExprToken::EndList)?;
 
-			loop {
-				let next_expr = slicer.next().ok_or(Error::InputEmpty)?;
+			value = Box::new(Index::new(value, index));
+		}
 
-				if next_expr == ExprToken::EndGrouping {
-					nest_depth += 1;
-				} else if next_expr == ExprToken::StartGrouping {
-					if nest_depth != 0 {
-						nest_depth -= 1;
-					} else {
-						break;
-					}
-				}
+
+		Ok(Some(Expression::new_range(value, (start_pos, slicer.get_pos()))))
+	}
+
+	pub fn parse_primary(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
+		let start_pos = slicer.get_pos();
+
+		let args: ExpressionArg = match slicer.peek() {
+			// GROUPINGS ( {  } )
+			Some(ExprToken::StartGrouping) => {
+				slicer.next_pos();
+
+				let inner = self.expect_expression(slicer, Self::parse_expression)?;
+
+				self.expect_token(slicer, ExprToken::EndGrouping)?;
+
+				Box::new(Grouping::new(inner))
 			}
 
+			// INTERVALS [10 kg .. 12 kg]
+			Some(ExprToken::StartList) if self.is_interval(slicer) => {
+				slicer.next_pos();
 
-			let actual_start = std::cmp::min(start_pos, slicer.get_pos());
+				let lo = self.expect_expression(slicer, Self::parse_expression)?;
 
-			slicer.forward();
-			// Add 1 to Ignore Start Grouping.
-			slicer.set_pos(actual_start + 1);
-			start_pos = actual_start;
-		}
+				self.expect_token(slicer, Operator::Range.into())?;
 
-		// If it was reversed, slicer pos should now be set to start of parentheses.
+				let hi = self.expect_expression(slicer, Self::parse_expression)?;
 
-		if start_pos != 0 {
-			// Is it a literal before the parentheses?
-			// If so it's a function.
-			if let Some(ExprToken::Literal(func_name)) = slicer.peek_previous() {
-				print_dbg!(" - Function Literal: {}", func_name);
+				self.expect_token(slicer, ExprToken::EndList)?;
 
-				let func = self.factory.find_func(func_name)
-					.ok_or_else(|| Error::InvalidFunction)?;
+				Box::new(Interval::new(lo, hi))
+			}
 
-				// Capture everything after Function Name and inside the parentheses.
-				let mut inner_slicer = slicer.clone_from(start_pos + 1, slicer.tokens.len() - 1);
+			// LISTS [ ]
+			Some(ExprToken::StartList) => {
+				slicer.next_pos();
 
-				let mut params = Vec::new();
+				Box::new(List::new(self.parse_arguments(slicer, ExprToken::EndList)?))
+			}
 
-				while let Some(expr) = self.parse_number_expression(&mut inner_slicer)? {
-					params.push(expr.args);
+			// UNKNOWNS x in integrate(x^2, x, 0, 1)
+			Some(ExprToken::Literal(name)) if self.find_unknown(name).is_some() => {
+				print_dbg!(" - Unknown: {}", name);
 
-					if !inner_slicer.consume_if_next(&ExprToken::Comma) {
-						break;
-					}
+				let name = name.clone();
+				let value = self.find_unknown(&name).unwrap();
+
+				slicer.next_pos();
+
+				Box::new(Unknown::new(name, value))
+			}
+
+			// SOLVE solve(3x - 6 = 9, x), solve(2x + 3y = 7; x - y = 1, x, y)
+			Some(ExprToken::Literal(name)) if name == "solve" && slicer.get(start_pos + 1) == Some(&ExprToken::StartGrouping) => {
+				slicer.consume(2);
+
+				let mut equations = vec![self.parse_equation_sides(slicer)?];
+
+				while slicer.consume_if_next(&ExprToken::Semicolon) {
+					equations.push(self.parse_equation_sides(slicer)?);
 				}
 
-				let end_pos = slicer.pos + inner_slicer.pos + 2;
+				let mut variables = Vec::new();
 
-				return Ok(Some(Expression::new_range(
-					Box::new(Function::new(func, params)),
-					(start_pos - 1, end_pos)
-				)));
+				while slicer.consume_if_next(&ExprToken::Comma) {
+					variables.push(match slicer.next() {
+						Some(ExprToken::Literal(name)) if self.factory.find_unit(&name).is_some() => {
+							return Err(Error::UnableToSolve(format!(r#""{}" is a unit, not an unknown"#, name)));
+						}
+						Some(ExprToken::Literal(name)) => name,
+						Some(token) => return Err(Error::UnexpectedToken(token)),
+						None => return Err(Error::InputEmpty)
+					});
+				}
+
+				if variables.is_empty() {
+					return Err(Error::UnableToSolve("Expected the unknowns to solve for".to_string()));
+				}
+
+				self.expect_token(slicer, ExprToken::EndGrouping)?;
+
+				Box::new(Solve::new(equations, variables))
 			}
-		}
 
-		let mut insides = Vec::new();
+			// DATES AND TIMES 2026-10-18, 2026-10-18T09:30, 9:30am, 1:30:00
+			Some(ExprToken::Literal(value)) if value.starts_with(|c: char| c.is_ascii_digit()) => {
+				let parsed = if value.contains('-') {
+					DateTime::parse(value).map(Value::DateTime)
+				} else {
+					parse_clock(value)
+				};
 
-		// Skip start Grouping
-		if slicer.peek().map(|e| e == &ExprToken::StartGrouping).unwrap_or_default() {
-			slicer.next_pos();
-		}
+				let parsed = parsed.ok_or_else(|| invalid_date(value))?;
 
-		loop {
-			if let Some(item) = slicer.next() {
-				// Inner grouping?
-				if item == ExprToken::StartGrouping {
-					if crate::is_debug() {
-						print_dbg!(" - Inner: {}", insides.iter().map(|t| format!("{}", t)).collect::<Vec<String>>().join(" "));
-					}
+				slicer.next_pos();
 
-					return self.parse_parentheses(slicer.get_pos() - 1, slicer);
+				match parsed {
+					Value::DateTime(date) => Box::new(Literal::new(Value::DateTime(self.parse_date_suffix(slicer, date)?))),
+					parsed => Box::new(Literal::new(parsed))
 				}
+			}
 
-				if item == ExprToken::EndGrouping {
-					if crate::is_debug() {
-						print_dbg!(" - Inner: {}", insides.iter().map(|t| format!("{}", t)).collect::<Vec<String>>().join(" "));
-					}
+			// NOW now, today
+			Some(ExprToken::Literal(name)) if (name == "now" || name == "today") && self.find_constant(name).is_none() => {
+				let now = self.factory.now();
 
-					let end_pos = slicer.get_pos();
+				let value = if name == "today" {
+					now.start_of_day()
+				} else {
+					now
+				};
 
-					// The insides of the grouping only.
-					let mut group_slicer = slicer.clone_from(start_pos + 1, end_pos - 1);
+				slicer.next_pos();
 
-					let parsed = self.parse_tokens(&mut group_slicer)?;
+				Box::new(Literal::new(Value::DateTime(value)))
+			}
 
-					slicer.reset_pos();
+			// DATE FUNCTIONS weekday(2026-10-18), add_months(2026-01-31, 1)
+			Some(ExprToken::Literal(name)) if DateFunction::from_name(name).is_some() && slicer.get(start_pos + 1) == Some(&ExprToken::StartGrouping) => {
+				let func = DateFunction::from_name(name).unwrap();
 
-					if let Some(mut expr) = parsed {
-						if let Some(range) = expr.range.as_mut() {
-							// Check if start and end of range is at the start and end of group.
-							// If so, extend range in both directions by one to remove groupings too.
-							let i_s = start_pos + range.0 + 1;
-							let i_e = start_pos + range.1 + 1;
+				slicer.consume(2);
 
-							if start_pos + 1 == i_s && end_pos - 1 == i_e {
-								*range = (i_s - 1, i_e + 1);
-							} else {
-								*range = (i_s, i_e);
-							}
-						}
+				let args = self.parse_arguments(slicer, ExprToken::EndGrouping)?;
 
-						return Ok(Some(expr));
-					} else {
-						return Ok(None);
+				if args.len() != func.arg_count() {
+					let expects = format!("{} argument{}", func.arg_count(), if func.arg_count() == 1 { "" } else { "s" });
+
+					return Err(Error::ArgumentCount(func.name().to_string(), expects, args.len()));
+				}
+
+				Box::new(DateCall::new(func, args))
+			}
+
+			// FUNCTIONS name( )
+			Some(ExprToken::Literal(func_name)) if slicer.get(start_pos + 1) == Some(&ExprToken::StartGrouping) => {
+				print_dbg!(" - Function Literal: {}", func_name);
+
+				let func_name = func_name.clone();
+
+				let func = self.factory.find_func(&func_name)
+					.ok_or(Error::InvalidFunction)?;
+
+				slicer.consume(2);
+
+				let takes_angle = func.takes_angle();
+
+				let info = func.info();
+
+				let args = match self.find_unknown_name(slicer, &info)? {
+					// Parsed with the unknown bound so it isn't treated as a unit.
+					Some(name) => {
+						let value = UnknownValue::default();
+
+						self.unknowns.borrow_mut().push((name, value.clone()));
+						let args = self.parse_arguments(slicer, ExprToken::EndGrouping);
+						self.unknowns.borrow_mut().pop();
+
+						args?.into_iter()
+							.enumerate()
+							.map(|(i, arg)| match info.params.get(i).map(|p| p.dimension) {
+								Some(Dimension::Expression) => Box::new(ExpressionLambda::new(arg, value.clone())) as ExpressionArg,
+								_ => arg
+							})
+							.collect()
 					}
 
+					None => self.parse_arguments(slicer, ExprToken::EndGrouping)?
+				};
+
+				if !info.accepts(args.len()) {
+					return Err(Error::ArgumentCount(func_name, info.expects(), args.len()));
 				}
 
-				if crate::is_debug() {
-					insides.push(item.clone());
+				let mut function = Function::new(func, args);
+
+				if takes_angle {
+					function = function.with_default_unit(Units::new(self.factory.settings().angle_unit.clone()));
 				}
-			} else {
-				return Err(Error::InputEmpty);
+
+				Box::new(function)
 			}
-		}
+
+			// CONSTANTS PI, c
+			Some(ExprToken::Literal(name)) if self.find_constant(name).is_some() => {
+				print_dbg!(" - Constant: {}", name);
+
+				let value = self.find_constant(name).unwrap();
+
+				slicer.next_pos();
+
+				// The imaginary unit can carry units. 4i Ω
+				let value = match value {
+					Value::Complex(value, None, form) => Value::Complex(value, self.parse_unit_expression(slicer)?, form),
+					value => value
+				};
+
+				Box::new(Literal::new(value))
+			}
+
+			_ => return self.parse_number_expression(slicer)
+		};
+
+		Ok(Some(Expression::new_range(args, (start_pos, slicer.get_pos()))))
 	}
 
-	pub fn parse_operation(&self, pos: usize, slicer: &mut TokenSlicer) -> ExpressionResult {
-		print_dbg!("parse_operation");
+	/// Consumes ^2 or ^-1 after a unit. Only whole numbers.
+	fn parse_unit_power(&self, slicer: &mut TokenSlicer) -> Option<i32> {
+		let pos = slicer.get_pos();
 
-		let operator = slicer.get(pos).cloned().unwrap().into_operator();
+		if slicer.get(pos) != Some(&Operator::Caret.into()) {
+			return None;
+		}
 
-		slicer.backward();
-		slicer.set_pos(pos - 1);
+		let negative = slicer.get(pos + 1) == Some(&Operator::Minus.into());
+		let number_pos = if negative { pos + 2 } else { pos + 1 };
 
-		let prev = self.parse_number_expression(slicer)?;
+		let power = match slicer.get(number_pos) {
+			Some(ExprToken::Number(value)) if value.fract().is_zero() => value.to_i32()?,
+			_ => return None
+		};
 
-		slicer.forward();
-		slicer.set_pos(pos + 1);
-		let next = self.parse_number_expression(slicer)?;
-		let end_pos = slicer.get_pos();
+		slicer.set_pos(number_pos + 1);
 
-		slicer.reset_pos();
+		Some(if negative { -power } else { power })
+	}
 
+	/// A time of day and time zone written after a date. 2026-10-18 09:00 America/New_York
+	fn parse_date_suffix(&self, slicer: &mut TokenSlicer, mut date: DateTime) -> Result<DateTime> {
+		if !date.has_time() {
+			if let Some(Value::Time(time, _)) = slicer.peek().and_then(|token| match token {
+				ExprToken::Literal(value) if value.starts_with(|c: char| c.is_ascii_digit()) => parse_clock(value),
+				_ => None
+			}) {
+				if let Some(with_time) = date.add_seconds(time) {
+					date = with_time.with_time();
+					slicer.next_pos();
+				}
+			}
+		}
+
+		if date.zone().is_none() {
+			let zone = match slicer.peek() {
+				Some(ExprToken::Literal(name)) if self.factory.find_unit(name).is_none() => TimeZone::from_name(name),
+				_ => None
+			};
 
-		let prev = prev.ok_or(Error::InputEmpty)?;
-		let next = next.ok_or(Error::InputEmpty)?;
+			if let Some(zone) = zone {
+				date = DateTime::from_local(date.seconds(), zone).ok_or_else(|| unknown_offset(zone))?;
+				slicer.next_pos();
+			}
+		}
+
+		Ok(date)
+	}
 
+	/// Names of Literals which would become custom units. km/x -> [x]
+	fn find_variables(&self, tokens: &[ExprToken]) -> Vec<String> {
+		let mut variables: Vec<String> = Vec::new();
 
-		// Ensure they have same unit
-		if !crate::units::can_operate(&prev.args, &next.args) {
-			return Ok(None);
+		for token in tokens {
+			if let ExprToken::Literal(value) = token {
+				for name in value.split(['/', '·']) {
+					let (name, _) = split_superscript(name);
+
+					// x/3
+					if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+						continue;
+					}
+
+					let is_variable = self.factory.find_unit(name).is_none()
+						&& self.find_constant(name).is_none()
+						&& self.factory.find_func(name).is_none();
+
+					if is_variable && !variables.iter().any(|v| v == name) {
+						variables.push(name.to_string());
+					}
+				}
+			}
 		}
 
+		variables
+	}
 
-		let start_pos = prev.range.unwrap().0;
+	/// Units take priority over constants with the same name.
+	fn find_constant(&self, name: &str) -> Option<Value> {
+		if self.factory.find_unit(name).is_some() {
+			None
+		} else {
+			self.factory.find_const(name)
+		}
+	}
 
-		let expr = operator.compare(
-			prev.args,
-			next.args
-		);
+	/// Constants and unknowns. Neither are units.
+	fn is_constant(&self, token: &ExprToken) -> bool {
+		match token {
+			ExprToken::Literal(name) => self.find_unknown(name).is_some() || self.find_constant(name).is_some(),
+			_ => false
+		}
+	}
 
-		Ok(Some(Expression::new_range(expr, (start_pos, end_pos))))
+	/// Innermost unknown with the name.
+	fn find_unknown(&self, name: &str) -> Option<UnknownValue> {
+		self.unknowns.borrow().iter().rev().find(|(n, _)| n == name).map(|(_, v)| v.clone())
 	}
 
+	/// Looks ahead for the argument naming the unknown of a function with expression arguments.
+	///
+	/// Called after the opening grouping. integrate(x^2, x, 0, 1) -> x
+	fn find_unknown_name(&self, slicer: &TokenSlicer, info: &FunctionInfo) -> Result<Option<String>> {
+		let index = match info.params.iter().position(|p| p.dimension == Dimension::Unknown) {
+			Some(index) => index,
+			None => return Ok(None)
+		};
 
-	pub fn parse_number_expression(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
-		let start_pos = slicer.get_pos();
+		let mut depth = 0;
+		let mut position = 0;
+		let mut found = Vec::new();
 
-		if slicer.is_reversed() {
-			let unit = self.parse_unit_expression(slicer)?;
+		for token in &slicer.get_tokens()[slicer.get_pos()..] {
+			match token {
+				ExprToken::StartGrouping | ExprToken::StartList => depth += 1,
+				ExprToken::EndGrouping | ExprToken::EndList if depth == 0 => break,
+				ExprToken::EndGrouping | ExprToken::EndList => depth -= 1,
+				ExprToken::Comma if depth == 0 => {
+					position += 1;
+					continue;
+				}
+				_ => {}
+			}
 
-			if slicer.is_next_value_func(|v| v.is_number()) {
-				// Temp fix.
-				let pos = slicer.get_pos();
+			if position == index {
+				found.push(token);
+			}
+		}
 
-				let value = return_value!(slicer, ExprToken::Number);
+		match found.as_slice() {
+			[ExprToken::Literal(name)] if self.factory.find_func(name).is_none() => Ok(Some(name.clone())),
+			_ => Err(Error::Conversion(ConversionError::InvalidArgument(format!("{} expects the name of an unknown as argument {}", info.name, index + 1))))
+		}
+	}
 
-				return Ok(Some(
-					Expression::new_range(
-						Box::new(Literal::new(Value::Quantity(Quantity::new_unit(value, unit)))),
-						(pos, start_pos.max(slicer.get_pos()))
-					)
-				));
+	/// Looks ahead from the opening bracket for ".." outside of any inner groupings. [10 kg .. 12 kg]
+	fn is_interval(&self, slicer: &TokenSlicer) -> bool {
+		let mut depth = 0;
+
+		for token in &slicer.get_tokens()[slicer.get_pos() + 1..] {
+			match token {
+				ExprToken::StartGrouping | ExprToken::StartList => depth += 1,
+				ExprToken::EndGrouping | ExprToken::EndList if depth == 0 => return false,
+				ExprToken::EndGrouping | ExprToken::EndList => depth -= 1,
+				ExprToken::Operator(Operator::Range) if depth == 0 => return true,
+				_ => {}
 			}
+		}
+
+		false
+	}
 
-			else if let Some(unit) = unit {
-				return Ok(Some(
-					Expression::new_range(
-						Box::new(Literal::new(Value::Unit(unit))),
-						(start_pos.min(slicer.get_pos()), start_pos.max(slicer.get_pos()))
-					)
-				));
+	/// Comma separated expressions. Consumes the ending token.
+	pub fn parse_arguments(&self, slicer: &mut TokenSlicer, end: ExprToken) -> Result<Vec<ExpressionArg>> {
+		let mut args = Vec::new();
+
+		if slicer.consume_if_next(&end) {
+			return Ok(args);
+		}
+
+		loop {
+			args.push(self.expect_expression(slicer, Self::parse_expression)?);
+
+			if !slicer.consume_if_next(&ExprToken::Comma) {
+				self.expect_token(slicer, end)?;
+
+				return Ok(args);
 			}
-		} else if slicer.is_next_value_func(|v| v.is_number()) {
+		}
+	}
+
+	fn expect_expression<F>(&self, slicer: &mut TokenSlicer, func: F) -> Result<ExpressionArg> where F: Fn(&Self, &mut TokenSlicer) -> ExpressionResult {
+		match func(self, slicer)? {
+			Some(expr) => Ok(expr.args),
+			None => Err(slicer.peek().cloned().map(Error::UnexpectedToken).unwrap_or(Error::InputEmpty))
+		}
+	}
+
+	fn expect_token(&self, slicer: &mut TokenSlicer, token: ExprToken) -> Result<()> {
+		match slicer.next() {
+			Some(found) if found == token => Ok(()),
+			Some(found) => Err(Error::UnexpectedToken(found)),
+			None => Err(Error::InputEmpty)
+		}
+	}
+
+
+	pub fn parse_number_expression(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
+		let start_pos = slicer.get_pos();
+
+		if slicer.is_next_value_func(|v| v.is_number()) {
 			let value = return_value!(slicer, ExprToken::Number);
 			let mut unit = self.parse_unit_expression(slicer)?;
 
@@ -545,7 +940,7 @@ impl<'a> Parser<'a> {
 				//  - 9
 				// Change Division into a Unit. Check for unit when operating.
 				// Checks for Division. Then checks to see if token after it is a operator or doesn't exist.
-				if slicer.is_next_value(&Operator::Division.into()) && slicer.get(slicer.get_pos() + 1).map(|t| t.is_operator()).unwrap_or(true) {
+				if slicer.is_next_value(&Operator::Division.into()) && slicer.get(slicer.get_pos() + 1).map(|t| !t.is_number() && !t.is_literal()).unwrap_or(true) {
 					slicer.next_pos();
 
 					if let Some(found) = self.factory.find_unit("%") {
@@ -558,15 +953,15 @@ impl<'a> Parser<'a> {
 
 			return Ok(Some(
 				Expression::new_range(
-					Box::new(Literal::new(Value::Quantity(Quantity::new_unit(value, unit)))),
-					(start_pos.min(slicer.get_pos()), start_pos.max(slicer.get_pos()))
+					Box::new(Literal::new(Value::Quantity(Quantity::new_exact(value, unit)))),
+					(start_pos, slicer.get_pos())
 				)
 			));
 		} else if let Some(unit) = self.parse_unit_expression(slicer)? {
 			return Ok(Some(
 				Expression::new_range(
 					Box::new(Literal::new(Value::Unit(unit))),
-					(start_pos.min(slicer.get_pos()), start_pos.max(slicer.get_pos()))
+					(start_pos, slicer.get_pos())
 				)
 			));
 		}
@@ -575,49 +970,98 @@ impl<'a> Parser<'a> {
 	}
 
 	pub fn parse_unit_expression(&self, slicer: &mut TokenSlicer) -> Result<Option<Units>> {
-		if slicer.is_next_value_func(|v| v.is_literal()) {
+		// Literals before a grouping are function names.
+		let is_function = slicer.get(slicer.get_pos() + 1) == Some(&ExprToken::StartGrouping);
+
+		if slicer.is_next_value_func(|v| v.is_literal() && !self.is_constant(v)) && !is_function {
 			let literal_val = return_value!(slicer, ExprToken::Literal);
 
 			let mut units = Vec::new();
-			let split = literal_val.split('/');
+			let mut denominator = self.push_unit_terms(&literal_val, false, &mut units);
 
-			for name in split {
-				if let Some(base_unit) = self.factory.find_unit(name) {
-					units.push(base_unit);
-				} else if !self.options.ignore_custom_units {
-					units.push(Box::new(CustomUnit::new("%".to_string())));
-				}
+			if units.is_empty() {
+				// Put it back. It's not a unit.
+				slicer.prev_pos();
+
+				return Ok(None);
 			}
 
-			if units.is_empty() {
-				Ok(None)
-			} else {
-				Ok(Some(Units::new_vec(units)))
+			// The power belongs to the last unit. 6x^2 is 6·x², m/s^2 is m/s²
+			while let Some(power) = self.parse_unit_power(slicer) {
+				if let Some(last) = units.last_mut() {
+					last.1 *= power;
+				}
+
+				// The rest of the unit after the power. m^2/s, m^2·s
+				let pos = slicer.get_pos();
+				let count = units.len();
+
+				match slicer.peek() {
+					Some(ExprToken::Operator(Operator::Divide)) => {
+						slicer.next_pos();
+
+						if let Some(literal) = self.take_unit_literal(slicer) {
+							denominator = self.push_unit_terms(&literal, true, &mut units);
+						}
+					}
+					Some(ExprToken::Literal(literal)) if literal.starts_with('·') => {
+						if let Some(literal) = self.take_unit_literal(slicer) {
+							denominator = self.push_unit_terms(&literal, denominator, &mut units);
+						}
+					}
+					_ => break
+				}
+
+				if units.len() == count {
+					slicer.set_pos(pos);
+
+					break;
+				}
 			}
+
+			Ok(Some(Units::new_powers(units)))
 		} else {
 			Ok(None)
 		}
 	}
 
+	/// A Literal which can continue a unit. Not a constant or function name.
+	fn take_unit_literal(&self, slicer: &mut TokenSlicer) -> Option<String> {
+		let is_function = slicer.get(slicer.get_pos() + 1) == Some(&ExprToken::StartGrouping);
 
-	fn parse_finished(&self, slicer: &mut TokenSlicer) -> ExpressionResult {
-		print_dbg!("parse_finished");
+		match slicer.peek() {
+			Some(token @ ExprToken::Literal(literal)) if !is_function && !self.is_constant(token) => {
+				let literal = literal.clone();
 
-		slicer.forward();
-		slicer.reset_pos();
+				slicer.next_pos();
 
-		match self.parse_number_expression(slicer)? {
-			Some(i) => {
-				if slicer.is_finished() || slicer.tokens.len() == 1 {
-					slicer.clear();
-					Ok(Some(i))
-				} else {
-					slicer.reset_pos();
-					Ok(None)
+				Some(literal)
+			}
+			_ => None
+		}
+	}
+
+	/// Adds the units of km/h, m², kg·m/s². Denominator starts them below the line, s in m^2/s.
+	/// Returns whether the last unit is below the line.
+	fn push_unit_terms(&self, literal: &str, denominator: bool, units: &mut Vec<(Box<dyn BaseUnit>, i32)>) -> bool {
+		let mut below = denominator;
+
+		for (i, part) in literal.split('/').enumerate() {
+			below = denominator || i != 0;
+
+			for name in part.split('·').filter(|name| !name.is_empty()) {
+				let (name, power) = split_superscript(name);
+				let power = if below { -power } else { power };
+
+				if let Some(base_unit) = self.factory.find_unit(name) {
+					units.push((base_unit, power));
+				} else if !self.options.ignore_custom_units {
+					units.push((Box::new(CustomUnit::new(name.to_string())) as Box<dyn BaseUnit>, power));
 				}
-			},
-			None => Ok(None)
+			}
 		}
+
+		below
 	}
 }
 
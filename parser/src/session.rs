@@ -0,0 +1,292 @@
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+use conversion::{DateTime, MonthLength, Quantity, TimeZone};
+use conversion::Error as ConversionError;
+
+use crate::{Factory, Value, ComplexForm, NumberFormat, Result, Error};
+use crate::define::{unit_definition, prefix_definition};
+
+
+/// Settings, the units, exchange rates and constants added to a Factory, written as a script of the same commands typed into the calculator.
+///
+/// Lines are run in order when loaded. Blank lines and lines starting with # are skipped.
+/// Settings come first so conversions like "-> frac" are read back the same.
+/// ```text
+/// # Settings
+/// angle degree
+/// precision 4
+///
+/// # Units
+/// prefix kibi- (Ki-) = 1024
+/// define furlong = 201.168 m
+///
+/// # Exchange rates
+/// rate base,USD
+/// rate EUR,0.92
+///
+/// # Constants
+/// v = 30 km/h
+/// ```
+impl Factory {
+	pub fn save_session(&self) -> String {
+		let settings = self.settings();
+
+		let mut lines = vec![
+			"# Settings".to_string(),
+			format!("angle {}", settings.angle_unit.long()),
+			format!("rational {}", if settings.rational { "on" } else { "off" }),
+			format!("frac {}", settings.max_denominator),
+			format!("precision {}", settings.precision.map(|d| d.to_string()).unwrap_or_else(|| "off".to_string())),
+			format!("rounding {}", settings.rounding),
+			format!("notation {}", settings.notation),
+			format!("clock {}", settings.clock_format.map(|f| f.to_string()).unwrap_or_else(|| "auto".to_string())),
+			format!("months {}", month_length(settings.duration_months)),
+			String::new()
+		];
+
+		if !self.get_prefixes().is_empty() || !self.get_user_units().is_empty() {
+			lines.push("# Units".to_string());
+			lines.extend(self.get_prefixes().iter().map(|prefix| format!("prefix {}", prefix_definition(prefix))));
+			lines.extend(self.get_user_units().iter().map(|unit| format!("define {}", unit_definition(unit))));
+			lines.push(String::new());
+		}
+
+		let rates = self.exchange_rates();
+
+		if rates.base().is_some() {
+			lines.push("# Exchange rates".to_string());
+			lines.extend(rates.to_string().lines().map(|row| format!("rate {}", row)));
+			lines.push(String::new());
+		}
+
+		let mut constants = Vec::new();
+
+		for (name, value) in self.get_user_constants().iter().filter_map(|name| Some((name, self.find_const(name)?))) {
+			// Made with other settings. "months off", then "months average" after.
+			let changed = self.changed_settings(&value);
+
+			constants.extend(changed.iter().map(|(made_with, _)| made_with.clone()));
+			constants.push(format!("{} = {}", name, session_value(value)));
+			constants.extend(changed.into_iter().map(|(_, current)| current));
+		}
+
+		if !constants.is_empty() {
+			lines.push("# Constants".to_string());
+			lines.extend(constants);
+			lines.push(String::new());
+		}
+
+		lines.join("\n")
+	}
+
+	/// Settings a formatted value was made with which differ from the current ones, and the current ones.
+	fn changed_settings(&self, value: &Value) -> Vec<(String, String)> {
+		let settings = self.settings();
+
+		match value {
+			Value::Formatted(_, NumberFormat::Humanized(format)) if format.months != settings.duration_months => {
+				vec![(format!("months {}", month_length(format.months)), format!("months {}", month_length(settings.duration_months)))]
+			}
+			Value::Formatted(q, NumberFormat::Fraction) => match q.exact().map(|exact| exact.denom()) {
+				Some(denom) if denom > settings.max_denominator => vec![(format!("frac {}", denom), format!("frac {}", settings.max_denominator))],
+				_ => Vec::new()
+			},
+			Value::List(items) => items.first().map(|item| self.changed_settings(item)).unwrap_or_default(),
+			_ => Vec::new()
+		}
+	}
+
+	/// Runs a script from `save_session`. Lines before an error are kept.
+	pub fn load_session(&mut self, script: &str) -> Result<()> {
+		for (index, line) in script.lines().enumerate() {
+			let line = line.trim();
+
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+
+			self.run_session_line(line)
+				.map_err(|e| Error::Conversion(ConversionError::InvalidArgument(format!("Line {}: {}", index + 1, e))))?;
+		}
+
+		Ok(())
+	}
+
+	fn run_session_line(&mut self, line: &str) -> Result<()> {
+		// "define furlong = 201.168 m", "prefix kibi- (Ki-) = 1024"
+		if let Some(definition) = self.parse_definition(line) {
+			return self.add_definition(definition?);
+		}
+
+		// "v = 30 km/h"
+		if let Some((name, eval)) = line.split_once('=') {
+			let name = name.trim();
+
+			if !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+				let value = self.parse_value(eval)?;
+
+				return self.add_constant(name.to_string(), value);
+			}
+		}
+
+		// "rate EUR,0.92" adds to the rates. "rate base,USD" starts new ones.
+		if let Some(row) = line.strip_prefix("rate ") {
+			let row = row.trim();
+
+			let contents = if row.starts_with("base,") {
+				row.to_string()
+			} else {
+				format!("{}\n{}", self.exchange_rates(), row)
+			};
+
+			return self.load_exchange_rates(&contents).map(|_| ());
+		}
+
+		// "precision 4"
+		self.apply_setting(line)
+			.unwrap_or_else(|| Err(invalid(format!(r#"Expected "setting value" or "name = value" but found "{}""#, line))))
+	}
+
+	/// Changes a setting from a line like "precision 4". None if the line doesn't start with a setting name.
+	pub fn apply_setting(&mut self, line: &str) -> Option<Result<()>> {
+		let (setting, arg) = line.trim().split_once(char::is_whitespace)
+			.map(|(setting, arg)| (setting, arg.trim()))?;
+
+		if !SETTINGS.contains(&setting) {
+			return None;
+		}
+
+		Some(self.set_setting(setting, arg))
+	}
+
+	fn set_setting(&mut self, setting: &str, arg: &str) -> Result<()> {
+		match setting {
+			"angle" => self.set_angle_unit(arg),
+			"rational" => {
+				let enabled = match arg {
+					"on" => true,
+					"off" => false,
+					_ => return Err(invalid(format!(r#"Expected on or off, found "{}""#, arg)))
+				};
+
+				self.set_rational(enabled);
+
+				Ok(())
+			}
+			"frac" => self.set_max_denominator(arg.parse().map_err(|_| invalid(format!(r#"Expected a whole number, found "{}""#, arg)))?),
+			"precision" => match arg {
+				"off" => self.set_precision(None),
+				digits => self.set_precision(Some(digits.parse().map_err(|_| invalid(format!(r#"Expected a whole number or off, found "{}""#, digits)))?))
+			},
+			"rounding" => self.set_rounding(arg),
+			"notation" => self.set_notation(arg),
+			"clock" => self.set_clock_format(arg),
+			"months" => self.set_duration_months(arg),
+			_ => Err(invalid(format!(r#"Unknown setting "{}""#, setting)))
+		}
+	}
+}
+
+
+/// Settings changed with `apply_setting`.
+pub const SETTINGS: [&str; 8] = ["angle", "rational", "frac", "precision", "rounding", "notation", "clock", "months"];
+
+
+/// A value written so it's parsed back the same. Exact amounts stay fractions and nothing is rounded for display.
+fn session_value(value: Value) -> String {
+	match value {
+		// 3 in / 8 -> frac
+		Value::Formatted(q, NumberFormat::Fraction) => format!("{} -> frac", session_value(Value::Quantity(q))),
+		// weekday(1970-01-11). 1970-01-05 was a Monday.
+		Value::Formatted(q, NumberFormat::Weekday) => match q.amount().to_u32().filter(|day| (1..=7).contains(day)).and_then(|day| DateTime::from_ymd(1970, 1, 4 + day)) {
+			Some(date) => format!("weekday({})", date),
+			None => q.to_string()
+		},
+		Value::Formatted(q, NumberFormat::Duration) => clock_duration(&q),
+		// 93784 s -> dhms(2), (2027-01-01 - 2026-10-18) -> human
+		Value::Formatted(q, NumberFormat::Humanized(format)) => {
+			let name = if format.compact { "dhms" } else { "human" };
+
+			let value = match format.start {
+				Some(start) => format!("({})", session_value(Value::Formatted(q, NumberFormat::Elapsed(start)))),
+				None => session_value(Value::Quantity(q))
+			};
+
+			match format.max_parts {
+				Some(count) => format!("{} -> {}({})", value, name, count),
+				None => format!("{} -> {}", value, name)
+			}
+		}
+		// 2027-01-01 - 2026-10-18, 2026-10-18T09:30 - 2026-10-18
+		Value::Formatted(q, NumberFormat::Elapsed(start)) => match start.add_seconds(q.total_amount()) {
+			Some(end) if (end.seconds() % Decimal::from(86_400)).is_zero() => format!("{} - {}", end, start),
+			Some(end) => format!("{} - {}", end.with_time(), start),
+			None => session_value(Value::Quantity(q))
+		},
+		value => plain_session_value(value)
+	}
+}
+
+fn plain_session_value(value: Value) -> String {
+	match value.into_plain() {
+		Value::Quantity(q) => match q.exact().filter(|r| !r.is_integer()) {
+			// 1 m / 3
+			Some(exact) => match q.unit() {
+				Ok(unit) => format!("{} {} / {}", exact.numer(), unit, exact.denom()),
+				Err(_) => exact.to_string()
+			},
+			None => q.to_string()
+		},
+		Value::Uncertain(q, uncertainty) => match q.unit() {
+			Ok(unit) => format!("{} ± {} {}", q.amount(), uncertainty, unit),
+			Err(_) => format!("{} ± {}", q.amount(), uncertainty)
+		},
+		// Polar form rounds the angle. 4.330127… + 2.5i -> polar
+		Value::Complex(c, unit, form) => {
+			let value = Value::Complex(c, unit, ComplexForm::Rectangular).to_string();
+
+			match form {
+				ComplexForm::Rectangular => value,
+				ComplexForm::Polar => format!("{} -> polar", value)
+			}
+		}
+		// The instant then the zone, so times repeated when the clocks go back stay the same. 2026-10-18T07:30Z -> Europe/Berlin
+		Value::DateTime(date) => match date.zone() {
+			Some(zone @ TimeZone::Named(_)) => match date.with_zone(TimeZone::Fixed(0)) {
+				Some(utc) => format!("{} -> {}", utc, zone),
+				None => date.to_string()
+			},
+			_ => date.to_string()
+		},
+		Value::List(items) => format!("[{}]", items.into_iter().map(session_value).collect::<Vec<_>>().join(", ")),
+		value => value.to_string()
+	}
+}
+
+fn month_length(months: Option<MonthLength>) -> String {
+	months.map(|m| m.to_string()).unwrap_or_else(|| "off".to_string())
+}
+
+/// Hours, minutes and seconds, which are parsed as a duration. 1:30:00, -0:45:30.5
+fn clock_duration(value: &Quantity) -> String {
+	let total = value.total_amount();
+	let seconds = total.abs();
+
+	let hours = (seconds / Decimal::from(3600)).trunc();
+	let minutes = ((seconds - hours * Decimal::from(3600)) / Decimal::from(60)).trunc();
+	let seconds = (seconds - hours * Decimal::from(3600) - minutes * Decimal::from(60)).normalize();
+
+	format!(
+		"{}{}:{:02}:{}{}",
+		if total.is_sign_negative() { "-" } else { "" },
+		hours.normalize(),
+		minutes.to_u32().unwrap_or_default(),
+		if seconds < Decimal::TEN { "0" } else { "" },
+		seconds
+	)
+}
+
+fn invalid(message: String) -> Error {
+	Error::Conversion(ConversionError::InvalidArgument(message))
+}
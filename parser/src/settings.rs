@@ -0,0 +1,157 @@
+use std::fmt;
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use conversion::{BaseUnit, MonthLength};
+use conversion::units::Radian;
+
+
+/// Session settings which change how input is evaluated.
+#[derive(Debug, Clone)]
+pub struct Settings {
+	/// Unit given to bare numbers passed into trig functions.
+	pub angle_unit: Box<dyn BaseUnit>,
+	/// Exact results are shown as fractions. 1/3 + 1/6 = 1/2
+	pub rational: bool,
+	/// Largest denominator used by "-> frac".
+	pub max_denominator: i128,
+	/// Digits results are rounded to. Decimal places, or significant figures for the other notations.
+	pub precision: Option<u32>,
+	pub rounding: Rounding,
+	pub notation: Notation,
+	/// How times of day are shown. None shows them as they were written.
+	pub clock_format: Option<ClockFormat>,
+	/// Months used by "-> human". None skips months.
+	pub duration_months: Option<MonthLength>
+}
+
+impl Settings {
+	/// Rounds a result for display. Trailing zeros are removed.
+	pub fn round(&self, value: Decimal) -> Decimal {
+		let strategy = self.rounding.strategy();
+
+		match (self.precision, self.notation) {
+			(Some(places), Notation::Fixed) => value.round_dp_with_strategy(places, strategy).normalize(),
+			(Some(digits), _) => value.round_sf_with_strategy(digits, strategy).unwrap_or(value).normalize(),
+			(None, _) => value
+		}
+	}
+}
+
+impl Default for Settings {
+	fn default() -> Self {
+		Settings {
+			angle_unit: Box::new(Radian),
+			rational: false,
+			max_denominator: 1000,
+			precision: None,
+			rounding: Rounding::HalfEven,
+			notation: Notation::Fixed,
+			clock_format: None,
+			duration_months: None
+		}
+	}
+}
+
+
+/// How results are rounded to the precision.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rounding {
+	/// 2.5 -> 2, 3.5 -> 4
+	HalfEven,
+	/// 2.5 -> 3, -2.5 -> -3
+	HalfUp,
+	/// 2.9 -> 2, -2.9 -> -2
+	TowardZero
+}
+
+impl Rounding {
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"even" | "half-even" => Some(Rounding::HalfEven),
+			"up" | "half-up" => Some(Rounding::HalfUp),
+			"zero" | "toward-zero" => Some(Rounding::TowardZero),
+			_ => None
+		}
+	}
+
+	fn strategy(self) -> RoundingStrategy {
+		match self {
+			Rounding::HalfEven => RoundingStrategy::MidpointNearestEven,
+			Rounding::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+			Rounding::TowardZero => RoundingStrategy::ToZero
+		}
+	}
+}
+
+impl fmt::Display for Rounding {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Rounding::HalfEven => f.write_str("half-even"),
+			Rounding::HalfUp => f.write_str("half-up"),
+			Rounding::TowardZero => f.write_str("toward-zero")
+		}
+	}
+}
+
+
+/// How results are written.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Notation {
+	/// 12500.25
+	Fixed,
+	/// 12500 with a precision of 3
+	Significant,
+	/// 12.5e3
+	Engineering
+}
+
+impl Notation {
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"fixed" => Some(Notation::Fixed),
+			"sigfig" | "significant" => Some(Notation::Significant),
+			"eng" | "engineering" => Some(Notation::Engineering),
+			_ => None
+		}
+	}
+}
+
+impl fmt::Display for Notation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Notation::Fixed => f.write_str("fixed"),
+			Notation::Significant => f.write_str("sigfig"),
+			Notation::Engineering => f.write_str("engineering")
+		}
+	}
+}
+
+
+/// How a time of day is shown.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClockFormat {
+	/// 17:45
+	TwentyFourHour,
+	/// 5:45pm
+	TwelveHour
+}
+
+impl ClockFormat {
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"24" | "24h" => Some(ClockFormat::TwentyFourHour),
+			"12" | "12h" => Some(ClockFormat::TwelveHour),
+			_ => None
+		}
+	}
+}
+
+impl fmt::Display for ClockFormat {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ClockFormat::TwentyFourHour => f.write_str("24h"),
+			ClockFormat::TwelveHour => f.write_str("12h")
+		}
+	}
+}
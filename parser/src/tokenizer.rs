@@ -1,26 +1,36 @@
 use std::ops::Range;
 
 use regex::Regex;
+use rust_decimal::Decimal;
 
-use crate::{ExprToken, TokenType, Operator, Result, Factory};
+use conversion::TimeZone;
+use conversion::units::CURRENCY_SYMBOLS;
+
+use crate::{ExprToken, TokenType, Operator, Result, Error, Factory};
 
 pub type Id<T> = (&'static str, T);
 
-pub static DOUBLE_CHAR_TOKENS: [Id<ExprToken>; 6] = [
+pub static TRIPLE_CHAR_TOKENS: [Id<ExprToken>; 1] = [
+	("+/-", ExprToken::Operator(Operator::PlusMinus))
+];
+
+pub static DOUBLE_CHAR_TOKENS: [Id<ExprToken>; 7] = [
 	("->", ExprToken::Operator(Operator::ConvertInto)),
 	("<=", ExprToken::Operator(Operator::LessThanOrEqual)),
 	(">=", ExprToken::Operator(Operator::GreaterThanOrEqual)),
 	("!=", ExprToken::Operator(Operator::DoesNotEqual)),
 	("~=", ExprToken::Operator(Operator::ApproxEqual)),
-	("==", ExprToken::Operator(Operator::DoubleEqual))
+	("==", ExprToken::Operator(Operator::DoubleEqual)),
+	("..", ExprToken::Operator(Operator::Range))
 ];
 
-pub static SINGLE_CHAR_TOKENS: [Id<ExprToken>; 16] = [
+pub static SINGLE_CHAR_TOKENS: [Id<ExprToken>; 19] = [
 	(",", ExprToken::Comma),
+	(";", ExprToken::Semicolon),
 	("(", ExprToken::StartGrouping),
 	(")", ExprToken::EndGrouping),
-	("[", ExprToken::StartGrouping),
-	("]", ExprToken::EndGrouping),
+	("[", ExprToken::StartList),
+	("]", ExprToken::EndList),
 	("{", ExprToken::StartGrouping),
 	("}", ExprToken::EndGrouping),
 	("+", ExprToken::Operator(Operator::Plus)),
@@ -31,7 +41,9 @@ pub static SINGLE_CHAR_TOKENS: [Id<ExprToken>; 16] = [
 	("*", ExprToken::Operator(Operator::Multiply)),
 	("/", ExprToken::Operator(Operator::Divide)),
 	("%", ExprToken::Operator(Operator::Division)),
-	("^", ExprToken::Operator(Operator::Caret))
+	("^", ExprToken::Operator(Operator::Caret)),
+	("∠", ExprToken::Operator(Operator::Angle)),
+	("±", ExprToken::Operator(Operator::PlusMinus))
 ];
 
 pub type RangedType = Range<usize>;
@@ -58,6 +70,11 @@ impl<'a> Tokenizer<'a> {
 		self.compiled.iter().map(|(_, e)| e.clone()).collect()
 	}
 
+	/// What's left to tokenize. All of it before `parse`.
+	pub fn get_remaining_str(&self) -> &str {
+		self.value.get_remaining_str()
+	}
+
 	pub fn get_compiled(&self) -> &[(RangedType, ExprToken)] {
 		self.compiled.as_ref()
 	}
@@ -70,9 +87,14 @@ impl<'a> Tokenizer<'a> {
 		while !self.value.is_finished() {
 			let found = None
 				.or_else(|| self.remove_non_essiential())
+				.or_else(|| self.parse_tokens(&TRIPLE_CHAR_TOKENS))
 				.or_else(|| self.parse_tokens(&DOUBLE_CHAR_TOKENS))
 				.or_else(|| self.parse_tokens(&SINGLE_CHAR_TOKENS))
+				.or_else(|| self.parse_date())
+				.or_else(|| self.parse_clock())
+				.or_else(|| self.parse_currency())
 				.or_else(|| self.parse_number())
+				.or_else(|| self.parse_zone())
 				.or_else(|| self.parse_literal());
 
 			if let Some(found) = found {
@@ -80,6 +102,15 @@ impl<'a> Tokenizer<'a> {
 			} else {
 				print_dbg!("Unable to finish. Stopped at: {:?}", self.value.get_remaining_str());
 
+				// Numbers larger than a Decimal holds. 1e30
+				let remains = self.value.get_remaining_str();
+
+				if remains.starts_with(|c: char| c.is_ascii_digit()) {
+					let number = remains.split(char::is_whitespace).next().unwrap_or(remains);
+
+					return Err(Error::Conversion(conversion::Error::InvalidArgument(format!("{} is too large", number))));
+				}
+
 				break;
 			}
 		}
@@ -96,33 +127,111 @@ impl<'a> Tokenizer<'a> {
 		}
 	}
 
-	fn parse_number(&mut self) -> ParseResult {
+	/// Kept as a Literal for the Parser. 2026-10-18, 2026-10-18T09:30:15, 2026-10-18T09:30Z
+	fn parse_date(&mut self) -> ParseResult {
 		let start_pos = self.value.pos;
 		let remains = self.value.get_remaining_str();
 
-		let builder = Regex::new(r#"^((?:[0-9,]+)?\.?(?:e-?)?(?:[0-9]+)?)"#).unwrap();
+		let builder = Regex::new(r#"^[0-9]{4}-[0-9]{2}-[0-9]{2}(?:T[0-9]{2}:[0-9]{2}(?::[0-9]{2}(?:\.[0-9]+)?)?(?:Z\b|[+-][0-9]{2}:?[0-9]{2}\b)?)?"#).unwrap();
 
-		if let Some(found) = builder.find(remains) {
-			let mut end = found.end();
+		let found = builder.find(remains)?.as_str().to_string();
 
-			if end != 0 {
-				let num  = {
-					// If ending has a comma remove it.
-					if remains.get(end - 1..end) == Some(",") {
-						end -= 1;
-					}
+		self.value.consume_amount(found.len());
+
+		Some((start_pos..start_pos + found.len(), ExprToken::Literal(found)))
+	}
+
+	/// Kept as a Literal for the Parser. 9:30am, 17:45, 1:30:00
+	fn parse_clock(&mut self) -> ParseResult {
+		let start_pos = self.value.pos;
+		let remains = self.value.get_remaining_str();
 
-					let number = remains.get(0..end).unwrap();
+		let builder = Regex::new(r#"^[0-9]+:[0-9]{2}(?::[0-9]{2}(?:\.[0-9]+)?)?(?: ?(?i:am|pm)\b)?"#).unwrap();
 
-					number.replace(",", "").parse().unwrap()
-				};
+		let found = builder.find(remains)?.as_str().to_string();
 
-				self.value.consume_amount(end);
+		self.value.consume_amount(found.len());
 
-				Some((start_pos..start_pos + end, ExprToken::Number(num)))
-			} else {
-				None
+		Some((start_pos..start_pos + found.len(), ExprToken::Literal(found)))
+	}
+
+	/// Kept as a Literal for the Parser. Europe/Berlin, UTC+5:30
+	/// An "in" before a time zone is a conversion. now in Tokyo
+	fn parse_zone(&mut self) -> ParseResult {
+		let start_pos = self.value.pos;
+		let remains = self.value.get_remaining_str();
+
+		let builder = Regex::new(r#"^(?:(?i:utc|gmt)[+-][0-9]{1,2}(?::?[0-9]{2})?\b|[A-Za-z_]+(?:/[A-Za-z0-9_+\-]+)+)"#).unwrap();
+
+		if let Some(found) = builder.find(remains).map(|m| m.as_str().to_string()).filter(|name| TimeZone::from_name(name).is_some()) {
+			self.value.consume_amount(found.len());
+
+			return Some((start_pos..start_pos + found.len(), ExprToken::Literal(found)));
+		}
+
+		let builder = Regex::new(r#"^in\s+((?i:utc|gmt)[+-][0-9:]+|[A-Za-z_]+(?:/[A-Za-z0-9_+\-]+)*)"#).unwrap();
+
+		let zone = builder.captures(remains)?.get(1)?.as_str();
+
+		if TimeZone::from_name(zone).is_none() || self.factory.find_unit(zone).is_some() {
+			return None;
+		}
+
+		self.value.consume_amount(2);
+
+		Some((start_pos..start_pos + 2, ExprToken::Operator(Operator::ConvertInto)))
+	}
+
+	/// Symbols before an amount are placed after it as the currency. $1049 -> 1049 USD
+	fn parse_currency(&mut self) -> ParseResult {
+		let start_pos = self.value.pos;
+		let remains = self.value.get_remaining_str();
+
+		let (symbol, code) = CURRENCY_SYMBOLS.iter().find(|(symbol, _)| remains.starts_with(symbol))?;
+
+		if !remains[symbol.len()..].starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+			return None;
+		}
+
+		self.value.consume_amount(symbol.len());
+
+		match self.parse_number() {
+			Some(amount) => self.compiled.push(amount),
+			None => {
+				self.value.pos = start_pos;
+				return None;
 			}
+		}
+
+		Some((start_pos..start_pos + symbol.len(), ExprToken::Literal(code.to_string())))
+	}
+
+	fn parse_number(&mut self) -> ParseResult {
+		let start_pos = self.value.pos;
+		let remains = self.value.get_remaining_str();
+
+		let builder = Regex::new(r#"^(?:[0-9]+(?:,[0-9]{3})*)?(?:\.[0-9]+)?(?:e-?[0-9]+)?"#).unwrap();
+
+		let mut end = builder.find(remains).map(|f| f.end()).unwrap_or_default();
+
+		// Commas are only thousands separators when followed by exactly three digits.
+		// "[1,2,3]" and "1,2345" will stop at the first comma.
+		if remains[end..].starts_with(|c: char| c.is_ascii_digit()) {
+			end = remains.find(',').unwrap_or(end);
+		}
+
+		let number = remains.get(0..end).unwrap().replace(",", "");
+
+		if number.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+			let num = if number.contains('e') {
+				Decimal::from_scientific(&number).ok()?
+			} else {
+				number.parse().ok()?
+			};
+
+			self.value.consume_amount(end);
+
+			Some((start_pos..start_pos + end, ExprToken::Number(num)))
 		} else {
 			None
 		}
@@ -132,22 +241,29 @@ impl<'a> Tokenizer<'a> {
 		let start_pos = self.value.pos;
 		let remains = self.value.get_remaining_str();
 
-		let builder = Regex::new(r#"^([^\d\s\(\)\[\]\{\}]+)"#).unwrap();
+		// Digits are allowed after the first character. log2, atan2
+		let builder = Regex::new(r#"^([^\d\s\(\)\[\]\{\},;+\-*=<>^%!~∠±][^\s\(\)\[\]\{\},;+\-*=<>^%!~∠±]*)"#).unwrap();
 
 		if let Some(found) = builder.find(remains) {
-			let end = found.end();
+			let found = found.as_str();
+
+			// Intervals end the literal. [1 kg..2 kg]
+			let interval = found.find("..");
+
+			// Dividing by a number ends the literal. PI/2, km/2h
+			let divide = found.match_indices('/')
+				.map(|(pos, _)| pos)
+				.find(|pos| found[pos + 1..].starts_with(|c: char| c.is_ascii_digit() || c == '.'));
+
+			let end = interval.into_iter().chain(divide).min().unwrap_or(found.len());
 
 			if end != 0 {
-				let found = found.as_str().to_string();
+				let found = remains[..end].to_string();
 
 				self.value.consume_amount(end);
 
-				// Check if it's a const.
-				if let Some(item) = self.factory.find_const(found.as_str()) {
-					Some((start_pos..start_pos + end, ExprToken::Number(item)))
-				} else {
-					Some((start_pos..start_pos + end, ExprToken::Literal(found)))
-				}
+				// Constants are resolved by the Parser. They can have units.
+				Some((start_pos..start_pos + end, ExprToken::Literal(found)))
 			} else {
 				None
 			}
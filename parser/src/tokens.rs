@@ -2,15 +2,18 @@ use std::fmt;
 
 use rust_decimal::Decimal;
 
-use crate::operations::{ExpressionArg, Divide, Multiply, Add, Subtract, Conversion, Comparison};
+use crate::operations::{ExpressionArg, Divide, Multiply, Exponentiate, Polar, Uncertainty, Add, Subtract, Conversion, Comparison};
 
 
 #[derive(Debug, PartialEq)]
 pub enum TokenType {
 	Comma,
+	Semicolon,
 	Whitespace,
 	StartGrouping,
 	EndGrouping,
+	StartList,
+	EndList,
 
 	Number,
 	Operator,
@@ -25,9 +28,12 @@ impl PartialEq<ExprToken> for TokenType {
 	fn eq(&self, other: &ExprToken) -> bool {
 		match (self, other) {
 			(Self::Comma, ExprToken::Comma) |
+			(Self::Semicolon, ExprToken::Semicolon) |
 			(Self::Whitespace, ExprToken::Whitespace) |
 			(Self::StartGrouping, ExprToken::StartGrouping) |
 			(Self::EndGrouping, ExprToken::EndGrouping) |
+			(Self::StartList, ExprToken::StartList) |
+			(Self::EndList, ExprToken::EndList) |
 
 			(Self::Number, ExprToken::Number(_)) |
 			(Self::Operator, ExprToken::Operator(_)) |
@@ -57,6 +63,12 @@ pub enum Operator {
 	Division,
 	Multiply,
 	Caret,
+	/// 5∠30°
+	Angle,
+	/// 5.00 ± 0.02 m
+	PlusMinus,
+	/// [10 kg .. 12 kg]
+	Range,
 
 	ConvertInto,
 
@@ -112,13 +124,30 @@ impl Operator {
 			}
 
 			Operator::Caret => {
-				panic!("Caret's are not implemented currently.")
-				// Box::new(
-				// 	Exponentiate::new(
-				// 		left,
-				// 		right
-				// 	)
-				// )
+				Box::new(
+					Exponentiate::new(
+						left,
+						right
+					)
+				)
+			}
+
+			Operator::Angle => {
+				Box::new(
+					Polar::new(
+						left,
+						right
+					)
+				)
+			}
+
+			Operator::PlusMinus => {
+				Box::new(
+					Uncertainty::new(
+						left,
+						right
+					)
+				)
 			}
 
 			Operator::ConvertInto => {
@@ -159,6 +188,9 @@ impl fmt::Display for Operator {
 			Operator::Division => f.write_str("%"),
 			Operator::Multiply => f.write_str("*"),
 			Operator::Caret => f.write_str("^"),
+			Operator::Angle => f.write_str("∠"),
+			Operator::PlusMinus => f.write_str("±"),
+			Operator::Range => f.write_str(".."),
 
 			Operator::ConvertInto => f.write_str("->"),
 
@@ -178,9 +210,12 @@ impl fmt::Display for Operator {
 #[derive(Debug, Clone, PartialEq)]
 pub enum ExprToken {
 	Comma,
+	Semicolon,
 	Whitespace,
 	StartGrouping,
 	EndGrouping,
+	StartList,
+	EndList,
 
 	Number(Decimal),
 	Operator(Operator),
@@ -250,9 +285,12 @@ impl fmt::Display for ExprToken {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
 			ExprToken::Comma => f.write_str(","),
+			ExprToken::Semicolon => f.write_str(";"),
 			ExprToken::Whitespace => f.write_str(" "),
 			ExprToken::StartGrouping => f.write_str("("),
 			ExprToken::EndGrouping => f.write_str(")"),
+			ExprToken::StartList => f.write_str("["),
+			ExprToken::EndList => f.write_str("]"),
 			ExprToken::Number(v) => v.fmt(f),
 			ExprToken::Operator(o) => o.fmt(f),
 			ExprToken::Literal(l) => f.write_str(l)
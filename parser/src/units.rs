@@ -1,12 +1,12 @@
 use rust_decimal::Decimal;
 
 use conversion::units::*;
+use conversion::quantity::transcendental::PRECISION;
 
 use crate::{Value, Result, Error};
-use crate::operations::ExpressionArg;
 
 pub fn default_units() -> Vec<Box<dyn BaseUnit>> {
-	vec![
+	let mut units: Vec<Box<dyn BaseUnit>> = vec![
 		// TIME
 		Box::new(Second),
 		Box::new(NanoSecond),
@@ -78,21 +78,26 @@ pub fn default_units() -> Vec<Box<dyn BaseUnit>> {
 
 		Box::new(Pound),
 		Box::new(Ounce),
-	]
-}
-
-
-pub fn can_operate(one: &ExpressionArg, two: &ExpressionArg) -> bool {
-	let eval_1 = one.eval().unwrap();
-	let eval_2 = two.eval().unwrap();
 
-	match (eval_1.into_base_unit(), eval_2.into_base_unit()) {
-		(Some(_), None) |
-		(None, Some(_)) |
-		(None, None) => true,
-
-		(Some(u1), Some(u2)) => u1 == u2
-	}
+		// ANGLE
+		Box::new(Radian),
+		Box::new(Degree),
+		Box::new(Gradian),
+		Box::new(Turn),
+		Box::new(ArcMinute),
+		Box::new(ArcSecond),
+
+		// OTHER SI BASE UNITS
+		Box::new(Ampere),
+		Box::new(Kelvin),
+		Box::new(Mole),
+		Box::new(Candela),
+	];
+
+	// CURRENCY
+	units.extend(CURRENCIES.iter().map(|currency| Box::new(currency.clone()) as Box<dyn BaseUnit>));
+
+	units
 }
 
 
@@ -105,21 +110,38 @@ pub fn convert(from: &Value, to: &Value) -> Result<Decimal> {
 	};
 
 	if is_convertable(from_unit, to_unit) {
-		let mut val = from.amount().unwrap();
+		let (from_num, from_den) = from_unit.factor_parts();
+		let (to_num, to_den) = to_unit.factor_parts();
+
+		let amount = from.amount().unwrap();
+		let (multiplier, divisor) = (from_num * to_den, from_den * to_num);
 
-		if from_unit.is_base_equal(to_unit) {
-			val = val * from_unit.base().factor_amount() / to_unit.base().factor_amount();
-		}
+		// Multiply before dividing to keep precision. 36 km/h -> m/s = 10
+		// Small amounts divide first so they don't drop digits past Decimal's 28 decimal places. 1.67262192595e-12 pg -> ng
+		let val = if underflows(amount, multiplier) {
+			let divided = amount / divisor;
 
-		if from_unit.is_base_2_equal(to_unit) {
-			let factor_1 = from_unit.base_2().map(|b| b.factor_amount()).unwrap_or_else(|| Decimal::new(1, 0));
-			let factor_2 = to_unit.base_2().map(|b| b.factor_amount()).unwrap_or_else(|| Decimal::new(1, 0));
+			if underflows(divided, multiplier) {
+				return Err(Error::Conversion(conversion::Error::InvalidArgument(format!("{} is too small to convert to {}", from, to_unit))));
+			}
 
-			val = (val / factor_1) * factor_2;
-		}
+			divided * multiplier
+		} else {
+			amount * multiplier / divisor
+		};
+
+		// Irrational factors (deg -> rad) leave noise in the last digits.
+		let val = val.round_sf(PRECISION).unwrap_or(val).normalize();
 
 		Ok(val)
 	} else {
 		Err(Error::UnableToConvertValues(from_unit.long(), to_unit.long()))
 	}
-}
\ No newline at end of file
+}
+
+/// Multiplying drops digits past the 28th decimal place. 9.1093837139e-16 * 1e-15
+fn underflows(amount: Decimal, factor: Decimal) -> bool {
+	let product = amount * factor;
+
+	product.abs() < Decimal::ONE && product / factor != amount
+}
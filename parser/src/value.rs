@@ -1,20 +1,198 @@
 use std::fmt;
 
 use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
 
-use conversion::{Quantity, Units};
+use conversion::{Quantity, Units, Complex, Rational, DateTime, DurationFormat, is_convertable};
+use conversion::units::{Second, Day};
+use conversion::datetime::WEEKDAYS;
+use conversion::quantity::transcendental::{self, PI};
 
-use crate::{Result, Error, ExprToken, Operator};
+use crate::{Result, Error, ExprToken, Operator, Settings, Notation, ClockFormat};
 use crate::units::convert;
+use crate::dates::{out_of_range, unknown_zone};
+use crate::currency::round_money;
+
+
+/// Digits shown for the magnitude and angle of polar values.
+const POLAR_PRECISION: u32 = 20;
 
 
 #[derive(Debug)]
 pub enum Value {
 	Quantity(Quantity),
-	Unit(Units)
+	Unit(Units),
+	List(Vec<Value>),
+	/// 3 + 4i, (3 + 4i) Ω
+	Complex(Complex, Option<Units>, ComplexForm),
+	/// A Quantity shown in another format. 2 3/8 in, 12.5e3 m
+	Formatted(Quantity, NumberFormat),
+	/// A measured value and its standard uncertainty in the same units. 5.00 ± 0.02 m
+	Uncertain(Quantity, Decimal),
+	/// Lower and upper bounds in the same units. [10 kg .. 12 kg]
+	Interval(Quantity, Quantity),
+	/// 2026-10-18, 2026-10-18T09:30
+	DateTime(DateTime),
+	/// Seconds since midnight. 9:30am, 17:45
+	Time(Decimal, ClockFormat)
+}
+
+/// How a formatted Quantity is shown.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberFormat {
+	/// The exact amount as a mixed fraction. 2 3/8
+	Fraction,
+	/// Exponents are a multiple of 3. 12.5e3
+	Engineering,
+	/// The name of an ISO weekday number. 7 = Sunday
+	Weekday,
+	/// Split into hours, minutes and seconds. 9 h 35 min
+	Duration,
+	/// Split into named units. 1 day 2 hours 3 minutes 4 seconds, 1d 2h 3m 4s
+	Humanized(DurationFormat),
+	/// The time since a date. Shown as the plain quantity. 2027-01-01 - 2026-10-18
+	Elapsed(DateTime)
+}
+
+/// How a complex value is shown.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComplexForm {
+	/// 3 + 4i
+	Rectangular,
+	/// 5 ∠ 53.13°
+	Polar
+}
+
+impl ComplexForm {
+	/// Name used after "->". 3 + 4i -> polar
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"rect" | "rectangular" => Some(ComplexForm::Rectangular),
+			"polar" => Some(ComplexForm::Polar),
+			_ => None
+		}
+	}
+
+	/// Polar if either is.
+	fn combine(self, other: ComplexForm) -> Self {
+		if self == ComplexForm::Polar || other == ComplexForm::Polar {
+			ComplexForm::Polar
+		} else {
+			ComplexForm::Rectangular
+		}
+	}
 }
 
 impl Value {
+	/// Formatted values become a Quantity again. Used before operating on values.
+	pub fn into_plain(self) -> Value {
+		match self {
+			Value::Formatted(q, _) => Value::Quantity(q),
+			Value::List(items) => Value::List(items.into_iter().map(Value::into_plain).collect()),
+			value => value
+		}
+	}
+
+	fn is_duration(&self) -> bool {
+		matches!(self, Value::Formatted(_, NumberFormat::Duration))
+	}
+
+	/// Quantities are shown split into hours, minutes and seconds.
+	fn into_duration(self) -> Value {
+		match self {
+			Value::Quantity(q) => Value::Formatted(q, NumberFormat::Duration),
+			value => value
+		}
+	}
+
+	/// Shown as the closest fraction with a denominator no larger than `max_denominator`.
+	/// Values are kept as a fraction even if it's a whole number. 0.3125 in -> 5/16 in
+	pub fn into_fraction(self, max_denominator: i128) -> Result<Value> {
+		match self.into_plain() {
+			Value::Quantity(q) => {
+				let exact = q.exact()
+					.or_else(|| Rational::from_decimal(q.amount()))
+					.and_then(|r| r.limit_denominator(max_denominator))
+					.ok_or_else(|| Error::Conversion(conversion::Error::InvalidArgument(format!("{} can't be shown as a fraction", q.amount()))))?;
+
+				let unit = q.into_unit();
+
+				Quantity::from_rational(exact, unit)
+					.map(|q| Value::Formatted(q, NumberFormat::Fraction))
+					.ok_or_else(|| Error::Conversion(conversion::Error::InvalidArgument(format!("{} can't be shown as a fraction", exact))))
+			}
+
+			Value::List(items) => Ok(Value::List(items.into_iter().map(|v| v.into_fraction(max_denominator)).collect::<Result<_>>()?)),
+
+			Value::Complex(..) => Err(Error::ExpectedQuantity),
+
+			value => Ok(value)
+		}
+	}
+
+	/// Durations split into years down to seconds. 93784 s -> human = 1 day 2 hours 3 minutes 4 seconds
+	/// Differences of dates keep their start for calendar months.
+	pub fn into_humanized(self, mut format: DurationFormat) -> Result<Value> {
+		match self {
+			Value::Formatted(_, NumberFormat::Elapsed(start)) => format.start = Some(start),
+			Value::Formatted(_, NumberFormat::Humanized(previous)) => format.start = previous.start,
+			_ => ()
+		}
+
+		match self.into_plain() {
+			Value::Quantity(q) => {
+				q.humanize_parts(&format)?;
+
+				Ok(Value::Formatted(q, NumberFormat::Humanized(format)))
+			}
+
+			Value::List(items) => Ok(Value::List(items.into_iter().map(|v| v.into_humanized(format)).collect::<Result<_>>()?)),
+
+			_ => Err(Error::ExpectedQuantity)
+		}
+	}
+
+	/// Values which are still exact and not whole numbers are shown as fractions. 1/3 + 1/6 = 1/2
+	pub fn into_exact_fraction(self) -> Value {
+		match self {
+			Value::Quantity(q) => match q.exact() {
+				Some(exact) if !exact.is_integer() => Value::Formatted(q, NumberFormat::Fraction),
+				_ => Value::Quantity(q)
+			},
+
+			Value::List(items) => Value::List(items.into_iter().map(Value::into_exact_fraction).collect()),
+
+			value => value
+		}
+	}
+
+	/// Rounds to the session's precision and applies its notation. Fractions are left as they are.
+	pub fn into_display(self, settings: &Settings) -> Value {
+		match self {
+			Value::Quantity(mut q) => {
+				let amount = settings.round(q.amount());
+
+				// Keeps exact values exact.
+				if amount != q.amount() {
+					q.set_amount(amount);
+				}
+
+				match settings.notation {
+					Notation::Engineering => Value::Formatted(q, NumberFormat::Engineering),
+					_ => Value::Quantity(q)
+				}
+			}
+
+			Value::Complex(value, unit, form) => Value::new_complex(Complex::new(settings.round(value.re), settings.round(value.im)), unit, form),
+
+			Value::List(items) => Value::List(items.into_iter().map(|v| v.into_display(settings)).collect()),
+
+			Value::Time(time, format) => Value::Time(time, settings.clock_format.unwrap_or(format)),
+
+			value => value
+		}
+	}
+
 	pub fn new_quantity(value: Decimal) -> Value {
 		Value::Quantity(Quantity::new(value))
 	}
@@ -23,39 +201,110 @@ impl Value {
 		Value::Quantity(Quantity::new_unit(value, unit))
 	}
 
+	/// Values without an imaginary part are a Quantity.
+	pub fn new_complex(value: Complex, unit: Option<Units>, form: ComplexForm) -> Value {
+		if value.is_real() {
+			Value::Quantity(Quantity::new_unit(value.re, unit))
+		} else {
+			Value::Complex(Complex::new(value.re.normalize(), value.im.normalize()), unit, form)
+		}
+	}
+
+
+	/// The uncertainty is converted into the units of the value. A percentage is relative to the value.
+	pub fn new_uncertain(value: Quantity, uncertainty: Quantity) -> Result<Value> {
+		let sigma = match (value.unit(), uncertainty.unit()) {
+			(_, Ok(unit)) if unit.base() == "%" => value.amount() * uncertainty.amount() / Decimal::ONE_HUNDRED,
+			(Ok(to), Ok(from)) => {
+				if !is_convertable(from, to) {
+					return Err(Error::UnableToConvertValues(from.long(), to.long()));
+				}
+
+				uncertainty.total_amount() / to.total_factor()
+			}
+			_ => uncertainty.amount()
+		};
+
+		// 5.00 ± 0.02 m, the value uses the units of the uncertainty.
+		let value = match (value.unit(), uncertainty.unit()) {
+			(Err(_), Ok(unit)) if unit.base() != "%" => Quantity::new_unit(value.amount(), Some(unit.clone())),
+			_ => value
+		};
+
+		Ok(Value::Uncertain(value, sigma.abs()))
+	}
+
+
+	/// The upper bound is converted into the units of the lower bound. Equal bounds are a Quantity.
+	pub fn new_interval(lo: Quantity, hi: Quantity) -> Result<Value> {
+		Value::check_units(&lo, &hi)?;
+
+		// [10 .. 12 kg], the lower bound uses the units of the upper bound.
+		let (lo, hi) = match (lo.unit(), hi.unit()) {
+			(Ok(to), Ok(_)) => {
+				let hi = Quantity::new_unit(hi.total_amount() / to.total_factor(), Some(to.clone()));
+
+				(lo, hi)
+			}
+			(Err(_), Ok(unit)) => (Quantity::new_unit(lo.amount(), Some(unit.clone())), hi),
+			_ => (lo, hi)
+		};
+
+		let normalize = |mut q: Quantity| {
+			q.set_amount(q.amount().normalize());
+			q
+		};
+
+		let (lo, hi) = (normalize(lo), normalize(hi));
+
+		if lo.amount() == hi.amount() {
+			Ok(Value::Quantity(lo))
+		} else if lo.amount() > hi.amount() {
+			Ok(Value::Interval(hi, lo))
+		} else {
+			Ok(Value::Interval(lo, hi))
+		}
+	}
+
 
 	pub fn as_base_unit(&self) -> Result<&Units> {
 		Ok(match self {
-			Value::Quantity(q) => q.unit()?,
-			Value::Unit(u) => u
+			Value::Quantity(q) | Value::Formatted(q, _) | Value::Uncertain(q, _) | Value::Interval(q, _) => q.unit()?,
+			Value::Unit(u) => u,
+			Value::Complex(_, u, _) => u.as_ref().ok_or(Error::ExpectedQuantity)?,
+			Value::List(_) | Value::DateTime(_) | Value::Time(..) => return Err(Error::ExpectedQuantity)
 		})
 	}
 
 	pub fn clone_base_unit(&self) -> Result<Units> {
 		Ok(match self {
-			Value::Quantity(q) => q.unit().map(|i| i.clone())?,
-			Value::Unit(u) => u.clone()
+			Value::Quantity(q) | Value::Formatted(q, _) | Value::Uncertain(q, _) | Value::Interval(q, _) => q.unit().cloned()?,
+			Value::Unit(u) => u.clone(),
+			Value::Complex(_, u, _) => u.clone().ok_or(Error::ExpectedQuantity)?,
+			Value::List(_) | Value::DateTime(_) | Value::Time(..) => return Err(Error::ExpectedQuantity)
 		})
 	}
 
 	pub fn base_factor(&self) -> Decimal {
 		match self {
-			Value::Quantity(q) => q.unit().map(|u| u.base().factor_amount()).unwrap_or_else(|_| Decimal::new(1, 0)),
-			Value::Unit(u) => u.base().factor_amount()
+			Value::Quantity(q) | Value::Formatted(q, _) | Value::Uncertain(q, _) | Value::Interval(q, _) => q.unit().map(|u| u.total_factor()).unwrap_or_else(|_| Decimal::new(1, 0)),
+			Value::Unit(u) => u.total_factor(),
+			Value::Complex(_, u, _) => u.as_ref().map(|u| u.total_factor()).unwrap_or_else(|| Decimal::new(1, 0)),
+			Value::List(_) | Value::DateTime(_) | Value::Time(..) => Decimal::new(1, 0)
 		}
 	}
 
 	pub fn amount(&self) -> Option<Decimal> {
 		match self {
-			Value::Quantity(q) => Some(q.amount()),
-			Value::Unit(_) => None
+			Value::Quantity(q) | Value::Formatted(q, _) | Value::Uncertain(q, _) => Some(q.amount()),
+			_ => None
 		}
 	}
 
 	pub fn total_amount(&self) -> Option<Decimal> {
 		match self {
-			Value::Quantity(q) => Some(q.total_amount()),
-			Value::Unit(_) => None
+			Value::Quantity(q) | Value::Formatted(q, _) | Value::Uncertain(q, _) => Some(q.total_amount()),
+			_ => None
 		}
 	}
 
@@ -74,6 +323,160 @@ impl Value {
 			Value::Unit(unit) => {
 				tokens.push(ExprToken::Literal(unit.short()));
 			}
+
+			// 2 3/8 in
+			Value::Formatted(q, NumberFormat::Fraction) => {
+				let (whole, numer, denom) = mixed_fraction(&q);
+
+				if let Some(whole) = whole {
+					tokens.push(ExprToken::Number(whole));
+				}
+
+				if let Some((numer, denom)) = numer.zip(denom) {
+					tokens.push(ExprToken::Number(numer));
+					tokens.push(ExprToken::Operator(Operator::Divide));
+					tokens.push(ExprToken::Number(denom));
+				}
+
+				if let Some(unit) = q.into_unit() {
+					tokens.push(ExprToken::Literal(unit.short()));
+				}
+			}
+
+			// 12.5e3 m
+			Value::Formatted(q, NumberFormat::Engineering) => {
+				let (mantissa, exponent) = engineering_parts(q.amount());
+
+				tokens.push(ExprToken::Number(mantissa));
+
+				if exponent != 0 {
+					tokens.push(ExprToken::Literal(format!("e{}", exponent)));
+				}
+
+				if let Some(unit) = q.into_unit() {
+					tokens.push(ExprToken::Literal(unit.short()));
+				}
+			}
+
+			// 12.50 ± 0.07 m²
+			Value::Uncertain(q, uncertainty) => {
+				let (value, uncertainty) = uncertain_parts(q.amount(), uncertainty);
+
+				tokens.push(ExprToken::Number(value));
+				tokens.push(ExprToken::Operator(Operator::PlusMinus));
+				tokens.push(ExprToken::Number(uncertainty));
+
+				if let Some(unit) = q.into_unit() {
+					tokens.push(ExprToken::Literal(unit.short()));
+				}
+			}
+
+			// Sunday
+			Value::Formatted(q, NumberFormat::Weekday) => {
+				tokens.push(ExprToken::Literal(weekday_name(&q).to_string()));
+			}
+
+			// 75 d
+			Value::Formatted(q, NumberFormat::Elapsed(_)) => tokens.append(&mut Value::Quantity(q).into_tokens()),
+
+			// 9 h 35 min
+			Value::Formatted(q, NumberFormat::Duration) => {
+				for (amount, unit) in duration_parts(&q) {
+					tokens.push(ExprToken::Number(amount));
+					tokens.push(ExprToken::Literal(unit.to_string()));
+				}
+			}
+
+			// 1 day 2 hours, 1d 2h
+			Value::Formatted(q, NumberFormat::Humanized(format)) => match q.humanize_parts(&format) {
+				Ok(parts) => for (amount, name) in parts {
+					if format.compact {
+						tokens.push(ExprToken::Literal(format!("{}{}", amount, name)));
+					} else {
+						tokens.push(ExprToken::Number(amount));
+						tokens.push(ExprToken::Literal(name));
+					}
+				},
+				// Shown as the plain quantity, the same as Display.
+				Err(_) => tokens.append(&mut Value::Quantity(q).into_tokens())
+			},
+
+			Value::DateTime(date) => {
+				tokens.push(ExprToken::Literal(date.to_string()));
+			}
+
+			value @ Value::Time(..) => {
+				tokens.push(ExprToken::Literal(value.to_string()));
+			}
+
+			// [10 kg .. 12 kg]
+			Value::Interval(lo, hi) => {
+				tokens.push(ExprToken::StartList);
+				tokens.append(&mut Value::Quantity(lo).into_tokens());
+				tokens.push(ExprToken::Operator(Operator::Range));
+				tokens.append(&mut Value::Quantity(hi).into_tokens());
+				tokens.push(ExprToken::EndList);
+			}
+
+			// 5 Ω ∠ 53.13°
+			Value::Complex(value, unit, ComplexForm::Polar) => {
+				let (magnitude, angle) = polar_parts(&value);
+
+				tokens.push(ExprToken::Number(magnitude));
+
+				if let Some(unit) = unit {
+					tokens.push(ExprToken::Literal(unit.short()));
+				}
+
+				tokens.push(ExprToken::Operator(Operator::Angle));
+				tokens.push(ExprToken::Number(angle));
+				tokens.push(ExprToken::Literal("°".to_string()));
+			}
+
+			// 3 + 4i, (3 + 4i) m
+			Value::Complex(value, unit, ComplexForm::Rectangular) => {
+				let has_both = !value.re.is_zero() && !value.im.is_zero();
+				let wrap = has_both && unit.is_some();
+
+				if wrap {
+					tokens.push(ExprToken::StartGrouping);
+				}
+
+				if !value.re.is_zero() || value.im.is_zero() {
+					tokens.push(ExprToken::Number(value.re));
+				}
+
+				if has_both {
+					tokens.push(ExprToken::Operator(if value.im.is_sign_negative() { Operator::Minus } else { Operator::Plus }));
+					tokens.push(ExprToken::Number(value.im.abs()));
+					tokens.push(ExprToken::Literal("i".to_string()));
+				} else if !value.im.is_zero() {
+					tokens.push(ExprToken::Number(value.im));
+					tokens.push(ExprToken::Literal("i".to_string()));
+				}
+
+				if wrap {
+					tokens.push(ExprToken::EndGrouping);
+				}
+
+				if let Some(unit) = unit {
+					tokens.push(ExprToken::Literal(unit.short()));
+				}
+			}
+
+			Value::List(items) => {
+				tokens.push(ExprToken::StartList);
+
+				for (i, item) in items.into_iter().enumerate() {
+					if i != 0 {
+						tokens.push(ExprToken::Comma);
+					}
+
+					tokens.append(&mut item.into_tokens());
+				}
+
+				tokens.push(ExprToken::EndList);
+			}
 		}
 
 		tokens
@@ -81,22 +484,447 @@ impl Value {
 
 	pub fn into_quantity(self) -> Option<Quantity> {
 		match self {
-			Value::Quantity(q) => Some(q),
+			Value::Quantity(q) | Value::Formatted(q, _) => Some(q),
+			_ => None
+		}
+	}
+
+	/// Flattens Lists into their Quantities.
+	pub fn into_quantities(self) -> Result<Vec<Quantity>> {
+		match self {
+			Value::Quantity(q) | Value::Formatted(q, _) => Ok(vec![q]),
+			Value::List(items) => Ok(
+				items.into_iter()
+				.map(Value::into_quantities)
+				.collect::<Result<Vec<_>>>()?
+				.concat()
+			),
+			Value::Unit(_) | Value::Complex(..) | Value::Uncertain(..) | Value::Interval(..) | Value::DateTime(_) | Value::Time(..) => Err(Error::ExpectedQuantity)
+		}
+	}
+
+	/// Only changes complex values.
+	pub fn with_complex_form(self, form: ComplexForm) -> Value {
+		match self {
+			Value::Complex(value, unit, _) => Value::Complex(value, unit, form),
+			Value::List(items) => Value::List(items.into_iter().map(|v| v.with_complex_form(form)).collect()),
+			value => value
+		}
+	}
+
+	/// The value as a complex number. Real quantities have no imaginary part.
+	pub fn into_complex(self) -> Option<(Complex, Option<Units>, ComplexForm)> {
+		match self {
+			Value::Quantity(q) | Value::Formatted(q, _) => Some((Complex::real(q.amount()), q.into_unit(), ComplexForm::Rectangular)),
+			Value::Complex(c, u, form) => Some((c, u, form)),
 			_ => None
 		}
 	}
 
+	/// Real and imaginary parts as quantities with the same units.
+	fn into_parts(self) -> Option<(Quantity, Quantity, ComplexForm)> {
+		let (value, unit, form) = self.into_complex()?;
+
+		Some((Quantity::new_unit(value.re, unit.clone()), Quantity::new_unit(value.im, unit), form))
+	}
+
+	/// The imaginary part is converted into the units of the real part.
+	fn from_parts(re: Quantity, im: Quantity, form: ComplexForm) -> Value {
+		let unit = re.unit().or_else(|_| im.unit()).ok().cloned();
+
+		let im_amount = match (im.unit(), &unit) {
+			(Ok(from), Some(to)) if from != to => im.total_amount() / to.total_factor(),
+			_ => im.amount()
+		};
+
+		Value::new_complex(Complex::new(re.amount(), im_amount), unit, form)
+	}
+
+	/// (a + bi) op (c + di)
+	fn complex_operation(left: Value, right: Value, op: Operator) -> Result<Value> {
+		let ((a, b, l_form), (c, d, r_form)) = match (left.into_parts(), right.into_parts()) {
+			(Some(left), Some(right)) => (left, right),
+			_ => return Err(Error::UnableToOperateValues(op))
+		};
+
+		let form = l_form.combine(r_form);
+
+		print_dbg!("Complex: ({} + {}i) {} ({} + {}i)", a.amount(), b.amount(), op, c.amount(), d.amount());
+
+		match op {
+			Operator::Plus => {
+				Value::check_units(&a, &c)?;
+
+				Ok(Value::from_parts(a + c, b + d, form))
+			}
+
+			Operator::Minus => {
+				Value::check_units(&a, &c)?;
+
+				Ok(Value::from_parts(a - c, b - d, form))
+			}
+
+			Operator::Multiply => Ok(Value::from_parts(
+				a.clone() * c.clone() - b.clone() * d.clone(),
+				a * d + b * c,
+				form
+			)),
+
+			Operator::Divide => {
+				let scale = c.amount().abs().max(d.amount().abs());
+
+				if scale.is_zero() {
+					return Err(Error::Conversion(conversion::Error::DivideByZero));
+				}
+
+				// Scaled by the larger part first so very small divisors aren't zero once squared.
+				let unscaled = |q: Quantity| Quantity::new_unit(q.amount() / scale, q.into_unit());
+				let (c, d) = (unscaled(c), unscaled(d));
+
+				let denominator = (c.clone() * c.clone() + d.clone() * d.clone()) * Quantity::new_unit(scale, None);
+
+				Ok(Value::from_parts(
+					(a.clone() * c.clone() + b.clone() * d.clone()) / denominator.clone(),
+					(b * c - a * d) / denominator,
+					form
+				))
+			}
+
+			_ => Err(Error::UnableToOperateValues(op))
+		}
+	}
+
+	/// The value and its uncertainty. Other quantities have none.
+	pub fn into_uncertain(self) -> Option<(Quantity, Decimal)> {
+		match self {
+			Value::Quantity(q) | Value::Formatted(q, _) => Some((q, Decimal::ZERO)),
+			Value::Uncertain(q, uncertainty) => Some((q, uncertainty)),
+			_ => None
+		}
+	}
+
+	/// First order propagation of independent uncertainties.
+	/// The result's uncertainty is the root of the summed squares of each uncertainty times its partial derivative.
+	fn uncertain_operation(left: Value, right: Value, op: Operator) -> Result<Value> {
+		let ((a, a_sigma), (b, b_sigma)) = match (left.into_uncertain(), right.into_uncertain()) {
+			(Some(left), Some(right)) => (left, right),
+			_ => return Err(Error::UnableToOperateValues(op))
+		};
+
+		let is_percent = |q: &Quantity| q.unit().map(|u| u.base() == "%").unwrap_or_default();
+
+		if is_percent(&a) || is_percent(&b) {
+			return Err(Error::UnableToOperateValues(op));
+		}
+
+		print_dbg!("Uncertain: ({} ± {}) {} ({} ± {})", a.amount(), a_sigma, op, b.amount(), b_sigma);
+
+		let (value, terms) = match op {
+			Operator::Plus | Operator::Minus => {
+				Value::check_units(&a, &b)?;
+
+				let value = if op == Operator::Plus { a.clone() + b.clone() } else { a.clone() - b.clone() };
+
+				// Both uncertainties in the units of the result.
+				let in_units = |q: &Quantity, sigma: Decimal| match (q.unit(), value.unit()) {
+					(Ok(from), Ok(to)) => sigma * from.total_factor() / to.total_factor(),
+					_ => sigma
+				};
+
+				let terms = [in_units(&a, a_sigma), in_units(&b, b_sigma)];
+
+				(value, terms)
+			}
+
+			// σ² = (b σa)² + (a σb)²
+			Operator::Multiply => {
+				let terms = [
+					(Quantity::new_unit(a_sigma, a.unit().ok().cloned()) * b.clone()).amount(),
+					(a.clone() * Quantity::new_unit(b_sigma, b.unit().ok().cloned())).amount()
+				];
+
+				(a * b, terms)
+			}
+
+			// σ² = (σa / b)² + (a σb / b²)²
+			Operator::Divide => {
+				if b.amount().is_zero() {
+					return Err(Error::UnableToOperateValues(op));
+				}
+
+				let b_relative = b_sigma / b.amount();
+				let a_term = (Quantity::new_unit(a_sigma, a.unit().ok().cloned()) / b.clone()).amount();
+
+				let value = a / b;
+				let terms = [a_term, value.amount() * b_relative];
+
+				(value, terms)
+			}
+
+			// σ² = (n a^(n-1) σa)² + (a^n ln(a) σn)²
+			Operator::Caret => {
+				let (base, exp) = (a.amount(), b.amount());
+				let value = a.pow(b)?;
+
+				let a_term = if a_sigma.is_zero() {
+					Decimal::ZERO
+				} else if base.is_zero() {
+					if exp == Decimal::ONE { a_sigma } else { Decimal::ZERO }
+				} else {
+					exp * value.amount() / base * a_sigma
+				};
+
+				let b_term = if b_sigma.is_zero() {
+					Decimal::ZERO
+				} else {
+					let ln = transcendental::ln(base)
+						.ok_or_else(|| Error::Conversion(conversion::Error::InvalidArgument("An uncertain exponent needs a positive base".to_string())))?;
+
+					value.amount() * ln * b_sigma
+				};
+
+				(value, [a_term, b_term])
+			}
+
+			_ => return Err(Error::UnableToOperateValues(op))
+		};
+
+		let sigma = combine_uncertainties(&terms).ok_or(Error::UnableToOperateValues(op))?;
+
+		Ok(Value::Uncertain(value, sigma))
+	}
+
+	/// The bounds of the value. Other quantities are an interval of a single value.
+	pub fn into_interval(self) -> Option<(Quantity, Quantity)> {
+		match self {
+			Value::Quantity(q) | Value::Formatted(q, _) => Some((q.clone(), q)),
+			Value::Interval(lo, hi) => Some((lo, hi)),
+			_ => None
+		}
+	}
+
+	/// The smallest interval holding every result of the operation on values inside both intervals.
+	fn interval_operation(left: Value, right: Value, op: Operator) -> Result<Value> {
+		let ((a, b), (c, d)) = match (left.into_interval(), right.into_interval()) {
+			(Some(left), Some(right)) => (left, right),
+			_ => return Err(Error::UnableToOperateValues(op))
+		};
+
+		print_dbg!("Interval: [{} .. {}] {} [{} .. {}]", a.amount(), b.amount(), op, c.amount(), d.amount());
+
+		match op {
+			Operator::Plus => {
+				Value::check_units(&a, &c)?;
+
+				Value::new_interval(a + c, b + d)
+			}
+
+			Operator::Minus => {
+				Value::check_units(&a, &c)?;
+
+				Value::new_interval(a - d, b - c)
+			}
+
+			Operator::Multiply => Value::interval_bounds(vec![
+				a.clone() * c.clone(),
+				a * d.clone(),
+				b.clone() * c,
+				b * d
+			]),
+
+			Operator::Divide => {
+				if c.amount() <= Decimal::ZERO && d.amount() >= Decimal::ZERO {
+					return Err(Error::Conversion(conversion::Error::InvalidArgument(
+						format!("Unable to divide by [{} .. {}] as it contains zero", c, d)
+					)));
+				}
+
+				Value::interval_bounds(vec![
+					a.clone() / c.clone(),
+					a / d.clone(),
+					b.clone() / c,
+					b / d
+				])
+			}
+
+			Operator::Caret => {
+				if c.amount() != d.amount() {
+					return Err(Error::Conversion(conversion::Error::InvalidArgument("Exponents can't be intervals".to_string())));
+				}
+
+				let exp = c.amount();
+				let contains_zero = a.amount() <= Decimal::ZERO && b.amount() >= Decimal::ZERO;
+
+				if contains_zero && exp.is_sign_negative() {
+					return Err(Error::Conversion(conversion::Error::InvalidArgument(
+						format!("Unable to raise [{} .. {}] to a negative power as it contains zero", a, b)
+					)));
+				}
+
+				let mut bounds = vec![a.clone().pow(c.clone())?, b.pow(c)?];
+
+				// [-2 .. 3]^2 = [0 .. 9]
+				if contains_zero {
+					let mut zero = a;
+					zero.set_amount(Decimal::ZERO);
+
+					bounds.push(zero.pow(Quantity::new(exp))?);
+				}
+
+				Value::interval_bounds(bounds)
+			}
+
+			_ => Err(Error::UnableToOperateValues(op))
+		}
+	}
+
+	/// Durations move dates and times. The difference between two dates or times is a duration.
+	/// Times wrap around midnight.
+	///  - 2026-10-18 + 3 weeks = 2026-11-08
+	///  - 2027-01-01 - 2026-10-18 = 75 days
+	///  - 9:30am + 4 h 45 min = 2:15pm
+	///  - 17:45 - 08:10 = 9 h 35 min
+	fn date_operation(left: Value, right: Value, op: Operator) -> Result<Value> {
+		let seconds = |q: &Quantity| match q.unit() {
+			Ok(unit) if is_convertable(unit, &Units::new(Box::new(Second))) => Ok(q.total_amount()),
+			_ => Err(Error::Conversion(conversion::Error::InvalidArgument(format!("Only durations can be added to dates and times, not {}", q))))
+		};
+
+		match (left, right, op) {
+			(Value::Time(time, format), Value::Quantity(q), Operator::Plus) |
+			(Value::Quantity(q), Value::Time(time, format), Operator::Plus) => Ok(Value::Time(wrap_day(time + seconds(&q)?), format)),
+
+			(Value::Time(time, format), Value::Quantity(q), Operator::Minus) => Ok(Value::Time(wrap_day(time - seconds(&q)?), format)),
+
+			(Value::Time(left, _), Value::Time(right, _), Operator::Minus) => {
+				let seconds = Quantity::new_unit(wrap_day(left - right), Some(Units::new(Box::new(Second))));
+
+				Ok(Value::Formatted(seconds, NumberFormat::Duration))
+			}
+
+			// 2026-10-18 + 9:30 = 2026-10-18T09:30
+			(Value::DateTime(date), Value::Time(time, _), Operator::Plus) |
+			(Value::Time(time, _), Value::DateTime(date), Operator::Plus) => {
+				date.add_seconds(time)
+					.map(|d| Value::DateTime(d.with_time()))
+					.ok_or_else(|| out_of_range(&date))
+			}
+
+			(Value::DateTime(date), Value::Quantity(q), Operator::Plus) |
+			(Value::Quantity(q), Value::DateTime(date), Operator::Plus) => {
+				date.add_seconds(seconds(&q)?).map(Value::DateTime).ok_or_else(|| out_of_range(&date))
+			}
+
+			(Value::DateTime(date), Value::Quantity(q), Operator::Minus) => {
+				date.add_seconds(-seconds(&q)?).map(Value::DateTime).ok_or_else(|| out_of_range(&date))
+			}
+
+			(Value::DateTime(left), Value::DateTime(right), Operator::Minus) => {
+				let difference = left.seconds() - right.seconds();
+				let days = difference / Decimal::from(86_400);
+
+				print_dbg!("Date: {} - {} = {}s", left, right, difference);
+
+				// Whole days are shown as days. Otherwise as seconds.
+				let q = if days.fract().is_zero() {
+					Quantity::new_unit(days.normalize(), Some(Units::new(Box::new(Day))))
+				} else {
+					Quantity::new_unit(difference.normalize(), Some(Units::new(Box::new(Second))))
+				};
+
+				Ok(Value::Formatted(q, NumberFormat::Elapsed(right)))
+			}
+
+			_ => Err(Error::UnableToOperateValues(op))
+		}
+	}
+
+	/// Smallest and largest of values in the same units.
+	fn interval_bounds(values: Vec<Quantity>) -> Result<Value> {
+		let lo = values.iter().min_by_key(|q| q.amount()).cloned().ok_or(Error::ExpectedQuantity)?;
+		let hi = values.into_iter().max_by_key(|q| q.amount()).ok_or(Error::ExpectedQuantity)?;
+
+		Value::new_interval(lo, hi)
+	}
+
 	pub fn into_base_unit(self) -> Option<Units> {
 		match self {
-			Value::Quantity(q) => q.into_unit(),
-			Value::Unit(u) => Some(u)
+			Value::Quantity(q) | Value::Formatted(q, _) | Value::Uncertain(q, _) | Value::Interval(q, _) => q.into_unit(),
+			Value::Unit(u) => Some(u),
+			Value::Complex(_, u, _) => u,
+			Value::List(_) | Value::DateTime(_) | Value::Time(..) => None
+		}
+	}
+
+
+	/// Applies the function to each item in the list(s).
+	///  - [1, 2] + 1 = [2, 3]
+	///  - [1, 2] + [3, 4] = [4, 6]
+	fn broadcast<F>(left: Value, right: Value, func: F) -> Result<Value> where F: Fn(Value, Value) -> Result<Value> {
+		Ok(Value::List(match (left, right) {
+			(Value::List(left), Value::List(right)) => {
+				if left.len() != right.len() {
+					return Err(Error::MismatchedListLength(left.len(), right.len()));
+				}
+
+				left.into_iter()
+				.zip(right)
+				.map(|(l, r)| func(l, r))
+				.collect::<Result<_>>()?
+			}
+
+			(Value::List(left), right) => {
+				left.into_iter()
+				.map(|l| func(l, right.clone()))
+				.collect::<Result<_>>()?
+			}
+
+			(left, Value::List(right)) => {
+				right.into_iter()
+				.map(|r| func(left.clone(), r))
+				.collect::<Result<_>>()?
+			}
+
+			(left, right) => return func(left, right)
+		}))
+	}
+
+	/// Ensures both units can be combined. Percentages can be combined with anything.
+	fn check_units(left: &Quantity, right: &Quantity) -> Result<()> {
+		match (left.unit(), right.unit()) {
+			(Ok(l), Ok(r)) if r.base() != "%" && !is_convertable(l, r) => Err(Error::UnableToConvertValues(l.long(), r.long())),
+			_ => Ok(())
 		}
 	}
 
 
 	pub fn try_add(left: Value, right: Value) -> Result<Value> {
-		match (left, right) {
+		// 1:30:00 + 1:30:00 = 3 h
+		if left.is_duration() && right.is_duration() {
+			return Value::try_add(left.into_plain(), right.into_plain()).map(Value::into_duration);
+		}
+
+		match (left.into_plain(), right.into_plain()) {
+			(left @ Value::List(_), right) |
+			(left, right @ Value::List(_)) => Value::broadcast(left, right, Value::try_add),
+
+			(left @ Value::Complex(..), right) |
+			(left, right @ Value::Complex(..)) => Value::complex_operation(left, right, Operator::Plus),
+
+			(left @ Value::Uncertain(..), right) |
+			(left, right @ Value::Uncertain(..)) => Value::uncertain_operation(left, right, Operator::Plus),
+
+			(left @ Value::Interval(..), right) |
+			(left, right @ Value::Interval(..)) => Value::interval_operation(left, right, Operator::Plus),
+
+			(left @ Value::DateTime(_), right) |
+			(left, right @ Value::DateTime(_)) |
+			(left @ Value::Time(..), right) |
+			(left, right @ Value::Time(..)) => Value::date_operation(left, right, Operator::Plus),
+
 			(Value::Quantity(left), Value::Quantity(right)) => {
+				Value::check_units(&left, &right)?;
+
 				let (l_amount, r_amount) = (left.amount(), right.amount());
 				let (l_name, r_name) = (
 					left.unit().map(|u| u.short()).unwrap_or_default(),
@@ -115,7 +943,7 @@ impl Value {
 					value.unit().map(|u| u.short()).unwrap_or_default()
 				);
 
-				Ok(Value::Quantity(value))
+				Ok(Value::Quantity(round_money(value)))
 			}
 
 			_ => Err(Error::UnableToOperateValues(Operator::Plus))
@@ -123,13 +951,37 @@ impl Value {
 	}
 
 	pub fn try_subtract(left: Value, right: Value) -> Result<Value> {
-		match (left, right) {
+		// 2:00:00 - 0:45:00 = 1 h 15 min
+		if left.is_duration() && right.is_duration() {
+			return Value::try_subtract(left.into_plain(), right.into_plain()).map(Value::into_duration);
+		}
+
+		match (left.into_plain(), right.into_plain()) {
+			(left @ Value::List(_), right) |
+			(left, right @ Value::List(_)) => Value::broadcast(left, right, Value::try_subtract),
+
+			(left @ Value::Complex(..), right) |
+			(left, right @ Value::Complex(..)) => Value::complex_operation(left, right, Operator::Minus),
+
+			(left @ Value::Uncertain(..), right) |
+			(left, right @ Value::Uncertain(..)) => Value::uncertain_operation(left, right, Operator::Minus),
+
+			(left @ Value::Interval(..), right) |
+			(left, right @ Value::Interval(..)) => Value::interval_operation(left, right, Operator::Minus),
+
+			(left @ Value::DateTime(_), right) |
+			(left, right @ Value::DateTime(_)) |
+			(left @ Value::Time(..), right) |
+			(left, right @ Value::Time(..)) => Value::date_operation(left, right, Operator::Minus),
+
 			(Value::Quantity(left), Value::Quantity(right)) => {
+				Value::check_units(&left, &right)?;
+
 				let (l_amount, r_amount) = (left.amount(), right.amount());
 
 				let value = left - right;
 				print_dbg!("Sub: {} - {} = {}", l_amount, r_amount, value.amount());
-				Ok(Value::Quantity(value))
+				Ok(Value::Quantity(round_money(value)))
 			}
 
 			_ => Err(Error::UnableToOperateValues(Operator::Minus))
@@ -137,13 +989,30 @@ impl Value {
 	}
 
 	pub fn try_multiply(left: Value, right: Value) -> Result<Value> {
-		match (left, right) {
+		match (left.into_plain(), right.into_plain()) {
+			(left @ Value::List(_), right) |
+			(left, right @ Value::List(_)) => Value::broadcast(left, right, Value::try_multiply),
+
+			(left @ Value::Complex(..), right) |
+			(left, right @ Value::Complex(..)) => Value::complex_operation(left, right, Operator::Multiply),
+
+			(left @ Value::Uncertain(..), right) |
+			(left, right @ Value::Uncertain(..)) => Value::uncertain_operation(left, right, Operator::Multiply),
+
+			(left @ Value::Interval(..), right) |
+			(left, right @ Value::Interval(..)) => Value::interval_operation(left, right, Operator::Multiply),
+
+			(left @ Value::DateTime(_), right) |
+			(left, right @ Value::DateTime(_)) |
+			(left @ Value::Time(..), right) |
+			(left, right @ Value::Time(..)) => Value::date_operation(left, right, Operator::Multiply),
+
 			(Value::Quantity(left), Value::Quantity(right)) => {
 				let (l_amount, r_amount) = (left.amount(), right.amount());
 
 				let value = left * right;
 				print_dbg!("Mul: {} * {} = {}", l_amount, r_amount, value.amount());
-				Ok(Value::Quantity(value))
+				Ok(Value::Quantity(round_money(value)))
 			}
 
 			_ => Err(Error::UnableToOperateValues(Operator::Multiply))
@@ -151,38 +1020,170 @@ impl Value {
 	}
 
 	pub fn try_divide(left: Value, right: Value) -> Result<Value> {
-		match (left, right) {
+		match (left.into_plain(), right.into_plain()) {
+			(left @ Value::List(_), right) |
+			(left, right @ Value::List(_)) => Value::broadcast(left, right, Value::try_divide),
+
+			(left @ Value::Complex(..), right) |
+			(left, right @ Value::Complex(..)) => Value::complex_operation(left, right, Operator::Divide),
+
+			(left @ Value::Uncertain(..), right) |
+			(left, right @ Value::Uncertain(..)) => Value::uncertain_operation(left, right, Operator::Divide),
+
+			(left @ Value::Interval(..), right) |
+			(left, right @ Value::Interval(..)) => Value::interval_operation(left, right, Operator::Divide),
+
+			(left @ Value::DateTime(_), right) |
+			(left, right @ Value::DateTime(_)) |
+			(left @ Value::Time(..), right) |
+			(left, right @ Value::Time(..)) => Value::date_operation(left, right, Operator::Divide),
+
 			(Value::Quantity(left), Value::Quantity(right)) => {
 				let (l_amount, r_amount) = (left.amount(), right.amount());
 
-				let value = left / right;
+				let value = left.checked_div(right)?;
 
 				print_dbg!("Div: {} / {} = {}", l_amount, r_amount, value.amount());
 
-				Ok(Value::Quantity(value))
+				Ok(Value::Quantity(round_money(value)))
 			}
 
 			_ => Err(Error::UnableToOperateValues(Operator::Divide))
 		}
 	}
 
-	// pub fn try_exponentiate(left: Value, right: Value) -> Result<Value> {
-	// 	match (left, right) {
-	// 		(Value::Quantity(left), Value::Quantity(right)) => {
-	// 			let (l_amount, r_amount) = (left.amount(), right.amount());
+	pub fn try_exponentiate(left: Value, right: Value) -> Result<Value> {
+		match (left.into_plain(), right.into_plain()) {
+			(left @ Value::List(_), right) |
+			(left, right @ Value::List(_)) => Value::broadcast(left, right, Value::try_exponentiate),
 
-	// 			let value = left.pow(right);
+			// (1 + i)^2 = 2i
+			(Value::Complex(value, unit, form), Value::Quantity(exp)) => {
+				let power = match (exp.unit(), exp.amount().fract().is_zero()) {
+					(Err(_), true) => exp.amount().to_i64(),
+					_ => None
+				};
 
-	// 			print_dbg!("Exp: {}^{} = {}", l_amount, r_amount, value.amount());
+				let power = power.ok_or_else(|| Error::Conversion(conversion::Error::InvalidArgument("Complex numbers can only be raised to whole numbers".to_string())))?;
 
-	// 			Ok(Value::Quantity(value))
-	// 		}
+				Ok(Value::new_complex(value.powi(power), unit.map(|u| u.powi(power as i32)), form))
+			}
 
-	// 		_ => Err(Error::UnableToOperateValues(Operator::Division))
-	// 	}
-	// }
+			(left @ Value::Uncertain(..), right @ Value::Quantity(_)) |
+			(left @ Value::Quantity(_), right @ Value::Uncertain(..)) |
+			(left @ Value::Uncertain(..), right @ Value::Uncertain(..)) => Value::uncertain_operation(left, right, Operator::Caret),
+
+			(left @ Value::Interval(..), right) |
+			(left, right @ Value::Interval(..)) => Value::interval_operation(left, right, Operator::Caret),
+
+			(Value::Quantity(left), Value::Quantity(right)) => {
+				let (l_amount, r_amount) = (left.amount(), right.amount());
+
+				let value = left.pow(right)?;
+
+				print_dbg!("Exp: {}^{} = {}", l_amount, r_amount, value.amount());
+
+				Ok(Value::Quantity(value))
+			}
+
+			_ => Err(Error::UnableToOperateValues(Operator::Caret))
+		}
+	}
+
+	pub fn try_negate(value: Value) -> Result<Value> {
+		match value {
+			Value::Quantity(q) => Ok(Value::Quantity(-q)),
+			Value::Formatted(q, format) => Ok(Value::Formatted(-q, format)),
+
+			Value::List(items) => Ok(Value::List(items.into_iter().map(Value::try_negate).collect::<Result<_>>()?)),
+
+			Value::Complex(value, unit, form) => Ok(Value::Complex(-value, unit, form)),
+			Value::Uncertain(q, uncertainty) => Ok(Value::Uncertain(-q, uncertainty)),
+			Value::Interval(lo, hi) => Ok(Value::Interval(-hi, -lo)),
+			Value::Unit(_) | Value::DateTime(_) | Value::Time(..) => Err(Error::UnableToOperateValues(Operator::Minus))
+		}
+	}
+
+	/// Negative indexes start from the end of the list.
+	pub fn try_index(value: Value, index: Value) -> Result<Value> {
+		match (value, index.into_plain()) {
+			(Value::List(items), Value::Quantity(index)) => {
+				let amount = index.amount();
+				let length = items.len();
+
+				let pos = if amount < Decimal::new(0, 0) {
+					amount + Decimal::from(length)
+				} else {
+					amount
+				};
+
+				if index.unit().is_err() && pos.fract().is_zero() {
+					if let Some(item) = pos.to_usize().and_then(|i| items.into_iter().nth(i)) {
+						print_dbg!("Index: [{}] = {}", amount, item);
+
+						return Ok(item);
+					}
+				}
+
+				Err(Error::InvalidIndex(amount, length))
+			}
+
+			_ => Err(Error::UnexpectedToken(ExprToken::StartList))
+		}
+	}
 
 	pub fn try_conversion(left: Value, right: Value) -> Result<Value> {
+		let (left, right) = (left.into_plain(), right.into_plain());
+
+		if let Value::List(items) = left {
+			return Ok(Value::List(
+				items.into_iter()
+				.map(|i| Value::try_conversion(i, right.clone()))
+				.collect::<Result<_>>()?
+			));
+		}
+
+		// Time since midnight. 9:30am -> min = 570 min
+		if let Value::Time(time, _) = left {
+			let value = convert(&Value::new_quantity_unit(time, Some(Units::new(Box::new(Second)))), &right)?;
+
+			return Ok(Value::Quantity(Quantity::new_unit(value, right.clone_base_unit().ok())));
+		}
+
+		// (3 + 4i) kΩ -> Ω
+		if let Value::Complex(value, unit, form) = left {
+			let re = convert(&Value::new_quantity_unit(value.re, unit.clone()), &right)?;
+			let im = convert(&Value::new_quantity_unit(value.im, unit), &right)?;
+
+			return Ok(Value::new_complex(Complex::new(re, im), right.clone_base_unit().ok(), form));
+		}
+
+		// 5.00 ± 0.02 m -> cm
+		if let Value::Uncertain(value, uncertainty) = left {
+			let unit = right.clone_base_unit().ok();
+			let uncertainty = convert(&Value::new_quantity_unit(uncertainty, value.unit().ok().cloned()), &right)?;
+			let value = convert(&Value::Quantity(value), &right)?;
+
+			return Ok(Value::Uncertain(Quantity::new_unit(value, unit), uncertainty.abs()));
+		}
+
+		// [10 kg .. 12 kg] -> lb
+		if let Value::Interval(lo, hi) = left {
+			let unit = right.clone_base_unit().ok();
+
+			let lo = convert(&Value::Quantity(lo), &right)?;
+			let hi = convert(&Value::Quantity(hi), &right)?;
+
+			return Ok(Value::Interval(Quantity::new_unit(lo, unit.clone()), Quantity::new_unit(hi, unit)));
+		}
+
+		// 2026-10-18 -> Foo/Bar. Time zones which exist were converted by DateConversion.
+		if let (Value::DateTime(_), Ok(unit)) = (&left, right.as_base_unit()) {
+			if unit.terms().iter().all(|(base, _)| base.is_custom()) {
+				return Err(unknown_zone(&unit.to_string()));
+			}
+		}
+
 		let (l_amount, r_amount) = (left.amount(), right.amount());
 
 		let unit = right.clone_base_unit().ok();
@@ -191,10 +1192,93 @@ impl Value {
 
 		print_dbg!("Conv: {}(f {}) -> {}(f {}) = {}", l_amount.unwrap_or_default(), left.base_factor(), r_amount.unwrap_or_default(), right.base_factor(), value);
 
+		// Exact values stay exact. 1/3 ft -> in = 4 in
+		let exact = match (&left, &right) {
+			(Value::Quantity(q), Value::Unit(_)) | (Value::Quantity(q), Value::Quantity(_)) => convert_exact(q.exact(), &left, &right),
+			_ => None
+		};
+
+		if let Some(quantity) = exact.and_then(|e| Quantity::from_rational(e, unit.clone())) {
+			return Ok(Value::Quantity(quantity));
+		}
+
 		Ok(Value::Quantity(Quantity::new_unit(value, unit)))
 	}
 
 	pub fn try_comparison(left: Value, right: Value, op: &Operator) -> Result<Value> {
+		let (left, right) = (left.into_plain(), right.into_plain());
+
+		if let (Value::List(_), _) | (_, Value::List(_)) = (&left, &right) {
+			return Value::broadcast(left, right, |l, r| Value::try_comparison(l, r, op));
+		}
+
+		// Complex numbers can only be equal or not.
+		if let (Value::Complex(..), _) | (_, Value::Complex(..)) = (&left, &right) {
+			let equal = match (left.into_parts(), right.into_parts()) {
+				(Some((a, b, _)), Some((c, d, _))) => a.total_amount() == c.total_amount() && b.total_amount() == d.total_amount(),
+				_ => false
+			};
+
+			let value = match op {
+				Operator::DoubleEqual => equal,
+				Operator::DoesNotEqual => !equal,
+				_ => return Err(Error::UnableToOperateValues(*op))
+			};
+
+			return Ok(Value::Quantity(Quantity::new(Decimal::from(value as i64))));
+		}
+
+		// 2026-10-18 < 2027-01-01
+		if let (Value::DateTime(_), _) | (_, Value::DateTime(_)) | (Value::Time(..), _) | (_, Value::Time(..)) = (&left, &right) {
+			let (l_seconds, r_seconds) = match (&left, &right) {
+				(Value::DateTime(l), Value::DateTime(r)) => (l.seconds(), r.seconds()),
+				(Value::Time(l, _), Value::Time(r, _)) => (*l, *r),
+				_ => return Err(Error::UnableToOperateValues(*op))
+			};
+
+			let value = match op {
+				Operator::GreaterThan => l_seconds > r_seconds,
+				Operator::GreaterThanOrEqual => l_seconds >= r_seconds,
+				Operator::LessThan => l_seconds < r_seconds,
+				Operator::LessThanOrEqual => l_seconds <= r_seconds,
+				Operator::DoubleEqual => l_seconds == r_seconds,
+				Operator::DoesNotEqual => l_seconds != r_seconds,
+				_ => return Err(Error::InvalidOperator)
+			};
+
+			return Ok(Value::Quantity(Quantity::new(Decimal::from(value as i64))));
+		}
+
+		// Whether it's certainly and possibly true. [1 .. 3] > 2 = [0 .. 1]
+		if let (Value::Interval(..), _) | (_, Value::Interval(..)) = (&left, &right) {
+			let ((a, b), (c, d)) = match (left.into_interval(), right.into_interval()) {
+				(Some(left), Some(right)) => (left, right),
+				_ => return Err(Error::UnableToOperateValues(*op))
+			};
+
+			Value::check_units(&a, &c)?;
+
+			let (a, b, c, d) = (a.total_amount(), b.total_amount(), c.total_amount(), d.total_amount());
+			let overlaps = a <= d && c <= b;
+
+			let (certainly, possibly) = match op {
+				Operator::GreaterThan => (a > d, b > c),
+				Operator::GreaterThanOrEqual => (a >= d, b >= c),
+				Operator::LessThan => (b < c, a < d),
+				Operator::LessThanOrEqual => (b <= c, a <= d),
+				Operator::DoubleEqual => (a == b && b == c && c == d, overlaps),
+				Operator::DoesNotEqual => (!overlaps, !(a == b && b == c && c == d)),
+				_ => return Err(Error::InvalidOperator)
+			};
+
+			print_dbg!("Comp: [{} .. {}] {} [{} .. {}] = {}, {}", a, b, op, c, d, certainly, possibly);
+
+			return Value::new_interval(
+				Quantity::new(Decimal::from(certainly as i64)),
+				Quantity::new(Decimal::from(possibly as i64))
+			);
+		}
+
 		let (l_amount, r_amount) = (left.total_amount(), right.total_amount());
 
 		let value = match op {
@@ -207,16 +1291,23 @@ impl Value {
 			_ => return Err(Error::InvalidOperator)
 		};
 
-		print_dbg!("Comp: {} {} {} = {}", l_amount.clone().unwrap_or_default(), op, r_amount.clone().unwrap_or_default(), value);
+		print_dbg!("Comp: {} {} {} = {}", l_amount.unwrap_or_default(), op, r_amount.unwrap_or_default(), value);
 
-		Ok(Value::Quantity(Quantity::new(Decimal::new(1, 0))))
+		Ok(Value::Quantity(Quantity::new(Decimal::new(value, 0))))
 	}
 }
 
 impl PartialEq for Value {
 	fn eq(&self, other: &Value) -> bool {
 		match (self, other) {
-			(Value::Quantity(q1), Value::Quantity(q2)) => q1 == q2,
+			(Value::Quantity(q1), Value::Quantity(q2)) |
+			(Value::Formatted(q1, _), Value::Formatted(q2, _)) => q1 == q2,
+			(Value::List(l1), Value::List(l2)) => l1 == l2,
+			(Value::Complex(c1, u1, _), Value::Complex(c2, u2, _)) => c1 == c2 && u1 == u2,
+			(Value::Uncertain(q1, s1), Value::Uncertain(q2, s2)) => q1 == q2 && s1 == s2,
+			(Value::Interval(l1, h1), Value::Interval(l2, h2)) => l1 == l2 && h1 == h2,
+			(Value::DateTime(d1), Value::DateTime(d2)) => d1 == d2,
+			(Value::Time(t1, _), Value::Time(t2, _)) => t1 == t2,
 			_ => false
 		}
 	}
@@ -225,8 +1316,15 @@ impl PartialEq for Value {
 impl Clone for Value {
 	fn clone(&self) -> Self {
 		match self {
-			Value::Quantity(q) => Value::Quantity(Quantity::new_unit(q.amount(), q.unit().ok().cloned())),
-			Value::Unit(u) => Value::Unit(u.clone())
+			Value::Quantity(q) => Value::Quantity(q.clone()),
+			Value::Formatted(q, format) => Value::Formatted(q.clone(), *format),
+			Value::Unit(u) => Value::Unit(u.clone()),
+			Value::List(v) => Value::List(v.clone()),
+			Value::Complex(c, u, form) => Value::Complex(*c, u.clone(), *form),
+			Value::Uncertain(q, uncertainty) => Value::Uncertain(q.clone(), *uncertainty),
+			Value::Interval(lo, hi) => Value::Interval(lo.clone(), hi.clone()),
+			Value::DateTime(date) => Value::DateTime(*date),
+			Value::Time(time, format) => Value::Time(*time, *format)
 		}
 	}
 }
@@ -235,7 +1333,264 @@ impl fmt::Display for Value {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
 			Value::Quantity(q) => q.fmt(f),
-			Value::Unit(u) => u.fmt(f)
+			Value::Unit(u) => u.fmt(f),
+			Value::Formatted(q, NumberFormat::Engineering) => {
+				let (mantissa, exponent) = engineering_parts(q.amount());
+
+				let value = if exponent != 0 { format!("{}e{}", mantissa, exponent) } else { mantissa.to_string() };
+
+				match q.unit() {
+					Ok(u) => write!(f, "{} {}", value, u),
+					Err(_) => f.write_str(&value)
+				}
+			}
+			Value::Formatted(q, NumberFormat::Fraction) => {
+				let (whole, numer, denom) = mixed_fraction(q);
+
+				let value = match (whole, numer.zip(denom)) {
+					(Some(whole), Some((numer, denom))) => format!("{} {}/{}", whole, numer, denom),
+					(None, Some((numer, denom))) => format!("{}/{}", numer, denom),
+					(Some(whole), None) => whole.to_string(),
+					(None, None) => "0".to_string()
+				};
+
+				match q.unit() {
+					Ok(u) => write!(f, "{} {}", value, u),
+					Err(_) => f.write_str(&value)
+				}
+			}
+			Value::Uncertain(q, uncertainty) => {
+				let (value, uncertainty) = uncertain_parts(q.amount(), *uncertainty);
+
+				match q.unit() {
+					Ok(u) => write!(f, "{} ± {} {}", value, uncertainty, u),
+					Err(_) => write!(f, "{} ± {}", value, uncertainty)
+				}
+			}
+			Value::Interval(lo, hi) => write!(f, "[{} .. {}]", lo, hi),
+			Value::DateTime(date) => date.fmt(f),
+			Value::Time(time, format) => {
+				let whole = time.trunc().to_u32().unwrap_or_default();
+				let (hour, minute, second) = (whole / 3600, whole / 60 % 60, *time - Decimal::from(whole / 60 * 60));
+
+				match format {
+					ClockFormat::TwentyFourHour => write!(f, "{:02}:{:02}", hour, minute)?,
+					ClockFormat::TwelveHour => write!(f, "{}:{:02}", if hour % 12 == 0 { 12 } else { hour % 12 }, minute)?
+				}
+
+				if !second.is_zero() {
+					write!(f, ":{}{}", if second < Decimal::TEN { "0" } else { "" }, second.normalize())?;
+				}
+
+				if *format == ClockFormat::TwelveHour {
+					f.write_str(if hour < 12 { "am" } else { "pm" })?;
+				}
+
+				Ok(())
+			}
+			Value::Formatted(q, NumberFormat::Duration) => {
+				for (i, (amount, unit)) in duration_parts(q).into_iter().enumerate() {
+					if i != 0 {
+						f.write_str(" ")?;
+					}
+
+					write!(f, "{} {}", amount, unit)?;
+				}
+
+				Ok(())
+			}
+			Value::Formatted(q, NumberFormat::Humanized(format)) => match q.humanize(format) {
+				Ok(value) => f.write_str(&value),
+				Err(_) => q.fmt(f)
+			},
+			Value::Formatted(q, NumberFormat::Weekday) => f.write_str(weekday_name(q)),
+			Value::Formatted(q, NumberFormat::Elapsed(_)) => q.fmt(f),
+			Value::Complex(c, unit, ComplexForm::Polar) => {
+				let (magnitude, angle) = polar_parts(c);
+
+				match unit {
+					Some(u) => write!(f, "{} {} ∠ {}°", magnitude, u, angle),
+					None => write!(f, "{} ∠ {}°", magnitude, angle)
+				}
+			}
+			Value::Complex(c, None, _) => c.fmt(f),
+			Value::Complex(c, Some(u), _) if c.re.is_zero() || c.im.is_zero() => write!(f, "{} {}", c, u),
+			Value::Complex(c, Some(u), _) => write!(f, "({}) {}", c, u),
+			Value::List(items) => {
+				f.write_str("[")?;
+
+				for (i, item) in items.iter().enumerate() {
+					if i != 0 {
+						f.write_str(", ")?;
+					}
+
+					item.fmt(f)?;
+				}
+
+				f.write_str("]")
+			}
 		}
 	}
-}
\ No newline at end of file
+}
+
+/// Magnitude and angle in degrees.
+fn polar_parts(value: &Complex) -> (Decimal, Decimal) {
+	let round = |v: Decimal| v.round_sf(POLAR_PRECISION).unwrap_or(v).normalize();
+
+	let magnitude = value.abs().unwrap_or_default();
+	let angle = value.arg().unwrap_or_default() * Decimal::from(180) / PI;
+
+	(round(magnitude), round(angle))
+}
+
+/// The uncertainty to one significant figure, or two if it starts with a 1, and the value rounded to the same place.
+/// (12.5, 0.0707) -> (12.50, 0.07)
+fn uncertain_parts(value: Decimal, uncertainty: Decimal) -> (Decimal, Decimal) {
+	if uncertainty.is_zero() {
+		return (value.normalize(), uncertainty);
+	}
+
+	let leading = uncertainty.round_sf(1).unwrap_or(uncertainty).normalize();
+	let figures = if leading.mantissa().unsigned_abs().to_string().starts_with('1') { 2 } else { 1 };
+
+	let mut uncertainty = uncertainty.round_sf(figures).unwrap_or(uncertainty).normalize();
+
+	// Position of the last shown digit. 0.07 = 2, 230 = -2
+	let first_digit = uncertainty.mantissa().unsigned_abs().to_string().len() as i32 - 1 - uncertainty.scale() as i32;
+	let places = figures as i32 - 1 - first_digit;
+
+	if places >= 0 {
+		let mut value = value.round_dp(places as u32);
+
+		value.rescale(places as u32);
+		uncertainty.rescale(places as u32);
+
+		(value, uncertainty)
+	} else {
+		let step = Decimal::from_i128_with_scale(10i128.pow((-places) as u32), 0);
+
+		((value / step).round() * step, uncertainty)
+	}
+}
+
+/// Root of the summed squares. None if it's too large.
+fn combine_uncertainties(terms: &[Decimal]) -> Option<Decimal> {
+	let sum = terms.iter().try_fold(Decimal::ZERO, |sum, term| sum.checked_add(term.checked_mul(*term)?))?;
+
+	transcendental::sqrt(sum).map(|v| v.normalize())
+}
+
+/// Seconds since midnight within a single day. -1 h = 23 h
+fn wrap_day(seconds: Decimal) -> Decimal {
+	let day = Decimal::from(86_400);
+	let seconds = seconds % day;
+
+	if seconds.is_sign_negative() && !seconds.is_zero() { seconds + day } else { seconds.normalize() }
+}
+
+/// Hours, minutes and seconds which aren't zero. 5400 s -> [(1, h), (30, min)]
+fn duration_parts(value: &Quantity) -> Vec<(Decimal, &'static str)> {
+	let total = value.total_amount();
+	let seconds = total.abs();
+
+	let hours = (seconds / Decimal::from(3600)).trunc();
+	let minutes = ((seconds - hours * Decimal::from(3600)) / Decimal::from(60)).trunc();
+	let seconds = (seconds - hours * Decimal::from(3600) - minutes * Decimal::from(60)).normalize();
+
+	let mut parts = vec![(hours, "h"), (minutes, "min"), (seconds, "s")]
+		.into_iter()
+		.filter(|(amount, _)| !amount.is_zero())
+		.collect::<Vec<_>>();
+
+	if parts.is_empty() {
+		parts.push((Decimal::ZERO, "s"));
+	}
+
+	// -1 h 30 min
+	if total.is_sign_negative() {
+		parts[0].0 = -parts[0].0;
+	}
+
+	parts
+}
+
+/// Sunday for 7.
+fn weekday_name(value: &Quantity) -> &'static str {
+	value.amount().to_usize()
+		.and_then(|day| WEEKDAYS.get(day.wrapping_sub(1)))
+		.copied()
+		.unwrap_or_default()
+}
+
+/// Whole part and the remaining fraction. -19/8 -> (-2, 3, 8)
+fn mixed_fraction(value: &Quantity) -> (Option<Decimal>, Option<Decimal>, Option<Decimal>) {
+	let exact = match value.exact() {
+		Some(exact) => exact,
+		None => return (Some(value.amount()), None, None)
+	};
+
+	let (numer, denom) = (exact.numer(), exact.denom());
+	let (whole, remainder) = (numer / denom, (numer % denom).abs());
+
+	let whole = if whole != 0 || remainder == 0 {
+		Decimal::try_from_i128_with_scale(whole, 0).ok()
+	} else {
+		None
+	};
+
+	if remainder == 0 {
+		return (whole, None, None);
+	}
+
+	// -3/8 keeps its sign on the numerator.
+	let remainder = if whole.is_none() { numer % denom } else { remainder };
+
+	(
+		whole,
+		Decimal::try_from_i128_with_scale(remainder, 0).ok(),
+		Decimal::try_from_i128_with_scale(denom, 0).ok()
+	)
+}
+
+/// The exact amount in the new units. None if either unit's factor is an approximation.
+fn convert_exact(exact: Option<Rational>, from: &Value, to: &Value) -> Option<Rational> {
+	let (from, to) = (from.as_base_unit().ok()?, to.as_base_unit().ok()?);
+
+	if !from.is_exact() || !to.is_exact() {
+		return None;
+	}
+
+	let (from_num, from_den) = from.factor_parts();
+	let (to_num, to_den) = to.factor_parts();
+
+	let factor = |value: Decimal| Rational::from_decimal(value);
+
+	exact?
+		.checked_mul(factor(from_num)?)?
+		.checked_mul(factor(to_den)?)?
+		.checked_div(factor(from_den)?.checked_mul(factor(to_num)?)?)
+}
+
+/// Mantissa and an exponent which is a multiple of 3. 12500 -> (12.5, 3)
+fn engineering_parts(value: Decimal) -> (Decimal, i32) {
+	if value.is_zero() {
+		return (value, 0);
+	}
+
+	let value = value.normalize();
+
+	// Position of the first digit. 12500 = 4, 0.05 = -2
+	let digits = value.mantissa().unsigned_abs().to_string().len() as i32 - 1 - value.scale() as i32;
+	let exponent = digits.div_euclid(3) * 3;
+
+	let scale = value.scale() as i32 + exponent;
+
+	// 0.05 = 50e-3, the mantissa gains digits.
+	let mantissa = if scale >= 0 {
+		Decimal::from_i128_with_scale(value.mantissa(), scale as u32)
+	} else {
+		Decimal::from_i128_with_scale(value.mantissa() * 10i128.pow((-scale) as u32), 0)
+	};
+
+	(mantissa.normalize(), exponent)
+}
@@ -1,8 +1,11 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use web_sys::*;
 
-use conversion_parser::{Factory, Operator, ExprToken, Tokenizer};
+use conversion_parser::{Factory, FixedClock, Operator, ExprToken, ParseValue, Tokenizer};
 
 use crate::{Line, LineDisplay, Table};
 
@@ -38,7 +41,12 @@ pub fn console_input() -> HtmlInputElement {
 
 
 pub fn register_display() {
+	// Kept for the whole console so settings and definitions carry over between lines.
+	let factory = Rc::new(RefCell::new(Factory::new()));
+
 	{ // On Key Enter Event
+		let factory = factory.clone();
+
 		let closure = Closure::wrap(Box::new(move |e: KeyboardEvent| {
 			// Did not press enter.
 			if e.key_code() != 13 { return; }
@@ -48,23 +56,49 @@ pub fn register_display() {
 			let value = line_container.value();
 			line_container.set_value("");
 
-			display_parsed(&value);
+			display_parsed(&mut factory.borrow_mut(), &value);
 		}) as Box<dyn FnMut(_)>);
 
 		console_input().add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref()).unwrap();
 		closure.forget();
 	}
 
-	help_command();
+	help_command(&factory.borrow());
 }
 
 
-pub fn display_parsed(eval: &str) {
-	let factory = Factory::new();
+pub fn display_parsed(factory: &mut Factory, eval: &str) {
+	// SystemTime isn't available in the browser.
+	factory.set_clock(Box::new(FixedClock::from_unix_millis(js_sys::Date::now() as i64)));
+
+	// "precision 4"
+	if let Some(result) = factory.apply_setting(eval) {
+		match result {
+			Ok(()) => {
+				let t = &[ExprToken::Literal(eval.trim().to_string())];
+
+				let _ = console_container().append_child(&Line::new(t).render());
+			}
+			Err(e) => log!("{:?}", e)
+		}
+
+		return;
+	}
 
 	let mut parser = factory.create_parser(eval);
 
 	match parser.parse() {
+		// "define furlong = 201.168 m"
+		Ok(ParseValue::Definition(definition)) => {
+			let t = &[ExprToken::Literal(definition.to_string())];
+
+			match factory.add_definition(definition) {
+				Ok(()) => {
+					let _ = console_container().append_child(&Line::new(t).render());
+				}
+				Err(e) => log!("{:?}", e)
+			}
+		}
 		Ok(v) => {
 			if parser.get_parsed_tokens().len() == 1 {
 				if let Some(token) = parser.get_parsed_tokens().first().cloned() {
@@ -78,10 +112,10 @@ pub fn display_parsed(eval: &str) {
 						let command_name = token.into_literal();
 
 						match command_name.as_str() {
-							"help" => help_command(),
-							"functions" => fn_list_command(&factory),
-							"constants" => const_list_command(&factory),
-							"units" => unit_list_command(&factory),
+							"help" => help_command(factory),
+							"functions" => fn_list_command(factory),
+							"constants" => const_list_command(factory),
+							"units" => unit_list_command(factory),
 							_ => {}
 						}
 
@@ -118,25 +152,80 @@ pub fn display_parsed(eval: &str) {
 }
 
 
-pub fn help_command() {
-	let factory = Factory::new();
-
-	let mul_1 = into_tokens("2014 / 2 * 5", &factory);
-	let min_sec = into_tokens("5 min 30 s", &factory);
-
-	let wrapped = into_tokens("5 * (10 / 2)", &factory);
-	let m_into_h = into_tokens("120 min -> h", &factory);
-
-	let command_help = into_tokens("help", &factory);
-	let command_fn = into_tokens("functions", &factory);
-	let command_units = into_tokens("units", &factory);
-	let command_const = into_tokens("constants", &factory);
+pub fn help_command(factory: &Factory) {
+	let mul_1 = into_tokens("2014 / 2 * 5", factory);
+	let min_sec = into_tokens("5 min 30 s", factory);
+
+	let wrapped = into_tokens("5 * (10 / 2)", factory);
+	let m_into_h = into_tokens("120 min -> h", factory);
+
+	let list_sum = into_tokens("sum([1 km, 500 m])", factory);
+	let list_mul = into_tokens("[1, 2, 3] * 2", factory);
+
+	let trig_deg = into_tokens("sin(30 deg)", factory);
+	let deg_rad = into_tokens("90 deg -> rad", factory);
+
+	let solve_bare = into_tokens("3x - 6 = 9", factory);
+	let solve_units = into_tokens("solve(5 km / x = 10 km/h, x)", factory);
+	let solve_quadratic = into_tokens("(x - 3)(x - 2) = 2", factory);
+	let solve_complex = into_tokens("x^2 + 2x + 5 = 0", factory);
+	let solve_system = into_tokens("2x + 3y = 7; x - y = 1", factory);
+	let solve_system_units = into_tokens("x + y = 10 km; x - y = 2000 m", factory);
+	let calc_root = into_tokens("root(cos(x) - x, x, 1)", factory);
+	let calc_integrate = into_tokens("integrate(9.8 m/s² * t, t, 0 s, 10 s)", factory);
+	let complex_units = into_tokens("10 V / (3 Ω + 4i Ω)", factory);
+	let complex_polar = into_tokens("5∠30deg + 2i -> polar", factory);
+	let frac_exact = into_tokens("1/3 + 1/6 -> frac", factory);
+	let frac_inch = into_tokens("0.3125 in -> frac", factory);
+	let round_step = into_tokens("round_to(1.234 m, 5 cm)", factory);
+	let round_sigfig = into_tokens("sigfig(0.0012345, 3)", factory);
+	let uncertain_area = into_tokens("5.00 ± 0.02 m * 2.50 ± 0.01 m", factory);
+	let uncertain_fn = into_tokens("sqrt(4 +/- 0.1)", factory);
+	let interval_mul = into_tokens("[10 kg .. 12 kg] * 3", factory);
+	let interval_stack = into_tokens("width([9.9 mm .. 10.1 mm] * 4)", factory);
+	let date_add = into_tokens("2026-10-18 + 3 weeks", factory);
+	let date_diff = into_tokens("2027-01-01 - 2026-10-18 -> days", factory);
+	let date_weekday = into_tokens("weekday(2026-10-18)", factory);
+	let date_months = into_tokens("add_months(2026-01-31, 1)", factory);
+	let time_add = into_tokens("9:30am + 4 h 45 min", factory);
+	let time_diff = into_tokens("17:45 - 08:10", factory);
+	let epoch_from = into_tokens("1700000000 -> datetime", factory);
+	let epoch_into = into_tokens("2026-10-18T00:00Z -> unix_ms", factory);
+	let human_long = into_tokens("93784 s -> human", factory);
+	let human_dhms = into_tokens("93784 s -> dhms(2)", factory);
+	let zone_convert = into_tokens("2026-10-18 09:00 America/New_York -> Europe/Berlin", factory);
+	let zone_now = into_tokens("now in Tokyo", factory);
+	let money_split = into_tokens("$1049 / 3", factory);
+	let money_sum = into_tokens("€5 + 2.50 EUR", factory);
+
+	let command_help = into_tokens("help", factory);
+	let command_fn = into_tokens("functions", factory);
+	let command_units = into_tokens("units", factory);
+	let command_const = into_tokens("constants", factory);
 
 	let empty_vec = vec![ExprToken::Whitespace];
 
 	let rows = vec![
 		mul_1, min_sec,
 		wrapped, m_into_h,
+		list_sum, list_mul,
+		trig_deg, deg_rad,
+		solve_bare, solve_units,
+		solve_quadratic, solve_complex,
+		solve_system, solve_system_units,
+		calc_root, calc_integrate,
+		complex_units, complex_polar,
+		frac_exact, frac_inch,
+		round_step, round_sigfig,
+		uncertain_area, uncertain_fn,
+		interval_mul, interval_stack,
+		date_add, date_diff,
+		date_weekday, date_months,
+		time_add, time_diff,
+		epoch_from, epoch_into,
+		human_long, human_dhms,
+		zone_convert, zone_now,
+		money_split, money_sum,
 
 		empty_vec.clone(), empty_vec,
 
@@ -167,7 +256,13 @@ pub fn unit_list_command(factory: &Factory) {
 pub fn const_list_command(factory: &Factory) {
 	let constants = factory.get_constants();
 
-	let rows: Vec<Vec<ExprToken>> = constants.iter().map(|f| vec![ExprToken::Literal(f.0.clone())]).collect();
+	// Name and value cells side by side.
+	let rows: Vec<Vec<ExprToken>> = constants.iter()
+		.flat_map(|(name, value)| vec![
+			vec![ExprToken::Literal(name.clone())],
+			value.clone().into_tokens()
+		])
+		.collect();
 
 	let table = Table::new(rows);
 
@@ -177,7 +272,17 @@ pub fn const_list_command(factory: &Factory) {
 pub fn fn_list_command(factory: &Factory) {
 	let functions = factory.get_functions();
 
-	let rows: Vec<Vec<ExprToken>> = functions.iter().map(|f| vec![ExprToken::Literal(f.0.clone())]).collect();
+	// Signature and description cells side by side.
+	let rows: Vec<Vec<ExprToken>> = functions.iter()
+		.flat_map(|(name, func)| {
+			let info = func.info();
+
+			vec![
+				vec![ExprToken::Literal(info.signature_as(name))],
+				vec![ExprToken::Literal(info.description.to_string())]
+			]
+		})
+		.collect();
 
 	let table = Table::new(rows);
 
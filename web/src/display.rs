@@ -41,16 +41,43 @@ impl<'a> LineDisplay for Line<'a> {
 		let container: HtmlDivElement = create_element("div");
 		let _ = container.class_list().add_1("line");
 
-		for token in self.value {
+		let mut tokens = self.value.iter().peekable();
+
+		while let Some(token) = tokens.next() {
 			let value: HtmlSpanElement = create_element("span");
 
 			value.set_inner_text(&format!("{}", token));
 
+			// Keep the imaginary unit and degree sign on their number. 3 + 4i, 5 ∠ 30°
+			if let ExprToken::Number(_) = token {
+				let suffix = tokens.peek()
+					.and_then(|next| match next {
+						ExprToken::Literal(v) if v == "i" => Some((v, "imaginary")),
+						ExprToken::Literal(v) if v == "°" => Some((v, "angle")),
+						_ => None
+					});
+
+				if let Some((suffix, class)) = suffix {
+					let suffix_value: HtmlSpanElement = create_element("span");
+					suffix_value.set_inner_text(suffix);
+					let _ = suffix_value.class_list().add_1(class);
+
+					let _ = value.append_child(&suffix_value);
+					let _ = value.class_list().add_1("amount");
+					let _ = container.append_child(&value);
+
+					tokens.next();
+
+					continue;
+				}
+			}
+
 			let _ = match token {
 				ExprToken::Literal(_) => value.class_list().add_1("literal"),
 				ExprToken::Number(_) => value.class_list().add_1("amount"),
 				ExprToken::Operator(_) => value.class_list().add_1("operator"),
-				ExprToken::StartGrouping | ExprToken::EndGrouping => value.class_list().add_1("grouping"),
+				ExprToken::StartGrouping | ExprToken::EndGrouping |
+				ExprToken::StartList | ExprToken::EndList => value.class_list().add_1("grouping"),
 				_ => Ok(())
 			};
 